@@ -0,0 +1,68 @@
+use crate::config::Config;
+use crate::{input, Generator};
+use failure::{format_err, Error};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Generate Rust types for a set of schemas from a build script. Each input may
+// be a file, a directory, or a glob, just like on the command line. Code is
+// written to `OUT_DIR`, with a file per schema, ready to be pulled in with:
+//
+//     include!(concat!(env!("OUT_DIR"), "/user.rs"));
+//
+// Build scripts are meant to fail loudly, so this panics with a readable
+// message if anything goes wrong.
+pub fn compile(inputs: &[&str]) {
+    let mut config = Config::default();
+    config.rust.out = Some(String::new());
+
+    if let Err(err) = compile_with_config(inputs, &config) {
+        panic!("jsl-codegen: {}", err);
+    }
+}
+
+// Generate code for a set of schemas from a build script, for whichever
+// targets the config has an output directory for. Those directories are taken
+// to be relative to `OUT_DIR`, and are created if need be.
+pub fn compile_with_config(inputs: &[&str], config: &Config) -> Result<(), Error> {
+    let out_dir =
+        PathBuf::from(env::var_os("OUT_DIR").ok_or_else(|| format_err!("OUT_DIR is not set"))?);
+
+    // Rebuild whenever any of the schemas change. For directories, this also
+    // catches schemas being added or removed.
+    for arg in inputs {
+        if Path::new(arg).is_dir() {
+            println!("cargo:rerun-if-changed={}", arg);
+        }
+    }
+
+    let paths = input::find(inputs)?;
+    for path in &paths {
+        println!("cargo:rerun-if-changed={}", path.display());
+    }
+
+    let mut config = config.clone();
+    for target in config.targets_mut() {
+        if let Some(ref out) = target.out {
+            target.out = Some(out_dir.join(out).to_string_lossy().into_owned());
+        }
+    }
+
+    // Lock files from the last build are picked up from OUT_DIR, though
+    // they'll only be found there until the build directory is cleaned. Keep
+    // them elsewhere, and check them in, by giving Protocol Buffers an
+    // absolute output directory.
+    let inputs = input::load(&paths)?;
+    let generator = Generator::from_config(&config)?;
+    let locks = generator.read_locks(&inputs)?;
+    for (path, contents) in generator.generate_with_locks(&inputs, &locks)? {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        fs::write(path, contents)?;
+    }
+
+    Ok(())
+}
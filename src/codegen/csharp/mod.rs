@@ -0,0 +1,334 @@
+use crate::codegen;
+use crate::codegen::ir::{DeclKind, EnumMember, Field, Ir, Type};
+use crate::config::{Collisions, Config, TypeMapping};
+use crate::input::Input;
+use failure::{format_err, Error};
+use inflector::Inflector;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+
+// Names generated types can't have, since generated code uses them itself,
+// either from the namespaces it imports or from the attributes it uses.
+const RESERVED_TYPE_NAMES: &[&str] = &[
+    "DateTimeOffset",
+    "Dictionary",
+    "JsonConverter",
+    "JsonDerivedType",
+    "JsonElement",
+    "JsonIgnore",
+    "JsonIgnoreCondition",
+    "JsonPolymorphic",
+    "JsonPropertyName",
+    "JsonStringEnumConverter",
+    "JsonStringEnumMemberName",
+    "JsonUnmappedMemberHandling",
+    "List",
+    "Object",
+    "String",
+    "System",
+];
+
+// Names properties can't have, since records already have members by these
+// names.
+const RESERVED_MEMBER_NAMES: &[&str] = &[
+    "EqualityContract",
+    "Equals",
+    "GetHashCode",
+    "GetType",
+    "MemberwiseClone",
+    "PrintMembers",
+    "ToString",
+];
+
+// The namespaces generated code uses.
+const USINGS: &[&str] = &[
+    "System",
+    "System.Collections.Generic",
+    "System.Text.Json",
+    "System.Text.Json.Serialization",
+];
+
+#[derive(Debug)]
+pub struct Codegen {
+    strict: bool,
+    names: HashMap<String, String>,
+    collisions: Collisions,
+    types: HashMap<String, TypeMapping>,
+    out_dir: PathBuf,
+    out_namespace: String,
+}
+
+impl codegen::Codegen for Codegen {
+    type Ast = Ir;
+
+    fn args<'a, 'b>(app: clap::App<'a, 'b>) -> clap::App<'a, 'b> {
+        app.args(&[
+            clap::Arg::with_name("csharp-out")
+                .help("C# output directory")
+                .takes_value(true)
+                .long("csharp-out"),
+            clap::Arg::with_name("csharp-namespace")
+                .help("C# output namespace")
+                .takes_value(true)
+                .long("csharp-namespace"),
+        ])
+    }
+
+    fn from_args(matches: &clap::ArgMatches, config: &Config) -> Result<Option<Codegen>, Error> {
+        let mut config = config.clone();
+        if let Some(csharp_out) = matches.value_of("csharp-out") {
+            config.csharp.out = Some(csharp_out.to_owned());
+        }
+        if let Some(namespace) = matches.value_of("csharp-namespace") {
+            config.csharp.package = Some(namespace.to_owned());
+        }
+
+        Self::from_config(&config)
+    }
+
+    fn from_config(config: &Config) -> Result<Option<Codegen>, Error> {
+        if let Some(ref csharp_out) = config.csharp.out {
+            let csharp_namespace = config
+                .csharp
+                .package
+                .clone()
+                .ok_or(format_err!("--csharp-namespace required for C# output"))?;
+
+            Ok(Some(Codegen {
+                strict: config.strict,
+                names: config.names.clone(),
+                collisions: config.collisions,
+                types: config.csharp.types.clone(),
+                out_dir: PathBuf::from(csharp_out),
+                out_namespace: csharp_namespace,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn transform(&self, input: &Input) -> Result<Self::Ast, Error> {
+        let mut ir = Ir::from_input(
+            &input.name.to_pascal_case(),
+            input,
+            self.strict,
+            &self.names,
+            self.collisions,
+            RESERVED_TYPE_NAMES,
+            "csharp",
+            &self.types,
+        )?;
+
+        ir.name_members(self.collisions, &Self::property_name, &Self::member_name)?;
+        Ok(ir)
+    }
+
+    fn serialize(&self, ir: &Self::Ast, output: &mut codegen::Output) -> Result<(), Error> {
+        let out_file_name = format!("{}.cs", ir.module.to_pascal_case());
+        let mut out = output.create(self.out_dir.join(out_file_name));
+
+        // The output for every input goes into the same namespace, so types
+        // from other inputs need no usings.
+        let mut usings = USINGS.to_vec();
+        usings.extend(ir.mapped_imports().keys());
+        usings.sort();
+        usings.dedup();
+
+        writeln!(out, "#nullable enable")?;
+        writeln!(out)?;
+        for using in usings {
+            writeln!(out, "using {};", using)?;
+        }
+        writeln!(out)?;
+        writeln!(out, "namespace {};", self.out_namespace)?;
+
+        for decl in ir.local_decls() {
+            match decl.kind {
+                // C# has no type aliases. References to aliases are resolved
+                // to the aliased type instead.
+                DeclKind::Alias(_) => {}
+                DeclKind::Record(ref fields) => {
+                    writeln!(out)?;
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    self.serialize_record(&mut out, ir, &decl.name, None, fields)?;
+                }
+                DeclKind::Enum(ref vals) => {
+                    writeln!(out)?;
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    writeln!(
+                        out,
+                        "[JsonConverter(typeof(JsonStringEnumConverter<{}>))]",
+                        decl.name
+                    )?;
+                    writeln!(out, "public enum {}", decl.name)?;
+                    writeln!(out, "{{")?;
+                    for val in vals {
+                        let name = Self::member_name(&decl.name, val);
+                        if name != val.value {
+                            writeln!(out, "    [JsonStringEnumMemberName({:?})]", val.value)?;
+                        }
+                        writeln!(out, "    {},", name)?;
+                    }
+                    writeln!(out, "}}")?;
+                }
+                DeclKind::Union(ref tag, ref variants) => {
+                    writeln!(out)?;
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    writeln!(
+                        out,
+                        "[JsonPolymorphic(TypeDiscriminatorPropertyName = {:?})]",
+                        tag
+                    )?;
+                    for variant in variants {
+                        writeln!(
+                            out,
+                            "[JsonDerivedType(typeof({}), {:?})]",
+                            variant.name, variant.tag_value
+                        )?;
+                    }
+                    writeln!(out, "public abstract record {};", decl.name)?;
+
+                    for variant in variants {
+                        writeln!(out)?;
+                        self.serialize_description(&mut out, "", &variant.description)?;
+                        self.serialize_record(
+                            &mut out,
+                            ir,
+                            &variant.name,
+                            Some(&decl.name),
+                            &variant.fields,
+                        )?;
+                    }
+                }
+            };
+        }
+
+        Ok(())
+    }
+}
+
+impl Codegen {
+    // Write out a record holding the properties of an object. If a parent is
+    // provided, the record is one case of a discriminated union.
+    fn serialize_record(
+        &self,
+        out: &mut dyn Write,
+        ir: &Ir,
+        name: &str,
+        parent: Option<&str>,
+        fields: &[Field],
+    ) -> Result<(), Error> {
+        // System.Text.Json skips over unknown properties by default, which is
+        // only what the schema calls for when it isn't being used strictly.
+        if ir.strict {
+            writeln!(
+                out,
+                "[JsonUnmappedMemberHandling(JsonUnmappedMemberHandling.Disallow)]"
+            )?;
+        }
+
+        let declaration = match parent {
+            Some(parent) => format!("public sealed record {} : {}", name, parent),
+            None => format!("public sealed record {}", name),
+        };
+
+        if fields.is_empty() {
+            writeln!(out, "{};", declaration)?;
+            return Ok(());
+        }
+
+        writeln!(out, "{}", declaration)?;
+        writeln!(out, "{{")?;
+        for (i, field) in fields.iter().enumerate() {
+            if i != 0 {
+                writeln!(out)?;
+            }
+
+            self.serialize_description(out, "    ", &field.description)?;
+            writeln!(out, "    [JsonPropertyName({:?})]", field.name)?;
+
+            let property_name = Self::property_name(name, field);
+            if field.required {
+                writeln!(
+                    out,
+                    "    public required {} {} {{ get; init; }}",
+                    self.type_expr(ir, &field.value),
+                    property_name
+                )?;
+            } else {
+                writeln!(
+                    out,
+                    "    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]"
+                )?;
+                writeln!(
+                    out,
+                    "    public {}? {} {{ get; init; }}",
+                    self.type_expr(ir, &field.value),
+                    property_name
+                )?;
+            }
+        }
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
+
+    // The name of the C# property for a JSON property. Members can't have the
+    // same name as the type they belong to, so that's avoided as well.
+    fn property_name(type_name: &str, field: &Field) -> String {
+        if let Some(ref identifier) = field.identifier {
+            return identifier.clone();
+        }
+
+        let reserved = [RESERVED_MEMBER_NAMES, &[type_name]].concat();
+        codegen::identifier(&field.name.to_pascal_case(), "_", &reserved)
+    }
+
+    // The name of the enum member for a value. As with properties, this can't
+    // be the name of the enum itself.
+    fn member_name(type_name: &str, val: &EnumMember) -> String {
+        match val.identifier {
+            Some(ref identifier) => identifier.clone(),
+            None => codegen::identifier(&val.value.to_pascal_case(), "_", &[type_name]),
+        }
+    }
+
+    fn type_expr(&self, ir: &Ir, typ: &Type) -> String {
+        match ir.resolve(typ) {
+            Type::Any => "JsonElement".to_owned(),
+            Type::Boolean => "bool".to_owned(),
+            Type::Number => "double".to_owned(),
+            Type::String => "string".to_owned(),
+            Type::Timestamp => "DateTimeOffset".to_owned(),
+            Type::Mapped(_, ref mapping) => mapping.name.clone(),
+            Type::Ref(ref id) => id.clone(),
+            Type::Elements(ref typ) => format!("List<{}>", self.type_expr(ir, typ)),
+            Type::Values(ref typ) => {
+                format!("Dictionary<string, {}>", self.type_expr(ir, typ))
+            }
+        }
+    }
+
+    fn serialize_description(
+        &self,
+        out: &mut dyn Write,
+        indent: &str,
+        description: &Option<String>,
+    ) -> Result<(), Error> {
+        if let Some(ref description) = description {
+            writeln!(out, "{}/// <summary>", indent)?;
+            for line in description.lines() {
+                let line = line
+                    .replace('&', "&amp;")
+                    .replace('<', "&lt;")
+                    .replace('>', "&gt;");
+                let line = format!("{}/// {}", indent, line);
+                writeln!(out, "{}", line.trim_end())?;
+            }
+            writeln!(out, "{}/// </summary>", indent)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,574 @@
+use crate::codegen;
+use crate::codegen::ir::{DeclKind, EnumMember, Field, Ir, Type, Variant};
+use crate::config::{Collisions, Config, TypeMapping};
+use crate::input::Input;
+use failure::Error;
+use inflector::Inflector;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+
+// Words which can't be used as identifiers. Dart's built-in identifiers, like
+// `get` and `required`, are fine as member names, so only reserved words are
+// here.
+const KEYWORDS: &[&str] = &[
+    "assert", "break", "case", "catch", "class", "const", "continue", "default", "do", "else",
+    "enum", "extends", "false", "final", "finally", "for", "if", "in", "is", "new", "null",
+    "rethrow", "return", "super", "switch", "this", "throw", "true", "try", "var", "void", "while",
+    "with",
+];
+
+// Names fields and enum values can't have, since every Dart object already has
+// members by these names, or since generated code declares them itself.
+const RESERVED_MEMBER_NAMES: &[&str] = &[
+    "fromJson",
+    "hashCode",
+    "noSuchMethod",
+    "runtimeType",
+    "toJson",
+    "toString",
+];
+
+// Names enum values can't have on top of those above, since enums have members
+// by these names too.
+const RESERVED_VALUE_NAMES: &[&str] = &["index", "value", "values"];
+
+// Names generated classes can't have, since generated code uses them itself.
+const RESERVED_TYPE_NAMES: &[&str] = &[
+    "DateTime",
+    "Enum",
+    "FormatException",
+    "Function",
+    "List",
+    "Map",
+    "MapEntry",
+    "Never",
+    "Null",
+    "Object",
+    "Record",
+    "String",
+    "Type",
+];
+
+#[derive(Debug)]
+pub struct Codegen {
+    strict: bool,
+    names: HashMap<String, String>,
+    collisions: Collisions,
+    types: HashMap<String, TypeMapping>,
+    out_dir: PathBuf,
+}
+
+impl codegen::Codegen for Codegen {
+    type Ast = Ir;
+
+    fn args<'a, 'b>(app: clap::App<'a, 'b>) -> clap::App<'a, 'b> {
+        app.arg(
+            clap::Arg::with_name("dart-out")
+                .help("Dart output directory")
+                .takes_value(true)
+                .long("dart-out"),
+        )
+    }
+
+    fn from_args(matches: &clap::ArgMatches, config: &Config) -> Result<Option<Codegen>, Error> {
+        let mut config = config.clone();
+        if let Some(dart_out) = matches.value_of("dart-out") {
+            config.dart.out = Some(dart_out.to_owned());
+        }
+
+        Self::from_config(&config)
+    }
+
+    fn from_config(config: &Config) -> Result<Option<Codegen>, Error> {
+        if let Some(ref dart_out) = config.dart.out {
+            Ok(Some(Codegen {
+                strict: config.strict,
+                names: config.names.clone(),
+                collisions: config.collisions,
+                types: config.dart.types.clone(),
+                out_dir: PathBuf::from(dart_out),
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn transform(&self, input: &Input) -> Result<Self::Ast, Error> {
+        let mut ir = Ir::from_input(
+            &input.name.to_pascal_case(),
+            input,
+            self.strict,
+            &self.names,
+            self.collisions,
+            RESERVED_TYPE_NAMES,
+            "dart",
+            &self.types,
+        )?;
+
+        ir.name_members(
+            self.collisions,
+            &|_, field| Self::field_name(field),
+            &|_, val| Self::value_name(val),
+        )?;
+        Ok(ir)
+    }
+
+    fn serialize(&self, ir: &Self::Ast, output: &mut codegen::Output) -> Result<(), Error> {
+        let mut out = output.create(self.out_dir.join(Self::out_file_name(&ir.module)));
+
+        let mut imports = Vec::new();
+        for (library, names) in ir.mapped_imports() {
+            let names: Vec<_> = names.into_iter().collect();
+            imports.push(format!(
+                "{} show {}",
+                Self::string(library),
+                names.join(", ")
+            ));
+        }
+
+        // The output for each input is a library of its own, and they're
+        // expected to live in the same directory.
+        for (module, names) in ir.imports() {
+            let names: Vec<_> = names.into_iter().collect();
+            imports.push(format!(
+                "{} show {}",
+                Self::string(&Self::out_file_name(module)),
+                names.join(", ")
+            ));
+        }
+
+        for import in &imports {
+            writeln!(out, "import {};", import)?;
+        }
+
+        for (i, decl) in ir.local_decls().enumerate() {
+            if i != 0 || !imports.is_empty() {
+                writeln!(out)?;
+            }
+
+            match decl.kind {
+                DeclKind::Alias(ref typ) => {
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    writeln!(out, "typedef {} = {};", decl.name, Self::type_expr(typ))?;
+                }
+                DeclKind::Record(ref fields) => {
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    self.serialize_class(&mut out, ir, &decl.name, None, fields)?;
+                }
+                DeclKind::Enum(ref vals) => {
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    self.serialize_enum(&mut out, &decl.name, vals)?;
+                }
+                DeclKind::Union(ref tag, ref variants) => {
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    self.serialize_union(&mut out, &decl.name, tag, variants)?;
+
+                    for variant in variants {
+                        writeln!(out)?;
+                        self.serialize_description(&mut out, "", &variant.description)?;
+                        self.serialize_class(
+                            &mut out,
+                            ir,
+                            &variant.name,
+                            Some((&decl.name, tag, &variant.tag_value)),
+                            &variant.fields,
+                        )?;
+                    }
+                }
+            };
+        }
+
+        Ok(())
+    }
+}
+
+impl Codegen {
+    fn out_file_name(name: &str) -> String {
+        format!("{}.dart", name.to_snake_case())
+    }
+
+    // Write out a class holding the properties of an object. If a parent is
+    // provided, the class is one case of a discriminated union. The parent is
+    // given as the name of the union's sealed class, the name of the tag, and
+    // the tag value for this case.
+    fn serialize_class(
+        &self,
+        out: &mut dyn Write,
+        ir: &Ir,
+        name: &str,
+        parent: Option<(&str, &str, &str)>,
+        fields: &[Field],
+    ) -> Result<(), Error> {
+        match parent {
+            Some((parent, _, _)) => writeln!(out, "class {} extends {} {{", name, parent)?,
+            None => writeln!(out, "class {} {{", name)?,
+        };
+
+        for field in fields {
+            self.serialize_description(out, "  ", &field.description)?;
+            let typ = Self::type_expr(&field.value);
+            if field.required {
+                writeln!(out, "  final {} {};", typ, Self::field_name(field))?;
+            } else {
+                writeln!(
+                    out,
+                    "  final {} {};",
+                    Self::nullable(&typ),
+                    Self::field_name(field)
+                )?;
+            }
+        }
+
+        if !fields.is_empty() {
+            writeln!(out)?;
+            writeln!(out, "  const {}({{", name)?;
+            for field in fields {
+                if field.required {
+                    writeln!(out, "    required this.{},", Self::field_name(field))?;
+                } else {
+                    writeln!(out, "    this.{},", Self::field_name(field))?;
+                }
+            }
+            writeln!(out, "  }});")?;
+        } else {
+            writeln!(out, "  const {}();", name)?;
+        }
+
+        writeln!(out)?;
+        writeln!(
+            out,
+            "  factory {}.fromJson(Map<String, dynamic> json) {{",
+            name
+        )?;
+        if ir.strict {
+            let mut known: Vec<_> = fields
+                .iter()
+                .map(|field| Self::string(&field.name))
+                .collect();
+            if let Some((_, tag, _)) = parent {
+                known.push(Self::string(tag));
+            }
+
+            writeln!(
+                out,
+                "    final unknown = json.keys.where((key) => !const <String>{{{}}}.contains(key));",
+                known.join(", ")
+            )?;
+            writeln!(out, "    if (unknown.isNotEmpty) {{")?;
+            writeln!(
+                out,
+                "      throw FormatException(\"unknown properties: ${{unknown.join(\", \")}}\");"
+            )?;
+            writeln!(out, "    }}")?;
+            writeln!(out)?;
+        }
+
+        if fields.is_empty() {
+            writeln!(out, "    return const {}();", name)?;
+        } else {
+            writeln!(out, "    return {}(", name)?;
+            for field in fields {
+                let access = format!("json[{}]", Self::string(&field.name));
+                let value = Self::from_json_expr(ir, &field.value, &access, 0);
+
+                // Plain casts can just as well cast to a nullable type.
+                // Anything else has to check for the property first.
+                let value = if field.required {
+                    value
+                } else if value == access {
+                    access
+                } else if value == format!("{} as {}", access, Self::type_expr(&field.value)) {
+                    format!("{}?", value)
+                } else {
+                    format!("{} == null ? null : {}", access, value)
+                };
+
+                writeln!(out, "      {}: {},", Self::field_name(field), value)?;
+            }
+            writeln!(out, "    );")?;
+        }
+        writeln!(out, "  }}")?;
+        writeln!(out)?;
+
+        if parent.is_some() {
+            writeln!(out, "  @override")?;
+        }
+        writeln!(out, "  Map<String, dynamic> toJson() {{")?;
+
+        let mut entries = Vec::new();
+        if let Some((_, tag, tag_value)) = parent {
+            entries.push(format!(
+                "{}: {}",
+                Self::string(tag),
+                Self::string(tag_value)
+            ));
+        }
+
+        for field in fields {
+            let field_name = Self::field_name(field);
+            if field.required {
+                let value = Self::to_json_expr(ir, &field.value, &field_name, 0);
+                entries.push(format!("{}: {}", Self::string(&field.name), value));
+            } else {
+                // Fields are never promoted to non-null, since they're
+                // public, so they have to be asserted to be non-null before
+                // they can be converted.
+                let access = format!("{}!", field_name);
+                let value = Self::to_json_expr(ir, &field.value, &access, 0);
+                let value = if value == access { &field_name } else { &value };
+                entries.push(format!(
+                    "if ({} != null) {}: {}",
+                    field_name,
+                    Self::string(&field.name),
+                    value
+                ));
+            }
+        }
+
+        if entries.is_empty() {
+            writeln!(out, "    return {{}};")?;
+        } else {
+            writeln!(out, "    return {{")?;
+            for entry in entries {
+                writeln!(out, "      {},", entry)?;
+            }
+            writeln!(out, "    }};")?;
+        }
+        writeln!(out, "  }}")?;
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
+
+    fn serialize_enum(
+        &self,
+        out: &mut dyn Write,
+        name: &str,
+        vals: &[EnumMember],
+    ) -> Result<(), Error> {
+        writeln!(out, "enum {} {{", name)?;
+        for (i, val) in vals.iter().enumerate() {
+            let end = if i == vals.len() - 1 { ";" } else { "," };
+            writeln!(
+                out,
+                "  {}({}){}",
+                Self::value_name(val),
+                Self::string(&val.value),
+                end
+            )?;
+        }
+        writeln!(out)?;
+        writeln!(out, "  const {}(this.value);", name)?;
+        writeln!(out)?;
+        writeln!(out, "  final String value;")?;
+        writeln!(out)?;
+        writeln!(out, "  factory {}.fromJson(String json) {{", name)?;
+        writeln!(out, "    return values.firstWhere(")?;
+        writeln!(out, "      (value) => value.value == json,")?;
+        writeln!(
+            out,
+            "      orElse: () => throw FormatException({}, json),",
+            Self::string(&format!("unknown {} value", name))
+        )?;
+        writeln!(out, "    );")?;
+        writeln!(out, "  }}")?;
+        writeln!(out)?;
+        writeln!(out, "  String toJson() => value;")?;
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
+
+    // Write out the sealed class the cases of a discriminated union extend.
+    // Parsing goes through here, and picks a case by the value of the tag.
+    fn serialize_union(
+        &self,
+        out: &mut dyn Write,
+        name: &str,
+        tag: &str,
+        variants: &[Variant],
+    ) -> Result<(), Error> {
+        let tag = Self::string(tag);
+
+        writeln!(out, "sealed class {} {{", name)?;
+        writeln!(out, "  const {}();", name)?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "  factory {}.fromJson(Map<String, dynamic> json) {{",
+            name
+        )?;
+        writeln!(out, "    return switch (json[{}]) {{", tag)?;
+        for variant in variants {
+            writeln!(
+                out,
+                "      {} => {}.fromJson(json),",
+                Self::string(&variant.tag_value),
+                variant.name
+            )?;
+        }
+        writeln!(
+            out,
+            "      _ => throw FormatException({}, json[{}]),",
+            Self::string(&format!("unknown {} tag", name)),
+            tag
+        )?;
+        writeln!(out, "    }};")?;
+        writeln!(out, "  }}")?;
+        writeln!(out)?;
+        writeln!(out, "  Map<String, dynamic> toJson();")?;
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
+
+    // The name of the Dart field for a JSON property. Names starting with an
+    // underscore are private in Dart, so names which don't start with a letter
+    // get a dollar sign in front of them instead.
+    fn field_name(field: &Field) -> String {
+        match field.identifier {
+            Some(ref identifier) => identifier.clone(),
+            None => codegen::identifier(
+                &field.name.to_camel_case(),
+                "$",
+                &[KEYWORDS, RESERVED_MEMBER_NAMES].concat(),
+            ),
+        }
+    }
+
+    fn value_name(val: &EnumMember) -> String {
+        match val.identifier {
+            Some(ref identifier) => identifier.clone(),
+            None => codegen::identifier(
+                &val.value.to_camel_case(),
+                "$",
+                &[KEYWORDS, RESERVED_MEMBER_NAMES, RESERVED_VALUE_NAMES].concat(),
+            ),
+        }
+    }
+
+    // A string literal. Dart strings are templates, so dollar signs have to be
+    // escaped on top of what Rust escapes.
+    fn string(s: &str) -> String {
+        format!("{:?}", s).replace('$', "\\$")
+    }
+
+    // Make a type nullable. Values of any type can already be null.
+    fn nullable(typ: &str) -> String {
+        if typ.ends_with('?') {
+            typ.to_owned()
+        } else {
+            format!("{}?", typ)
+        }
+    }
+
+    fn type_expr(typ: &Type) -> String {
+        match typ {
+            Type::Any => "Object?".to_owned(),
+            Type::Boolean => "bool".to_owned(),
+            Type::Number => "double".to_owned(),
+            Type::String => "String".to_owned(),
+            Type::Timestamp => "DateTime".to_owned(),
+            Type::Mapped(_, ref mapping) => mapping.name.clone(),
+            Type::Ref(ref id) => id.clone(),
+            Type::Elements(ref typ) => format!("List<{}>", Self::type_expr(typ)),
+            Type::Values(ref typ) => format!("Map<String, {}>", Self::type_expr(typ)),
+        }
+    }
+
+    // Produce a Dart expression converting `expr`, the decoded JSON for an
+    // instance of `typ`, into its typed representation. Depth is used to keep
+    // closure parameters from shadowing one another.
+    fn from_json_expr(ir: &Ir, typ: &Type, expr: &str, depth: usize) -> String {
+        match ir.resolve(typ) {
+            Type::Any => expr.to_owned(),
+
+            // JSON doesn't tell integers apart from other numbers, and
+            // decoding gives whole numbers as ints.
+            Type::Number => format!("({} as num).toDouble()", expr),
+            Type::Timestamp => format!("DateTime.parse({} as String)", expr),
+            Type::Ref(ref id) => match ir.decl(id).map(|decl| &decl.kind) {
+                Some(DeclKind::Enum(_)) => format!("{}.fromJson({} as String)", id, expr),
+                _ => format!("{}.fromJson({} as Map<String, dynamic>)", id, expr),
+            },
+            Type::Elements(ref inner) => {
+                let var = format!("v{}", depth);
+                let value = Self::from_json_expr(ir, inner, &var, depth + 1);
+                if value == var {
+                    format!("{} as {}", expr, Self::type_expr(typ))
+                } else {
+                    format!(
+                        "({} as List<dynamic>).map(({}) => {}).toList()",
+                        expr, var, value
+                    )
+                }
+            }
+            Type::Values(ref inner) => {
+                let key = format!("k{}", depth);
+                let var = format!("v{}", depth);
+                let value = Self::from_json_expr(ir, inner, &var, depth + 1);
+                if value == var {
+                    format!("{} as {}", expr, Self::type_expr(typ))
+                } else {
+                    format!(
+                        "({} as Map<String, dynamic>).map(({}, {}) => MapEntry({}, {}))",
+                        expr, key, var, key, value
+                    )
+                }
+            }
+
+            // Values of types mapped by the user are passed along as they are
+            // in JSON.
+            typ => format!("{} as {}", expr, Self::type_expr(typ)),
+        }
+    }
+
+    // The inverse of `from_json_expr`.
+    fn to_json_expr(ir: &Ir, typ: &Type, expr: &str, depth: usize) -> String {
+        match ir.resolve(typ) {
+            Type::Any | Type::Boolean | Type::Number | Type::String => expr.to_owned(),
+            Type::Timestamp => format!("{}.toUtc().toIso8601String()", expr),
+            Type::Mapped(..) => expr.to_owned(),
+            Type::Ref(_) => format!("{}.toJson()", expr),
+            Type::Elements(ref typ) => {
+                let var = format!("v{}", depth);
+                let value = Self::to_json_expr(ir, typ, &var, depth + 1);
+                if value == var {
+                    expr.to_owned()
+                } else {
+                    format!("{}.map(({}) => {}).toList()", expr, var, value)
+                }
+            }
+            Type::Values(ref typ) => {
+                let key = format!("k{}", depth);
+                let var = format!("v{}", depth);
+                let value = Self::to_json_expr(ir, typ, &var, depth + 1);
+                if value == var {
+                    expr.to_owned()
+                } else {
+                    format!(
+                        "{}.map(({}, {}) => MapEntry({}, {}))",
+                        expr, key, var, key, value
+                    )
+                }
+            }
+        }
+    }
+
+    fn serialize_description(
+        &self,
+        out: &mut dyn Write,
+        indent: &str,
+        description: &Option<String>,
+    ) -> Result<(), Error> {
+        if let Some(ref description) = description {
+            for line in description.lines() {
+                let line = format!("{}/// {}", indent, line);
+                writeln!(out, "{}", line.trim_end())?;
+            }
+        }
+
+        Ok(())
+    }
+}
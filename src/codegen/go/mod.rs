@@ -1,48 +1,72 @@
 use crate::codegen;
-use failure::{format_err, Error};
+use crate::codegen::ir::{self, Decl, DeclKind, EnumMember, Field, Ir, Type, Variant};
+use crate::config::{Collisions, Config, TypeMapping};
+use crate::input::Input;
+use failure::{bail, Error};
 use inflector::Inflector;
-use jsl::schema::{Form, Type};
-use jsl::Schema;
 use std::collections::HashMap;
-
-use std::fs::File;
-use std::io::{BufWriter, Write};
+use std::io::Write;
 use std::path::PathBuf;
+
 #[derive(Debug)]
 pub struct Codegen {
-    root_name: String,
-    out_path: PathBuf,
+    strict: bool,
+    names: HashMap<String, String>,
+    collisions: Collisions,
+    types: HashMap<String, TypeMapping>,
+    out_dir: PathBuf,
     out_pkg: String,
 }
 
+// The file holding the helpers shared by the code generated for every input.
+const HELPERS_FILE_NAME: &str = "jsl.go";
+
+// Names generated types can't have, since the helpers use them.
+const RESERVED_TYPE_NAMES: &[&str] = &["ValidationError", "ValidationErrors"];
+
 impl codegen::Codegen for Codegen {
-    type Ast = Vec<Ast>;
+    type Ast = Ir;
 
     fn args<'a, 'b>(app: clap::App<'a, 'b>) -> clap::App<'a, 'b> {
-        app.arg(
+        app.args(&[
             clap::Arg::with_name("go-out")
                 .help("Go output directory")
                 .takes_value(true)
                 .long("go-out"),
-        )
+            clap::Arg::with_name("go-pkg")
+                .help("Go output package name")
+                .takes_value(true)
+                .long("go-pkg"),
+        ])
     }
 
-    fn from_args(matches: &clap::ArgMatches) -> Result<Option<Codegen>, Error> {
+    fn from_args(matches: &clap::ArgMatches, config: &Config) -> Result<Option<Codegen>, Error> {
+        let mut config = config.clone();
         if let Some(go_out) = matches.value_of("go-out") {
-            let input = PathBuf::from(matches.value_of("INPUT").unwrap());
-            let input_stem = input
-                .file_stem()
-                .ok_or(format_err!("Could not infer file stem from input"))?;
-            let input_stem_str = input_stem
-                .to_str()
-                .ok_or(format_err!("Could not convert input file name to UTF-8"))?;
-            let root_name = input_stem_str.to_class_case();
-            let pkg_name = go_out.split("/").last().unwrap();
-            let out_file_name = format!("{}.go", pkg_name);
+            config.go.out = Some(go_out.to_owned());
+        }
+        if let Some(pkg) = matches.value_of("go-pkg") {
+            config.go.package = Some(pkg.to_owned());
+        }
+
+        Self::from_config(&config)
+    }
+
+    fn from_config(config: &Config) -> Result<Option<Codegen>, Error> {
+        if let Some(ref go_out) = config.go.out {
+            // By default, the package is named after the directory it's in.
+            let pkg_name = config
+                .go
+                .package
+                .as_deref()
+                .unwrap_or_else(|| go_out.rsplit('/').next().unwrap());
 
             Ok(Some(Codegen {
-                root_name,
-                out_path: PathBuf::from(go_out).join(out_file_name),
+                strict: config.strict,
+                names: config.names.clone(),
+                collisions: config.collisions,
+                types: config.go.types.clone(),
+                out_dir: PathBuf::from(go_out),
                 out_pkg: pkg_name.to_owned(),
             }))
         } else {
@@ -50,315 +74,772 @@ impl codegen::Codegen for Codegen {
         }
     }
 
-    fn transform(&self, schema: &Schema) -> Result<Self::Ast, Error> {
-        let mut out = Vec::new();
-
-        // First, generate each of the definitions.
-        for (name, def) in schema.definitions().as_ref().unwrap() {
-            let mut path = vec![name.clone()];
-            let ast = self.transform_subschema(&mut out, &mut path, def);
-            self.transform_for_id(&mut out, &path, ast);
+    fn transform(&self, input: &Input) -> Result<Self::Ast, Error> {
+        if Self::out_file_name(&input.name) == HELPERS_FILE_NAME {
+            bail!(
+                "Go output for {} would overwrite {}",
+                input.name,
+                HELPERS_FILE_NAME
+            );
         }
 
-        // Then, generate the root schema.
-        let mut path = vec![self.root_name.clone()];
-        let ast = self.transform_subschema(&mut out, &mut path, schema);
-        self.transform_for_id(&mut out, &path, ast);
-
-        Ok(out)
+        let mut ir = Ir::from_input(
+            &input.name.to_pascal_case(),
+            input,
+            self.strict,
+            &self.names,
+            self.collisions,
+            RESERVED_TYPE_NAMES,
+            "go",
+            &self.types,
+        )?;
+
+        ir.name_members(
+            self.collisions,
+            &|_, field| Self::field_name(field),
+            &Self::constant_name,
+        )?;
+        Ok(ir)
     }
 
-    fn serialize(&self, asts: &Self::Ast) -> Result<(), Error> {
-        let mut out = BufWriter::new(File::create(self.out_path.clone())?);
+    fn serialize(&self, ir: &Self::Ast, output: &mut codegen::Output) -> Result<(), Error> {
+        // The code for every input goes into the same package, so the helpers
+        // it all shares live in a file of their own.
+        self.serialize_helpers(output)?;
+
+        let out_path = self.out_dir.join(Self::out_file_name(&ir.module));
+        let mut out = output.create(out_path);
         writeln!(out, "package {}", self.out_pkg)?;
 
-        for ast in asts {
-            self.serialize_subschema(&mut out, ast)?;
+        // Go refuses to compile a file with unused imports, so only import
+        // the packages the generated code actually refers to.
+        //
+        // The decoding helpers always use encoding/json.
+        let mut imports = vec!["encoding/json"];
+
+        // Validators convert array indices into instance path tokens.
+        if ir.uses(&|typ| match typ {
+            Type::Elements(ref sub_typ) => !matches!(**sub_typ, Type::Any),
+            _ => false,
+        }) {
+            imports.push("strconv");
         }
 
-        Ok(())
-    }
-}
+        if ir.uses(&|typ| matches!(typ, Type::Timestamp)) {
+            imports.push("time");
+        }
 
-impl Codegen {
-    fn transform_subschema(
-        &self,
-        out: &mut Vec<Ast>,
-        path: &mut Vec<String>,
-        schema: &Schema,
-    ) -> Ast {
-        match schema.form() {
-            Form::Empty => Ast::Any,
-            Form::Ref(ref def) => Ast::Identifier(Self::path_to_identifier(&[def.clone()])),
-            Form::Type(ref typ) => match typ {
-                Type::Boolean => Ast::Boolean,
-                Type::Number => Ast::Number,
-                Type::String => Ast::String,
-                Type::Timestamp => Ast::Time,
-            },
-            Form::Enum(ref vals) => {
-                let name = Self::path_to_identifier(&path);
-                out.push(Ast::Type(name.clone(), Box::new(Ast::String)));
-                for val in vals {
-                    out.push(Ast::Var(
-                        (name.to_owned() + "_" + val).to_camel_case(),
-                        Box::new(Ast::Identifier(name.to_owned())),
-                        Box::new(Ast::Literal(val.to_owned())),
-                    ));
-                }
+        let mapped_imports = ir.mapped_imports();
+        imports.extend(mapped_imports.keys());
+        imports.sort();
+        imports.dedup();
 
-                Ast::Identifier(name)
-            }
-            Form::Elements(ref sub_schema) => {
-                Ast::Array(Box::new(self.transform_subschema(out, path, sub_schema)))
+        if !imports.is_empty() {
+            writeln!(out)?;
+            writeln!(out, "import (")?;
+            for import in imports {
+                writeln!(out, "\t{:?}", import)?;
             }
-            Form::Properties(ref required, ref optional, _) => {
-                let mut props = Vec::new();
-                for (name, prop) in required {
-                    props.push(self.transform_prop(out, path, true, name, prop));
+            writeln!(out, ")")?;
+        }
+
+        for decl in ir.local_decls() {
+            writeln!(out)?;
+
+            match decl.kind {
+                DeclKind::Alias(ref typ) => {
+                    self.serialize_description(&mut out, "", &decl.description)?;
+
+                    // A new named type would have none of the methods of the
+                    // type it's made from, so types mapped by the user, which
+                    // often rely on their own MarshalJSON and UnmarshalJSON,
+                    // get a true alias instead.
+                    if let Type::Mapped(..) = ir.resolve(typ) {
+                        writeln!(out, "type {} = {}", decl.name, self.type_expr(typ))?;
+                    } else {
+                        writeln!(out, "type {} {}", decl.name, self.type_expr(typ))?;
+                    }
+                }
+                DeclKind::Record(ref fields) => {
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    self.serialize_struct(&mut out, ir, &decl.name, fields)?;
                 }
+                DeclKind::Enum(ref vals) => {
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    writeln!(out, "type {} string", decl.name)?;
+                    writeln!(out)?;
+                    writeln!(out, "const (")?;
+                    for val in vals {
+                        let name = Self::constant_name(&decl.name, val);
+                        writeln!(out, "\t{} {} = {:?}", name, decl.name, val.value)?;
+                    }
+                    writeln!(out, ")")?;
+                }
+                DeclKind::Union(ref tag, ref variants) => {
+                    for variant in variants {
+                        self.serialize_description(&mut out, "", &variant.description)?;
+                        self.serialize_struct(&mut out, ir, &variant.name, &variant.fields)?;
+                        writeln!(out)?;
+                    }
 
-                for (name, prop) in optional {
-                    props.push(self.transform_prop(out, path, false, name, prop));
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    self.serialize_union(&mut out, &decl.name, tag, variants)?;
                 }
+            };
 
-                let id = Self::path_to_identifier(path);
-                out.push(Ast::Struct(id.clone(), props, None));
-                Ast::Identifier(id)
-            }
-            Form::Values(ref sub_schema) => {
-                Ast::Map(Box::new(self.transform_subschema(out, path, sub_schema)))
-            }
-            Form::Discriminator(ref tag, ref mapping) => {
-                let mut cases = Vec::new();
-                let mut case_names = Vec::new();
-                for (name, case) in mapping {
-                    path.push(name.clone());
-
-                    let mut props = Vec::new();
-                    let (required, optional) = match case.form() {
-                        Form::Properties(ref required, ref optional, _) => (required, optional),
-                        _ => unreachable!("non-prop form in mapping"),
-                    };
-
-                    for (name, prop) in required {
-                        props.push(self.transform_prop(out, path, true, name, prop));
-                    }
+            writeln!(out)?;
+            self.serialize_validator(&mut out, ir, decl)?;
+            writeln!(out)?;
+            self.serialize_decoder(&mut out, decl)?;
+        }
 
-                    for (name, prop) in optional {
-                        props.push(self.transform_prop(out, path, false, name, prop));
-                    }
+        Ok(())
+    }
+}
 
-                    let id = Self::path_to_identifier(path);
-                    out.push(Ast::Struct(id.clone(), props, None));
-                    cases.push(Ast::Identifier(id.clone()));
-                    case_names.push(name.clone());
+impl Codegen {
+    // Make a name exported, which encoding/json needs of struct fields, by
+    // prefixing it if it doesn't start with an upper case letter.
+    fn exported(name: &str) -> String {
+        if name.starts_with(char::is_uppercase) {
+            name.to_owned()
+        } else {
+            format!("X{}", name)
+        }
+    }
 
-                    path.pop();
-                }
+    // The name of the struct field for a property.
+    fn field_name(field: &Field) -> String {
+        match field.identifier {
+            Some(ref identifier) => identifier.clone(),
+            None => Self::exported(&field.name.to_pascal_case()),
+        }
+    }
 
-                let mut mapping = HashMap::new();
-                for (name, case) in case_names.into_iter().zip(cases) {
-                    mapping.insert(name, case);
-                }
+    // The name of the constant for an enum value. A chosen name stands for
+    // the whole constant, rather than being tacked onto the name of the type.
+    fn constant_name(type_name: &str, val: &EnumMember) -> String {
+        match val.identifier {
+            Some(ref identifier) => identifier.clone(),
+            None => format!(
+                "{}{}",
+                type_name,
+                Self::exported(&val.value.to_pascal_case())
+            ),
+        }
+    }
+
+    fn out_file_name(module: &str) -> String {
+        format!("{}.go", module.to_snake_case())
+    }
 
-                let id = Self::path_to_identifier(&path);
-                let tag_name = tag.to_class_case();
-
-                let props = vec![
-                    Property {
-                        name: tag_name,
-                        json_name: tag.clone(),
-                        value: Ast::String,
-                    },
-                    Property {
-                        name: "Val".to_owned(),
-                        json_name: "-".to_owned(),
-                        value: Ast::Any,
-                    },
-                ];
-
-                out.push(Ast::Struct(id.clone(), props, Some((tag.clone(), mapping))));
-                Ast::Identifier(id)
+    fn serialize_helpers(&self, output: &mut codegen::Output) -> Result<(), Error> {
+        let out = output.create(self.out_dir.join(HELPERS_FILE_NAME));
+        writeln!(out, "package {}", self.out_pkg)?;
+        writeln!(out)?;
+        writeln!(out, "import \"time\"")?;
+
+        // Every type gets a validator, which reports errors the same way the
+        // jsl crate does: as a pair of JSON Pointers into the instance and the
+        // schema.
+        writeln!(out)?;
+        writeln!(out, "type ValidationError struct {{")?;
+        writeln!(out, "\tInstancePath string")?;
+        writeln!(out, "\tSchemaPath string")?;
+        writeln!(out, "}}")?;
+        writeln!(out)?;
+        writeln!(out, "func jslPointer(path []string) string {{")?;
+        writeln!(out, "\tpointer := \"\"")?;
+        writeln!(out, "\tfor _, token := range path {{")?;
+        writeln!(out, "\t\tpointer += \"/\"")?;
+        writeln!(out, "\t\tfor _, c := range token {{")?;
+        writeln!(out, "\t\t\tswitch c {{")?;
+        writeln!(out, "\t\t\tcase '~':")?;
+        writeln!(out, "\t\t\t\tpointer += \"~0\"")?;
+        writeln!(out, "\t\t\tcase '/':")?;
+        writeln!(out, "\t\t\t\tpointer += \"~1\"")?;
+        writeln!(out, "\t\t\tdefault:")?;
+        writeln!(out, "\t\t\t\tpointer += string(c)")?;
+        writeln!(out, "\t\t\t}}")?;
+        writeln!(out, "\t\t}}")?;
+        writeln!(out, "\t}}")?;
+        writeln!(out, "\treturn pointer")?;
+        writeln!(out, "}}")?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "func jslAppend(path []string, tokens ...string) []string {{"
+        )?;
+        writeln!(
+            out,
+            "\treturn append(append([]string{{}}, path...), tokens...)"
+        )?;
+        writeln!(out, "}}")?;
+        writeln!(out)?;
+        writeln!(out, "type ValidationErrors []ValidationError")?;
+        writeln!(out)?;
+        writeln!(out, "func (e ValidationErrors) Error() string {{")?;
+        writeln!(
+            out,
+            "\treturn \"instance at \\\"\" + e[0].InstancePath + \"\\\" rejected by schema at \\\"\" + e[0].SchemaPath + \"\\\"\""
+        )?;
+        writeln!(out, "}}")?;
+
+        writeln!(out)?;
+        writeln!(out, "func jslIsTimestamp(instance interface{{}}) bool {{")?;
+        writeln!(out, "\ts, ok := instance.(string)")?;
+        writeln!(out, "\tif !ok {{")?;
+        writeln!(out, "\t\treturn false")?;
+        writeln!(out, "\t}}")?;
+        writeln!(out, "\t_, err := time.Parse(time.RFC3339, s)")?;
+        writeln!(out, "\treturn err == nil")?;
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
+
+    fn serialize_struct(
+        &self,
+        out: &mut dyn Write,
+        ir: &Ir,
+        name: &str,
+        fields: &[Field],
+    ) -> Result<(), Error> {
+        writeln!(out, "type {} struct {{", name)?;
+        for field in fields {
+            self.serialize_description(out, "\t", &field.description)?;
+            if field.required {
+                writeln!(
+                    out,
+                    "\t{} {} `json:{:?}`",
+                    Self::field_name(field),
+                    self.type_expr(&field.value),
+                    field.name
+                )?;
+            } else {
+                // Optional properties need to be told apart from properties
+                // holding a zero value. Types which can already be nil don't
+                // need the extra indirection.
+                let pointer = match ir.resolve(&field.value) {
+                    Type::Any | Type::Elements(_) | Type::Values(_) => "",
+                    _ => "*",
+                };
+
+                writeln!(
+                    out,
+                    "\t{} {}{} `json:\"{},omitempty\"`",
+                    Self::field_name(field),
+                    pointer,
+                    self.type_expr(&field.value),
+                    field.name
+                )?;
             }
         }
+        writeln!(out, "}}")?;
+
+        Ok(())
     }
 
-    fn transform_prop(
+    // Write out a struct holding the tag and the value of a discriminated
+    // union, with MarshalJSON and UnmarshalJSON overridden to dispatch on the
+    // tag.
+    fn serialize_union(
         &self,
-        out: &mut Vec<Ast>,
-        path: &mut Vec<String>,
-        required: bool,
+        out: &mut dyn Write,
         name: &str,
-        prop: &Schema,
-    ) -> Property {
-        path.push(name.to_owned());
-        let value = self.transform_subschema(out, path, prop);
-        path.pop();
-
-        Property {
-            name: name.to_pascal_case(),
-            json_name: name.to_owned(),
-            value,
+        tag: &str,
+        variants: &[Variant],
+    ) -> Result<(), Error> {
+        // The struct also has a field holding the value.
+        let tag_name = codegen::identifier(&Self::exported(&tag.to_pascal_case()), "", &["Val"]);
+
+        writeln!(out, "type {} struct {{", name)?;
+        writeln!(out, "\t{} string `json:{:?}`", tag_name, tag)?;
+        writeln!(out, "\tVal interface{{}} `json:\"-\"`")?;
+        writeln!(out, "}}")?;
+        writeln!(out)?;
+
+        writeln!(out, "func (s *{}) UnmarshalJSON(buf []byte) error {{", name)?;
+        writeln!(out, "\tvar x struct{{ Tag string `json:{:?}` }}", tag)?;
+        writeln!(out, "\tif err := json.Unmarshal(buf, &x); err != nil {{")?;
+        writeln!(out, "\t\treturn err")?;
+        writeln!(out, "\t}}")?;
+        writeln!(out, "\tswitch x.Tag {{")?;
+
+        for variant in variants {
+            writeln!(out, "\tcase {:?}:", variant.tag_value)?;
+            writeln!(out, "\t\tvar data {}", variant.name)?;
+            writeln!(
+                out,
+                "\t\tif err := json.Unmarshal(buf, &data); err != nil {{"
+            )?;
+            writeln!(out, "\t\t\treturn err")?;
+            writeln!(out, "\t\t}}")?;
+            writeln!(out, "\t\ts.Val = data")?;
         }
-    }
 
-    // Ensure that an AST will get a top-level identifier, and then return an
-    // AST for an identifier that refers to it.
-    fn transform_for_id(&self, out: &mut Vec<Ast>, path: &Vec<String>, ast: Ast) -> Ast {
-        match ast {
-            Ast::Struct(_, _, _) | Ast::Type(_, _) | Ast::Identifier(_) => {}
-            _ => {
-                out.push(Ast::Type(Self::path_to_identifier(path), Box::new(ast)));
-            }
-        };
+        writeln!(out, "\t}}")?;
+        writeln!(out, "\ts.{} = x.Tag", tag_name)?;
+        writeln!(out, "\treturn nil")?;
+        writeln!(out, "}}")?;
+        writeln!(out)?;
+
+        writeln!(out, "func (s {}) MarshalJSON() ([]byte, error) {{", name)?;
+        writeln!(out, "\tswitch val := s.Val.(type) {{")?;
+
+        for variant in variants {
+            writeln!(out, "\tcase {}:", variant.name)?;
+            writeln!(out, "\t\tvar data struct{{")?;
+            writeln!(out, "\t\t\tTag string `json:{:?}`", tag)?;
+            writeln!(out, "\t\t\t{}", variant.name)?;
+            writeln!(out, "\t\t}}")?;
+            writeln!(out, "\t\tdata.{} = val", variant.name)?;
+            writeln!(out, "\t\tdata.Tag = s.{}", tag_name)?;
+            writeln!(out, "\t\treturn json.Marshal(data)")?;
+        }
 
-        Ast::Identifier(Self::path_to_identifier(path))
+        writeln!(out, "\t}}")?;
+        writeln!(out, "\tpanic(\"invalid discriminator tag\")")?;
+        writeln!(out, "}}")?;
+
+        Ok(())
     }
 
-    fn serialize_subschema(&self, out: &mut Write, ast: &Ast) -> Result<(), Error> {
-        match ast {
-            Ast::Any => write!(out, "interface{{}}")?,
-            Ast::Boolean => write!(out, "bool")?,
-            Ast::Number => write!(out, "float64")?,
-            Ast::String => write!(out, "string")?,
-            Ast::Time => write!(out, "time.Time")?,
-            Ast::Identifier(ref id) => write!(out, "{}", id)?,
-            Ast::Literal(ref lit) => write!(out, "{:?}", lit)?,
-            Ast::Array(ref ast) => {
-                write!(out, "[]")?;
-                self.serialize_subschema(out, ast)?;
+    // Write out the validator for a declaration. The exported function
+    // collects all of the errors for an instance, and the other function is
+    // how validators for other declarations refer to this one.
+    fn serialize_validator(&self, out: &mut dyn Write, ir: &Ir, decl: &Decl) -> Result<(), Error> {
+        let schema_path = &decl.schema_path;
+
+        writeln!(
+            out,
+            "func Validate{}(instance interface{{}}) []ValidationError {{",
+            decl.name
+        )?;
+        writeln!(out, "\tvar errors []ValidationError")?;
+        writeln!(out, "\tvalidate{}(instance, nil, &errors)", decl.name)?;
+        writeln!(out, "\treturn errors")?;
+        writeln!(out, "}}")?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "func validate{}(instance interface{{}}, path []string, errors *[]ValidationError) {{",
+            decl.name
+        )?;
+
+        match decl.kind {
+            DeclKind::Alias(ref typ) => {
+                self.serialize_check(out, typ, "instance", &[], schema_path, 0, "\t")?;
             }
-            Ast::Map(ref ast) => {
-                write!(out, "map[string]")?;
-                self.serialize_subschema(out, ast)?;
-            }
-            Ast::Type(ref id, ref ast) => {
-                write!(out, "type {} ", id)?;
-                self.serialize_subschema(out, ast)?;
-                writeln!(out)?;
+            DeclKind::Record(ref fields) => {
+                // As in jsl, which keyword an error about a non-object is
+                // attributed to depends on which keywords are present.
+                let keyword = if fields.iter().any(|field| field.required) {
+                    "properties"
+                } else {
+                    "optionalProperties"
+                };
+
+                // Go won't allow binding the object if nothing then uses it.
+                if ir.strict || fields.iter().any(Self::needs_check) {
+                    writeln!(out, "\tobj, ok := instance.(map[string]interface{{}})")?;
+                    writeln!(out, "\tif !ok {{")?;
+                    self.serialize_error(out, &[], schema_path, &[keyword], "\t\t")?;
+                    writeln!(out, "\t\treturn")?;
+                    writeln!(out, "\t}}")?;
+                    self.serialize_field_checks(out, fields, schema_path, "\t")?;
+                    if ir.strict {
+                        self.serialize_unknown_check(out, fields, None, schema_path, "\t")?;
+                    }
+                } else {
+                    writeln!(
+                        out,
+                        "\tif _, ok := instance.(map[string]interface{{}}); !ok {{"
+                    )?;
+                    self.serialize_error(out, &[], schema_path, &[keyword], "\t\t")?;
+                    writeln!(out, "\t}}")?;
+                }
             }
-            Ast::Var(ref name, ref typ, ref val) => {
-                write!(out, "var {} ", name)?;
-                self.serialize_subschema(out, typ)?;
-                write!(out, " = ")?;
-                self.serialize_subschema(out, val)?;
-                writeln!(out)?;
+            DeclKind::Enum(ref vals) => {
+                let lits: Vec<_> = vals.iter().map(|val| format!("{:?}", val.value)).collect();
+
+                writeln!(out, "\tswitch instance {{")?;
+                writeln!(out, "\tcase {}:", lits.join(", "))?;
+                writeln!(out, "\tdefault:")?;
+                self.serialize_error(out, &[], schema_path, &["enum"], "\t\t")?;
+                writeln!(out, "\t}}")?;
             }
-            Ast::Struct(ref name, ref props, ref json) => {
-                writeln!(out, "type {} struct {{", name)?;
-                for prop in props {
-                    write!(out, "\t{} ", prop.name)?;
-                    self.serialize_subschema(out, &prop.value)?;
-                    writeln!(out, " `json:\"{},omitempty\"`", prop.json_name)?;
-                }
-                writeln!(out, "}}")?;
-
-                if let Some(ref json) = json {
-                    writeln!(out, "func (s *{}) UnmarshalJSON(buf []byte) error {{", name)?;
-                    writeln!(out, "\tvar x struct{{ Tag string `json:{:?}` }}", json.0)?;
-                    writeln!(out, "\tif err := json.Unmarshal(buf, &x); err != nil {{")?;
-                    writeln!(out, "\t\treturn err")?;
-                    writeln!(out, "\t}}")?;
-                    writeln!(out, "\tswitch x.Tag {{")?;
-
-                    for (name, val) in json.1.iter() {
-                        writeln!(out, "\tcase {:?}:", name)?;
-                        write!(out, "\t\tvar data ")?;
-                        self.serialize_subschema(out, &val)?;
-                        writeln!(out)?;
-                        writeln!(
+            DeclKind::Union(ref tag, ref variants) => {
+                let tag_path = [format!("{:?}", tag)];
+
+                writeln!(out, "\tobj, ok := instance.(map[string]interface{{}})")?;
+                writeln!(out, "\tif !ok {{")?;
+                self.serialize_error(out, &[], schema_path, &["discriminator"], "\t\t")?;
+                writeln!(out, "\t\treturn")?;
+                writeln!(out, "\t}}")?;
+                writeln!(out, "\ttag, ok := obj[{:?}]", tag)?;
+                writeln!(out, "\tif !ok {{")?;
+                self.serialize_error(out, &[], schema_path, &["discriminator", "tag"], "\t\t")?;
+                writeln!(out, "\t\treturn")?;
+                writeln!(out, "\t}}")?;
+                writeln!(out, "\tswitch tag {{")?;
+                for variant in variants {
+                    let variant_path = [
+                        &schema_path[..],
+                        &[
+                            "discriminator".to_owned(),
+                            "mapping".to_owned(),
+                            variant.tag_value.clone(),
+                        ],
+                    ]
+                    .concat();
+
+                    writeln!(out, "\tcase {:?}:", variant.tag_value)?;
+                    self.serialize_field_checks(out, &variant.fields, &variant_path, "\t\t")?;
+                    if ir.strict {
+                        self.serialize_unknown_check(
                             out,
-                            "\t\tif err := json.Unmarshal(buf, &data); err != nil {{"
+                            &variant.fields,
+                            Some(tag),
+                            &variant_path,
+                            "\t\t",
                         )?;
-                        writeln!(out, "\t\t\treturn err")?;
-                        writeln!(out, "\t\t}}")?;
-                        writeln!(out, "\t\ts.Val = data")?;
                     }
+                }
+                writeln!(out, "\tdefault:")?;
+                writeln!(out, "\t\tif _, ok := tag.(string); ok {{")?;
+                self.serialize_error(
+                    out,
+                    &tag_path,
+                    schema_path,
+                    &["discriminator", "mapping"],
+                    "\t\t\t",
+                )?;
+                writeln!(out, "\t\t}} else {{")?;
+                self.serialize_error(
+                    out,
+                    &tag_path,
+                    schema_path,
+                    &["discriminator", "tag"],
+                    "\t\t\t",
+                )?;
+                writeln!(out, "\t\t}}")?;
+                writeln!(out, "\t}}")?;
+            }
+        };
 
-                    writeln!(out, "\t}}")?;
-                    writeln!(out, "\ts.{} = x.Tag", json.0.to_class_case())?;
-                    writeln!(out, "\treturn nil")?;
-                    writeln!(out, "}}")?;
-
-                    writeln!(out, "func (s {}) MarshalJSON() ([]byte, error) {{", name)?;
-                    writeln!(out, "\tswitch val := s.Val.(type) {{")?;
-
-                    for (_, val) in json.1.iter() {
-                        write!(out, "\tcase ")?;
-                        self.serialize_subschema(out, &val)?;
-                        writeln!(out, ":")?;
-                        writeln!(out, "\t\tvar data struct{{")?;
-                        writeln!(out, "\t\t\tTag string `json:{:?}`", json.0)?;
-                        write!(out, "\t\t\t")?;
-                        self.serialize_subschema(out, &val)?;
-                        writeln!(out)?;
-                        writeln!(out, "\t\t}}")?;
-                        write!(out, "\t\tdata.")?;
-                        self.serialize_subschema(out, &val)?;
-                        writeln!(out, " = val")?;
-                        writeln!(out, "\t\tdata.Tag = s.{}", json.0.to_class_case())?;
-                        writeln!(out, "\t\treturn json.Marshal(data)")?;
-                    }
+        writeln!(out, "}}")?;
 
-                    writeln!(out, "\t}}")?;
-                    writeln!(out, "\tpanic(\"invalid discriminator tag\")")?;
-                    writeln!(out, "}}")?;
+        Ok(())
+    }
+
+    // Write out a function which decodes JSON into a declaration's type, but
+    // only if the JSON is valid. This is what rejects properties a schema does
+    // not describe when using the schema strictly: encoding/json's own
+    // DisallowUnknownFields can't be used, because it would also reject the
+    // tags of discriminated unions.
+    fn serialize_decoder(&self, out: &mut dyn Write, decl: &Decl) -> Result<(), Error> {
+        writeln!(
+            out,
+            "func Decode{}(data []byte) ({}, error) {{",
+            decl.name, decl.name
+        )?;
+        writeln!(out, "\tvar out {}", decl.name)?;
+        writeln!(out, "\tvar instance interface{{}}")?;
+        writeln!(
+            out,
+            "\tif err := json.Unmarshal(data, &instance); err != nil {{"
+        )?;
+        writeln!(out, "\t\treturn out, err")?;
+        writeln!(out, "\t}}")?;
+        writeln!(
+            out,
+            "\tif errors := Validate{}(instance); len(errors) > 0 {{",
+            decl.name
+        )?;
+        writeln!(out, "\t\treturn out, ValidationErrors(errors)")?;
+        writeln!(out, "\t}}")?;
+        writeln!(out, "\terr := json.Unmarshal(data, &out)")?;
+        writeln!(out, "\treturn out, err")?;
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
+
+    // Write out a check that `obj` has no properties other than the fields
+    // and, for the cases of a discriminated union, the tag.
+    fn serialize_unknown_check(
+        &self,
+        out: &mut dyn Write,
+        fields: &[Field],
+        tag: Option<&str>,
+        schema_path: &[String],
+        indent: &str,
+    ) -> Result<(), Error> {
+        let known: Vec<_> = fields
+            .iter()
+            .map(|field| field.name.as_str())
+            .chain(tag)
+            .map(|name| format!("{:?}", name))
+            .collect();
+
+        writeln!(out, "{}for key := range obj {{", indent)?;
+        writeln!(out, "{}\tswitch key {{", indent)?;
+        if !known.is_empty() {
+            writeln!(out, "{}\tcase {}:", indent, known.join(", "))?;
+        }
+        writeln!(out, "{}\tdefault:", indent)?;
+        self.serialize_error(
+            out,
+            &["key".to_owned()],
+            schema_path,
+            &[],
+            &format!("{}\t\t", indent),
+        )?;
+        writeln!(out, "{}\t}}", indent)?;
+        writeln!(out, "{}}}", indent)?;
+
+        Ok(())
+    }
+
+    // Whether validating a field requires looking at the object holding it.
+    fn needs_check(field: &Field) -> bool {
+        field.required || !matches!(field.value, Type::Any)
+    }
+
+    // Write out the checks on the properties of `obj`, which the caller has
+    // already asserted to be an object.
+    fn serialize_field_checks(
+        &self,
+        out: &mut dyn Write,
+        fields: &[Field],
+        schema_path: &[String],
+        indent: &str,
+    ) -> Result<(), Error> {
+        for field in fields.iter().filter(|field| Self::needs_check(field)) {
+            let keyword = if field.required {
+                "properties"
+            } else {
+                "optionalProperties"
+            };
+
+            let field_path = [schema_path, &[keyword.to_owned(), field.name.clone()]].concat();
+            let inner_indent = format!("{}\t", indent);
+
+            if let Type::Any = field.value {
+                writeln!(out, "{}if _, ok := obj[{:?}]; !ok {{", indent, field.name)?;
+                self.serialize_error(out, &[], &field_path, &[], &inner_indent)?;
+                writeln!(out, "{}}}", indent)?;
+                continue;
+            }
+
+            writeln!(out, "{}if v0, ok := obj[{:?}]; ok {{", indent, field.name)?;
+            self.serialize_check(
+                out,
+                &field.value,
+                "v0",
+                &[format!("{:?}", field.name)],
+                &field_path,
+                0,
+                &inner_indent,
+            )?;
+
+            if field.required {
+                writeln!(out, "{}}} else {{", indent)?;
+                self.serialize_error(out, &[], &field_path, &[], &inner_indent)?;
+            }
+
+            writeln!(out, "{}}}", indent)?;
+        }
+
+        Ok(())
+    }
+
+    // Write out the statements checking that `expr` is a valid instance of
+    // `typ`. The instance path of `expr` is `path` followed by the tokens in
+    // `path_tokens`, which are Go expressions. Depth is used to keep loop
+    // variables from shadowing one another.
+    #[allow(clippy::too_many_arguments)]
+    fn serialize_check(
+        &self,
+        out: &mut dyn Write,
+        typ: &Type,
+        expr: &str,
+        path_tokens: &[String],
+        schema_path: &[String],
+        depth: usize,
+        indent: &str,
+    ) -> Result<(), Error> {
+        let inner_indent = format!("{}\t", indent);
+        let cond = match typ {
+            Type::Any => return Ok(()),
+            Type::Boolean => format!("_, ok := {}.(bool); !ok", expr),
+            Type::Number => format!("_, ok := {}.(float64); !ok", expr),
+            Type::String => format!("_, ok := {}.(string); !ok", expr),
+            Type::Timestamp => format!("!jslIsTimestamp({})", expr),
+            Type::Mapped(ref typ, _) => {
+                return self.serialize_check(
+                    out,
+                    typ,
+                    expr,
+                    path_tokens,
+                    schema_path,
+                    depth,
+                    indent,
+                );
+            }
+            Type::Ref(ref id) => {
+                writeln!(
+                    out,
+                    "{}validate{}({}, {}, errors)",
+                    indent,
+                    id,
+                    expr,
+                    Self::path_expr(path_tokens)
+                )?;
+                return Ok(());
+            }
+            Type::Elements(ref sub_typ) => {
+                if let Type::Any = **sub_typ {
+                    writeln!(
+                        out,
+                        "{}if _, ok := {}.([]interface{{}}); !ok {{",
+                        indent, expr
+                    )?;
+                } else {
+                    let arr = format!("a{}", depth);
+                    let index = format!("i{}", depth);
+                    let elem = format!("v{}", depth + 1);
+
+                    writeln!(
+                        out,
+                        "{}if {}, ok := {}.([]interface{{}}); ok {{",
+                        indent, arr, expr
+                    )?;
+                    writeln!(
+                        out,
+                        "{}for {}, {} := range {} {{",
+                        inner_indent, index, elem, arr
+                    )?;
+                    self.serialize_check(
+                        out,
+                        sub_typ,
+                        &elem,
+                        &[path_tokens, &[format!("strconv.Itoa({})", index)]].concat(),
+                        &[schema_path, &["elements".to_owned()]].concat(),
+                        depth + 1,
+                        &format!("{}\t", inner_indent),
+                    )?;
+                    writeln!(out, "{}}}", inner_indent)?;
+                    writeln!(out, "{}}} else {{", indent)?;
+                }
+                self.serialize_error(out, path_tokens, schema_path, &["elements"], &inner_indent)?;
+                writeln!(out, "{}}}", indent)?;
+                return Ok(());
+            }
+            Type::Values(ref sub_typ) => {
+                if let Type::Any = **sub_typ {
+                    writeln!(
+                        out,
+                        "{}if _, ok := {}.(map[string]interface{{}}); !ok {{",
+                        indent, expr
+                    )?;
+                } else {
+                    let obj = format!("o{}", depth);
+                    let key = format!("k{}", depth);
+                    let elem = format!("v{}", depth + 1);
+
+                    writeln!(
+                        out,
+                        "{}if {}, ok := {}.(map[string]interface{{}}); ok {{",
+                        indent, obj, expr
+                    )?;
+                    writeln!(
+                        out,
+                        "{}for {}, {} := range {} {{",
+                        inner_indent, key, elem, obj
+                    )?;
+                    self.serialize_check(
+                        out,
+                        sub_typ,
+                        &elem,
+                        &[path_tokens, &[key]].concat(),
+                        &[schema_path, &["values".to_owned()]].concat(),
+                        depth + 1,
+                        &format!("{}\t", inner_indent),
+                    )?;
+                    writeln!(out, "{}}}", inner_indent)?;
+                    writeln!(out, "{}}} else {{", indent)?;
                 }
+                self.serialize_error(out, path_tokens, schema_path, &["values"], &inner_indent)?;
+                writeln!(out, "{}}}", indent)?;
+                return Ok(());
             }
         };
 
+        writeln!(out, "{}if {} {{", indent, cond)?;
+        self.serialize_error(out, path_tokens, schema_path, &["type"], &inner_indent)?;
+        writeln!(out, "{}}}", indent)?;
+
+        Ok(())
+    }
+
+    // Write out a statement adding an error to `errors`. The schema path is
+    // `schema_path` followed by the keywords in `rest`.
+    fn serialize_error(
+        &self,
+        out: &mut dyn Write,
+        path_tokens: &[String],
+        schema_path: &[String],
+        rest: &[&str],
+        indent: &str,
+    ) -> Result<(), Error> {
+        writeln!(
+            out,
+            "{}*errors = append(*errors, ValidationError{{InstancePath: jslPointer({}), SchemaPath: {:?}}})",
+            indent,
+            Self::path_expr(path_tokens),
+            ir::json_pointer(schema_path, rest)
+        )?;
+
         Ok(())
     }
 
-    fn path_to_identifier(path: &[String]) -> String {
-        path.join("_").to_pascal_case()
+    fn path_expr(path_tokens: &[String]) -> String {
+        if path_tokens.is_empty() {
+            "path".to_owned()
+        } else {
+            format!("jslAppend(path, {})", path_tokens.join(", "))
+        }
     }
-}
 
-#[derive(Debug)]
-pub enum Ast {
-    Any,
-    Boolean,
-    Number,
-    String,
-    Time,
-
-    // A string literal.
-    Literal(String),
-
-    // An identifier.
-    Identifier(String),
-
-    // An array with elements of some type.
-    Array(Box<Ast>),
-
-    // A map from strings to some type.
-    Map(Box<Ast>),
-
-    // A type declaration.
-    Type(String, Box<Ast>),
-
-    // A var declaration. First parameter is the name, second is the type, third
-    // is the value.
-    Var(String, Box<Ast>, Box<Ast>),
-
-    // An interface with a name and properties.
-    //
-    // If the third argument is present, this indicates that MarshalJSON and
-    // UnmarshalJSON should be overridden to act as a discriminated union. The
-    // first parameter is the name of the tag, and the second parameter is a
-    // mapping from tag values to corresponding type identifiers.
-    Struct(
-        String,
-        Vec<Property>,
-        Option<(String, HashMap<String, Ast>)>,
-    ),
-}
+    fn type_expr(&self, typ: &Type) -> String {
+        match typ {
+            Type::Any => "interface{}".to_owned(),
+            Type::Boolean => "bool".to_owned(),
+            Type::Number => "float64".to_owned(),
+            Type::String => "string".to_owned(),
+            Type::Timestamp => "time.Time".to_owned(),
+            Type::Mapped(_, ref mapping) => mapping.name.clone(),
+            Type::Ref(ref id) => id.clone(),
+            Type::Elements(ref typ) => format!("[]{}", self.type_expr(typ)),
+            Type::Values(ref typ) => format!("map[string]{}", self.type_expr(typ)),
+        }
+    }
 
-#[derive(Debug)]
-pub struct Property {
-    name: String,
-    json_name: String,
-    value: Ast,
+    fn serialize_description(
+        &self,
+        out: &mut dyn Write,
+        indent: &str,
+        description: &Option<String>,
+    ) -> Result<(), Error> {
+        if let Some(ref description) = description {
+            for line in description.lines() {
+                let line = format!("{}// {}", indent, line);
+                writeln!(out, "{}", line.trim_end())?;
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,1035 @@
+use crate::codegen;
+use crate::config::{Collisions, TypeMapping};
+use crate::input::Input;
+use failure::{bail, Error};
+use inflector::Inflector;
+use jsl::schema::{self, Form};
+use jsl::Schema;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+// A language-neutral representation of the types described by a schema.
+//
+// Every backend renders its output from this representation, so the work of
+// walking a schema, deciding which parts of it get a name, and choosing those
+// names happens exactly once.
+//
+// The jsl crate doesn't keep track of the order things were declared in a
+// schema, so to keep output stable from one run to the next, definitions,
+// properties, enum values and discriminator cases are all sorted by name.
+// Required properties come before optional ones.
+//
+// Names are made by joining up the path to a schema, so different schemas can
+// end up with the same name: a definition `user_name` and the property `name`
+// of a definition `user` are both `UserName`. Such collisions are either an
+// error, or resolved by numbering the later names, in the order they are
+// lowered: `UserName2`, `UserName3` and so on. Definitions come first, and
+// keep their names either way, since other inputs may refer to them.
+//
+// Names are also kept clear of anything the target reserves, such as keywords
+// and the types generated code relies on, by adding an underscore to them.
+//
+// The identifiers for fields and enum members are made by each backend, and
+// are then checked by `name_members` in just the same way, except that they
+// only need to be unique within the record, case or enum they belong to.
+// Backends naming the cases of unions after their tag values check those with
+// `name_variants`.
+//
+// Schemas can choose names for themselves in their metadata, with a `name`
+// or, for just one target, something like `javaName`. Likewise, `fieldName`
+// names the field for a property, and `enumNames` maps enum values to the
+// names of their members. Chosen names are used as they are, and like
+// definitions, are never renamed.
+//
+// Users can also swap out the types generated for `type` schemas, either for
+// every schema of a JSL type, or just those whose metadata gives a `type` of
+// the same name. These end up as `Type::Mapped`, which wraps the type the
+// schema describes, since that's still what instances are validated against.
+#[derive(Debug)]
+pub struct Ir {
+    // The name of the input this was lowered from. Backends name their
+    // output, and refer to output generated from other inputs, using this.
+    pub module: String,
+
+    // The named declarations, in the order they should be emitted. Nested
+    // declarations come before the declarations that use them.
+    pub decls: Vec<Decl>,
+
+    // Whether objects may only contain the properties their schema describes.
+    // The jsl crate treats this as a property of validation rather than of
+    // schemas, and `Form::Properties` says nothing about it, so it applies to
+    // every record alike.
+    pub strict: bool,
+}
+
+#[derive(Debug)]
+pub struct Decl {
+    pub name: String,
+
+    // The location of the schema this declaration was produced from, as a
+    // list of JSON Pointer tokens. This is relative to the root schema for
+    // definitions and the root itself; everything else is relative to the
+    // enclosing definition, mirroring how the jsl crate reports errors.
+    pub schema_path: Vec<String>,
+    pub description: Option<String>,
+    pub kind: DeclKind,
+
+    // The input this declaration belongs to, if it is not the one being
+    // generated. Such declarations are only here so that backends can look
+    // them up; code for them is generated alongside their own input.
+    pub module: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum DeclKind {
+    // Another name for some type.
+    Alias(Type),
+
+    // An object with a fixed set of properties.
+    Record(Vec<Field>),
+
+    // A string which must be one of a set of values.
+    Enum(Vec<EnumMember>),
+
+    // An object discriminated by a tag. The first parameter is the name of the
+    // tag property, and the second is the list of cases.
+    Union(String, Vec<Variant>),
+}
+
+#[derive(Debug)]
+pub struct EnumMember {
+    pub value: String,
+
+    // The name the schema chose for this member, if any. Otherwise, backends
+    // are responsible for turning the value into an identifier.
+    pub identifier: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Field {
+    // The name of the property in JSON. Unless the schema chose an identifier
+    // for it, backends are responsible for turning this into one.
+    pub name: String,
+    pub identifier: Option<String>,
+    pub required: bool,
+    pub value: Type,
+    pub description: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct Variant {
+    // The value of the tag which selects this case.
+    pub tag_value: String,
+
+    // The name of the record holding the rest of this case's properties.
+    pub name: String,
+    pub fields: Vec<Field>,
+    pub description: Option<String>,
+
+    // The identifier the target gives this case within the union, for targets
+    // which name cases after their tag values. This is only set once
+    // `name_variants` has been run.
+    pub identifier: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub enum Type {
+    Any,
+    Boolean,
+    Number,
+    String,
+    Timestamp,
+
+    // A reference to a declaration, by name.
+    Ref(String),
+
+    // An array with elements of some type.
+    Elements(Box<Type>),
+
+    // A map from strings to some type.
+    Values(Box<Type>),
+
+    // One of the primitive types above, to be rendered as a type chosen by
+    // the user.
+    Mapped(Box<Type>, TypeMapping),
+}
+
+impl Decl {
+    // The types used directly by this declaration, including the types of
+    // the fields of any records or cases it contains.
+    pub fn types(&self) -> Vec<&Type> {
+        match self.kind {
+            DeclKind::Alias(ref typ) => vec![typ],
+            DeclKind::Record(ref fields) => fields.iter().map(|field| &field.value).collect(),
+            DeclKind::Enum(_) => vec![],
+            DeclKind::Union(_, ref variants) => variants
+                .iter()
+                .flat_map(|variant| variant.fields.iter().map(|field| &field.value))
+                .collect(),
+        }
+    }
+}
+
+impl Type {
+    // The names of the declarations this type refers to, including from
+    // within any types nested in it.
+    pub fn refs(&self) -> Vec<&str> {
+        match self {
+            Type::Ref(ref name) => vec![name],
+            Type::Elements(ref typ) | Type::Values(ref typ) => typ.refs(),
+            _ => vec![],
+        }
+    }
+
+    // Change the names of the declarations this type refers to.
+    fn rename(&mut self, rename: &dyn Fn(&mut String)) {
+        match self {
+            Type::Ref(ref mut name) => rename(name),
+            Type::Elements(ref mut typ) | Type::Values(ref mut typ) => typ.rename(rename),
+            _ => {}
+        }
+    }
+
+    // The type mappings this type makes use of, including from within any
+    // types nested in it.
+    pub fn mappings(&self) -> Vec<&TypeMapping> {
+        match self {
+            Type::Mapped(_, ref mapping) => vec![mapping],
+            Type::Elements(ref typ) | Type::Values(ref typ) => typ.mappings(),
+            _ => vec![],
+        }
+    }
+
+    // The type a schema describes, whether or not it has been mapped to
+    // another.
+    pub fn unmapped(&self) -> &Type {
+        match self {
+            Type::Mapped(ref typ, _) => typ,
+            _ => self,
+        }
+    }
+
+    // Whether this type, or any type nested within it, matches a predicate.
+    pub fn any(&self, pred: &dyn Fn(&Type) -> bool) -> bool {
+        pred(self)
+            || match self {
+                Type::Elements(ref typ) | Type::Values(ref typ) => typ.any(pred),
+                _ => false,
+            }
+    }
+}
+
+impl Ir {
+    #[allow(clippy::too_many_arguments)]
+    pub fn from_input(
+        root_name: &str,
+        input: &Input,
+        strict: bool,
+        names: &HashMap<String, String>,
+        collisions: Collisions,
+        reserved: &[&str],
+        target: &str,
+        types: &HashMap<String, TypeMapping>,
+    ) -> Result<Ir, Error> {
+        let schema = &input.schema;
+        let definitions = sorted(schema.definitions().as_ref().unwrap());
+        let mut scope = Scope {
+            module: &input.name,
+            collisions,
+            reserved,
+            target,
+            types,
+            decls: Vec::new(),
+            names: HashMap::new(),
+            definitions: HashMap::new(),
+        };
+
+        // Definitions get first pick of names.
+        for (name, def) in &definitions {
+            let schema_path = vec!["definitions".to_owned(), name.to_string()];
+            scope.reserve(name, &schema_path, def)?;
+        }
+
+        // First, lower each of the definitions. Those borrowed from other
+        // inputs are marked as such, along with everything nested in them.
+        for (name, def) in definitions {
+            let start = scope.decls.len();
+            let mut path = vec![name.clone()];
+            let mut schema_path = vec!["definitions".to_owned(), name.clone()];
+            Self::lower_for_id(&mut scope, &mut path, &mut schema_path, def)?;
+
+            if let Some(module) = input.foreign.get(name) {
+                for decl in &mut scope.decls[start..] {
+                    decl.module = Some(module.clone());
+                }
+            }
+        }
+
+        // Then, lower the root schema.
+        let mut path = vec![root_name.to_owned()];
+        Self::lower_for_id(&mut scope, &mut path, &mut vec![], schema)?;
+        let mut decls = scope.decls;
+
+        // Finally, swap in any names the user chose in place of the generated
+        // ones.
+        let rename = |name: &mut String| {
+            if let Some(renamed) = names.get(name) {
+                *name = renamed.clone();
+            }
+        };
+
+        for decl in &mut decls {
+            rename(&mut decl.name);
+            match decl.kind {
+                DeclKind::Alias(ref mut typ) => typ.rename(&rename),
+                DeclKind::Record(ref mut fields) => {
+                    for field in fields {
+                        field.value.rename(&rename);
+                    }
+                }
+                DeclKind::Enum(_) => {}
+                DeclKind::Union(_, ref mut variants) => {
+                    for variant in variants {
+                        rename(&mut variant.name);
+                        for field in &mut variant.fields {
+                            field.value.rename(&rename);
+                        }
+                    }
+                }
+            }
+        }
+
+        // Names chosen by the user aren't checked as they're made, so check
+        // that they haven't introduced any collisions of their own.
+        let mut all_names = Vec::new();
+        for decl in &decls {
+            all_names.push((&decl.name, decl.schema_path.clone()));
+            if let DeclKind::Union(_, ref variants) = decl.kind {
+                for variant in variants {
+                    all_names.push((
+                        &variant.name,
+                        variant_schema_path(&decl.schema_path, variant),
+                    ));
+                }
+            }
+        }
+
+        let mut named = HashMap::new();
+        for (name, schema_path) in all_names {
+            if let Some(existing) = named.insert(name, schema_path.clone()) {
+                bail!(
+                    "{}: {} and {} are both named {}",
+                    input.name,
+                    describe(&existing),
+                    describe(&schema_path),
+                    name
+                );
+            }
+        }
+
+        Ok(Ir {
+            module: input.name.clone(),
+            decls,
+            strict,
+        })
+    }
+
+    // Look up a declaration by name.
+    pub fn decl(&self, name: &str) -> Option<&Decl> {
+        self.decls.iter().find(|decl| decl.name == name)
+    }
+
+    // The declarations code should be generated for, in order.
+    pub fn local_decls(&self) -> impl Iterator<Item = &Decl> {
+        self.decls.iter().filter(|decl| decl.module.is_none())
+    }
+
+    // Whether any type in any local declaration matches a predicate.
+    pub fn uses(&self, pred: &dyn Fn(&Type) -> bool) -> bool {
+        self.local_decls()
+            .flat_map(Decl::types)
+            .any(|typ| typ.any(pred))
+    }
+
+    // Like `uses`, but looking through aliases, including those from other
+    // inputs, as targets which resolve aliases away end up doing.
+    pub fn uses_resolved(&self, pred: &dyn Fn(&Type) -> bool) -> bool {
+        self.local_decls()
+            .flat_map(Decl::types)
+            .any(|typ| self.any_resolved(typ, pred))
+    }
+
+    fn any_resolved(&self, typ: &Type, pred: &dyn Fn(&Type) -> bool) -> bool {
+        let typ = self.resolve(typ);
+        pred(typ)
+            || match typ {
+                Type::Elements(ref typ) | Type::Values(ref typ) => self.any_resolved(typ, pred),
+                _ => false,
+            }
+    }
+
+    // What local declarations need imported for the types mapped by the
+    // user, along with the names of those types.
+    pub fn mapped_imports(&self) -> BTreeMap<&str, BTreeSet<&str>> {
+        let mut imports: BTreeMap<_, BTreeSet<_>> = BTreeMap::new();
+        for typ in self.local_decls().flat_map(Decl::types) {
+            for mapping in typ.mappings() {
+                if let Some(ref import) = mapping.import {
+                    imports
+                        .entry(import.as_str())
+                        .or_default()
+                        .insert(mapping.name.as_str());
+                }
+            }
+        }
+
+        imports
+    }
+
+    // The declarations from other inputs which local declarations refer to,
+    // grouped by the input they belong to.
+    pub fn imports(&self) -> BTreeMap<&str, BTreeSet<&str>> {
+        let mut imports: BTreeMap<_, BTreeSet<_>> = BTreeMap::new();
+        for typ in self.local_decls().flat_map(Decl::types) {
+            for name in typ.refs() {
+                if let Some(Decl {
+                    module: Some(ref module),
+                    ..
+                }) = self.decl(name)
+                {
+                    imports.entry(module.as_str()).or_default().insert(name);
+                }
+            }
+        }
+
+        imports
+    }
+
+    // Look through any aliases a type refers to. This is meant for targets
+    // which have no way to declare type aliases.
+    pub fn resolve<'a>(&'a self, typ: &'a Type) -> &'a Type {
+        if let Type::Ref(ref name) = typ {
+            if let Some(Decl {
+                kind: DeclKind::Alias(ref aliased),
+                ..
+            }) = self.decl(name)
+            {
+                return self.resolve(aliased);
+            }
+        }
+
+        typ
+    }
+
+    // Whether a field leads back to the record or case it belongs to, which is
+    // named by `owner`, without passing through an array or map along the way.
+    // Targets which store records inline need some indirection on such fields,
+    // or the records would have to contain themselves.
+    pub fn is_recursive(&self, owner: &str, field: &Field) -> bool {
+        let mut seen = BTreeSet::new();
+        let mut pending: Vec<_> = self.inline_ref(&field.value).into_iter().collect();
+        while let Some(name) = pending.pop() {
+            if name == owner {
+                return true;
+            }
+
+            if seen.insert(name) {
+                pending.extend(self.inline_refs(name));
+            }
+        }
+
+        false
+    }
+
+    // The declaration a type refers to, if it's stored inline rather than
+    // behind an array or map.
+    fn inline_ref<'a>(&'a self, typ: &'a Type) -> Option<&'a str> {
+        match self.resolve(typ) {
+            Type::Ref(ref name) => Some(name),
+            _ => None,
+        }
+    }
+
+    // The records and cases a record, union or case stores inline, by name.
+    // A union stores whichever of its cases it holds.
+    fn inline_refs(&self, name: &str) -> Vec<&str> {
+        for decl in &self.decls {
+            match decl.kind {
+                DeclKind::Record(ref fields) if decl.name == name => {
+                    return fields
+                        .iter()
+                        .filter_map(|field| self.inline_ref(&field.value))
+                        .collect();
+                }
+                DeclKind::Union(_, ref variants) => {
+                    if decl.name == name {
+                        return variants
+                            .iter()
+                            .map(|variant| variant.name.as_str())
+                            .collect();
+                    }
+
+                    if let Some(variant) = variants.iter().find(|variant| variant.name == name) {
+                        return variant
+                            .fields
+                            .iter()
+                            .filter_map(|field| self.inline_ref(&field.value))
+                            .collect();
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        Vec::new()
+    }
+
+    // Give each local field and enum member the identifier a target makes
+    // for it, using `field_name` and `member_name`, which are also passed the
+    // name of the record, case or enum the field or member belongs to. Names
+    // only need to be unique within a record, case or enum, but collisions
+    // there are dealt with just like those between declarations: either an
+    // error, or resolved by numbering the later identifiers. Chosen
+    // identifiers are never renamed.
+    pub fn name_members(
+        &mut self,
+        collisions: Collisions,
+        field_name: &dyn Fn(&str, &Field) -> String,
+        member_name: &dyn Fn(&str, &EnumMember) -> String,
+    ) -> Result<(), Error> {
+        let module = &self.module;
+        for decl in self.decls.iter_mut().filter(|decl| decl.module.is_none()) {
+            let (decl_name, decl_schema_path) = (&decl.name, &decl.schema_path);
+            match decl.kind {
+                DeclKind::Alias(_) => {}
+                DeclKind::Record(ref mut fields) => {
+                    let names = fields
+                        .iter()
+                        .map(|field| {
+                            Self::field_candidate(decl_schema_path, field, decl_name, field_name)
+                        })
+                        .collect();
+                    let names = unique_names(module, collisions, names)?;
+                    for (field, name) in fields.iter_mut().zip(names) {
+                        field.identifier = Some(name);
+                    }
+                }
+                DeclKind::Enum(ref mut members) => {
+                    let mut schema_path = decl_schema_path.clone();
+                    schema_path.push("enum".to_owned());
+                    let names = members
+                        .iter()
+                        .map(|member| Candidate {
+                            name: member_name(decl_name, member),
+                            chosen: member.identifier.is_some(),
+                            location: format!(
+                                "{} value {:?}",
+                                describe(&schema_path),
+                                member.value
+                            ),
+                        })
+                        .collect();
+                    let names = unique_names(module, collisions, names)?;
+                    for (member, name) in members.iter_mut().zip(names) {
+                        member.identifier = Some(name);
+                    }
+                }
+                DeclKind::Union(_, ref mut variants) => {
+                    for variant in variants {
+                        let schema_path = variant_schema_path(decl_schema_path, variant);
+                        let names = variant
+                            .fields
+                            .iter()
+                            .map(|field| {
+                                Self::field_candidate(
+                                    &schema_path,
+                                    field,
+                                    &variant.name,
+                                    field_name,
+                                )
+                            })
+                            .collect();
+                        let names = unique_names(module, collisions, names)?;
+                        for (field, name) in variant.fields.iter_mut().zip(names) {
+                            field.identifier = Some(name);
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Give each case of each local union the identifier a target makes for it
+    // with `variant_name`, for targets which name cases after their tag
+    // values. As with `name_members`, these only need to be unique within the
+    // union, and collisions are either an error or resolved by numbering.
+    pub fn name_variants(
+        &mut self,
+        collisions: Collisions,
+        variant_name: &dyn Fn(&Variant) -> String,
+    ) -> Result<(), Error> {
+        let module = &self.module;
+        for decl in self.decls.iter_mut().filter(|decl| decl.module.is_none()) {
+            let decl_schema_path = &decl.schema_path;
+            if let DeclKind::Union(_, ref mut variants) = decl.kind {
+                let names = variants
+                    .iter()
+                    .map(|variant| Candidate {
+                        name: variant_name(variant),
+                        chosen: false,
+                        location: describe(&variant_schema_path(decl_schema_path, variant)),
+                    })
+                    .collect();
+                let names = unique_names(module, collisions, names)?;
+                for (variant, name) in variants.iter_mut().zip(names) {
+                    variant.identifier = Some(name);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn field_candidate(
+        schema_path: &[String],
+        field: &Field,
+        owner: &str,
+        field_name: &dyn Fn(&str, &Field) -> String,
+    ) -> Candidate {
+        let keyword = if field.required {
+            "properties"
+        } else {
+            "optionalProperties"
+        };
+
+        let mut schema_path = schema_path.to_vec();
+        schema_path.push(keyword.to_owned());
+        schema_path.push(field.name.clone());
+
+        Candidate {
+            name: field_name(owner, field),
+            chosen: field.identifier.is_some(),
+            location: describe(&schema_path),
+        }
+    }
+
+    fn lower_subschema(
+        scope: &mut Scope,
+        path: &mut Vec<String>,
+        schema_path: &mut Vec<String>,
+        schema: &Schema,
+    ) -> Result<Type, Error> {
+        Ok(match schema.form() {
+            Form::Empty => Type::Any,
+            Form::Ref(ref def) => Type::Ref(scope.definitions[def].clone()),
+            Form::Type(ref typ) => {
+                let (typ, name) = match typ {
+                    schema::Type::Boolean => (Type::Boolean, "boolean"),
+                    schema::Type::Number => (Type::Number, "number"),
+                    schema::Type::String => (Type::String, "string"),
+                    schema::Type::Timestamp => (Type::Timestamp, "timestamp"),
+                };
+
+                // A type named in the metadata only applies if the target has
+                // a mapping for it. Otherwise, the schema gets whatever its
+                // JSL type would.
+                let mapping = codegen::metadata(schema, scope.target, "type")
+                    .and_then(|name| name.as_str())
+                    .and_then(|name| scope.types.get(name))
+                    .or_else(|| scope.types.get(name));
+
+                match mapping {
+                    Some(mapping) => Type::Mapped(Box::new(typ), mapping.clone()),
+                    None => typ,
+                }
+            }
+            Form::Enum(ref vals) => {
+                let mut vals: Vec<_> = vals.iter().cloned().collect();
+                vals.sort();
+
+                let identifiers = codegen::metadata(schema, scope.target, "enumNames");
+                let members = vals
+                    .into_iter()
+                    .map(|value| EnumMember {
+                        identifier: identifiers
+                            .and_then(|identifiers| identifiers.get(&value))
+                            .and_then(|identifier| identifier.as_str())
+                            .map(|identifier| identifier.to_owned()),
+                        value,
+                    })
+                    .collect();
+
+                let name = scope.name(path, schema_path, schema)?;
+                scope.decls.push(Decl {
+                    name: name.clone(),
+                    schema_path: schema_path.clone(),
+                    description: codegen::description(schema),
+                    kind: DeclKind::Enum(members),
+                    module: None,
+                });
+
+                Type::Ref(name)
+            }
+            Form::Elements(ref sub_schema) => {
+                schema_path.push("elements".to_owned());
+                let typ = Self::lower_subschema(scope, path, schema_path, sub_schema)?;
+                schema_path.pop();
+
+                Type::Elements(Box::new(typ))
+            }
+            Form::Properties(ref required, ref optional, _) => {
+                let fields = Self::lower_fields(scope, path, schema_path, required, optional)?;
+                let name = scope.name(path, schema_path, schema)?;
+                scope.decls.push(Decl {
+                    name: name.clone(),
+                    schema_path: schema_path.clone(),
+                    description: codegen::description(schema),
+                    kind: DeclKind::Record(fields),
+                    module: None,
+                });
+
+                Type::Ref(name)
+            }
+            Form::Values(ref sub_schema) => {
+                schema_path.push("values".to_owned());
+                let typ = Self::lower_subschema(scope, path, schema_path, sub_schema)?;
+                schema_path.pop();
+
+                Type::Values(Box::new(typ))
+            }
+            Form::Discriminator(ref tag, ref mapping) => {
+                let mut variants = Vec::new();
+                for (tag_value, case) in sorted(mapping) {
+                    path.push(tag_value.clone());
+                    schema_path.push("discriminator".to_owned());
+                    schema_path.push("mapping".to_owned());
+                    schema_path.push(tag_value.clone());
+
+                    let (required, optional) = match case.form() {
+                        Form::Properties(ref required, ref optional, _) => (required, optional),
+                        _ => unreachable!("non-prop form in mapping"),
+                    };
+
+                    variants.push(Variant {
+                        tag_value: tag_value.clone(),
+                        fields: Self::lower_fields(scope, path, schema_path, required, optional)?,
+                        name: scope.name(path, schema_path, case)?,
+                        description: codegen::description(case),
+                        identifier: None,
+                    });
+
+                    path.pop();
+                    schema_path.truncate(schema_path.len() - 3);
+                }
+
+                let name = scope.name(path, schema_path, schema)?;
+                scope.decls.push(Decl {
+                    name: name.clone(),
+                    schema_path: schema_path.clone(),
+                    description: codegen::description(schema),
+                    kind: DeclKind::Union(tag.clone(), variants),
+                    module: None,
+                });
+
+                Type::Ref(name)
+            }
+        })
+    }
+
+    fn lower_fields(
+        scope: &mut Scope,
+        path: &mut Vec<String>,
+        schema_path: &mut Vec<String>,
+        required: &HashMap<String, Schema>,
+        optional: &HashMap<String, Schema>,
+    ) -> Result<Vec<Field>, Error> {
+        let mut fields = Vec::new();
+        for (name, prop) in sorted(required) {
+            let field = Self::lower_field(scope, path, schema_path, true, name, prop)?;
+            fields.push(field);
+        }
+
+        for (name, prop) in sorted(optional) {
+            let field = Self::lower_field(scope, path, schema_path, false, name, prop)?;
+            fields.push(field);
+        }
+
+        Ok(fields)
+    }
+
+    fn lower_field(
+        scope: &mut Scope,
+        path: &mut Vec<String>,
+        schema_path: &mut Vec<String>,
+        required: bool,
+        name: &str,
+        prop: &Schema,
+    ) -> Result<Field, Error> {
+        let keyword = if required {
+            "properties"
+        } else {
+            "optionalProperties"
+        };
+
+        path.push(name.to_owned());
+        schema_path.push(keyword.to_owned());
+        schema_path.push(name.to_owned());
+        let value = Self::lower_subschema(scope, path, schema_path, prop)?;
+        path.pop();
+        schema_path.truncate(schema_path.len() - 2);
+
+        Ok(Field {
+            name: name.to_owned(),
+            identifier: codegen::metadata(prop, scope.target, "fieldName")
+                .and_then(|identifier| identifier.as_str())
+                .map(|identifier| identifier.to_owned()),
+            required,
+            value,
+            description: codegen::description(prop),
+        })
+    }
+
+    // Ensure that a schema will get a declaration named after its path, adding
+    // an alias if lowering it did not already produce one.
+    fn lower_for_id(
+        scope: &mut Scope,
+        path: &mut Vec<String>,
+        schema_path: &mut Vec<String>,
+        schema: &Schema,
+    ) -> Result<(), Error> {
+        let name = scope.name(path, schema_path, schema)?;
+
+        // The alias for an array or map takes the name any declaration for
+        // its elements or values would otherwise get, so name those after
+        // what they are.
+        let suffix = match schema.form() {
+            Form::Elements(_) => Some("element"),
+            Form::Values(_) => Some("value"),
+            _ => None,
+        };
+
+        if let Some(suffix) = suffix {
+            path.push(suffix.to_owned());
+        }
+
+        let typ = Self::lower_subschema(scope, path, schema_path, schema)?;
+
+        if suffix.is_some() {
+            path.pop();
+        }
+
+        match typ {
+            Type::Ref(ref id) if *id == name => {}
+            typ => scope.decls.push(Decl {
+                name,
+                schema_path: schema_path.clone(),
+                description: codegen::description(schema),
+                kind: DeclKind::Alias(typ),
+                module: None,
+            }),
+        };
+
+        Ok(())
+    }
+}
+
+// The declarations lowered so far, and the names they have taken.
+struct Scope<'a> {
+    module: &'a str,
+    collisions: Collisions,
+    reserved: &'a [&'a str],
+    target: &'a str,
+    types: &'a HashMap<String, TypeMapping>,
+    decls: Vec<Decl>,
+
+    // The schema path each name was taken for.
+    names: HashMap<String, Vec<String>>,
+
+    // The names given to each of the definitions.
+    definitions: HashMap<String, String>,
+}
+
+impl<'a> Scope<'a> {
+    // The name the schema at a path chose for itself, if any.
+    fn chosen(&self, schema: &Schema) -> Option<String> {
+        codegen::metadata(schema, self.target, "name")
+            .and_then(|name| name.as_str())
+            .map(|name| name.to_owned())
+    }
+
+    // The name a path would be given, before any collisions are dealt with.
+    fn identifier(&self, path: &[String]) -> String {
+        codegen::identifier(&path_to_identifier(path), "X", self.reserved)
+    }
+
+    // Take the name for a definition, which is never renamed.
+    fn reserve(&mut self, def: &str, schema_path: &[String], schema: &Schema) -> Result<(), Error> {
+        let name = self
+            .chosen(schema)
+            .unwrap_or_else(|| self.identifier(&[def.to_owned()]));
+
+        if let Some(existing) = self.names.get(&name) {
+            bail!(
+                "{}: {} and {} are both named {}, and definitions can't be renamed",
+                self.module,
+                describe(existing),
+                describe(schema_path),
+                name
+            );
+        }
+
+        self.definitions.insert(def.to_owned(), name.clone());
+        self.names.insert(name, schema_path.to_vec());
+        Ok(())
+    }
+
+    // Get the name for the schema at a path. Asking again for the same schema
+    // gives the same name.
+    fn name(
+        &mut self,
+        path: &[String],
+        schema_path: &[String],
+        schema: &Schema,
+    ) -> Result<String, Error> {
+        if let Some(name) = self.chosen(schema) {
+            match self.names.get(&name) {
+                Some(existing) if existing != schema_path => bail!(
+                    "{}: {} and {} are both named {}, and chosen names can't be changed",
+                    self.module,
+                    describe(existing),
+                    describe(schema_path),
+                    name
+                ),
+                _ => {
+                    self.names.insert(name.clone(), schema_path.to_vec());
+                    return Ok(name);
+                }
+            }
+        }
+
+        let base = self.identifier(path);
+        let mut name = base.clone();
+        let mut count = 1;
+
+        loop {
+            match self.names.get(&name) {
+                None => {
+                    self.names.insert(name.clone(), schema_path.to_vec());
+                    return Ok(name);
+                }
+                Some(existing) if existing == schema_path => return Ok(name),
+                Some(existing) => {
+                    if let Collisions::Error = self.collisions {
+                        bail!(
+                            "{}: {} and {} are both named {}",
+                            self.module,
+                            describe(existing),
+                            describe(schema_path),
+                            name
+                        );
+                    }
+
+                    count += 1;
+                    name = format!("{}{}", base, count);
+                }
+            }
+        }
+    }
+}
+
+// An identifier a target would like to use for a field or enum member.
+struct Candidate {
+    name: String,
+
+    // Whether the schema chose this identifier itself.
+    chosen: bool,
+
+    // Where the field or member is, for error messages.
+    location: String,
+}
+
+// Make sure a set of identifiers which have to be distinct from one another
+// are, returning the identifiers to use in the same order.
+fn unique_names(
+    module: &str,
+    collisions: Collisions,
+    candidates: Vec<Candidate>,
+) -> Result<Vec<String>, Error> {
+    // Chosen identifiers can't be renamed, so they get first pick.
+    let mut taken = HashMap::new();
+    for candidate in candidates.iter().filter(|candidate| candidate.chosen) {
+        if let Some(existing) = taken.insert(candidate.name.clone(), &candidate.location) {
+            bail!(
+                "{}: {} and {} are both named {}, and chosen names can't be changed",
+                module,
+                existing,
+                candidate.location,
+                candidate.name
+            );
+        }
+    }
+
+    let mut names = Vec::new();
+    for candidate in &candidates {
+        if candidate.chosen {
+            names.push(candidate.name.clone());
+            continue;
+        }
+
+        let mut name = candidate.name.clone();
+        let mut count = 1;
+        while let Some(existing) = taken.get(&name) {
+            if let Collisions::Error = collisions {
+                bail!(
+                    "{}: {} and {} are both named {}",
+                    module,
+                    existing,
+                    candidate.location,
+                    name
+                );
+            }
+
+            count += 1;
+            name = format!("{}{}", candidate.name, count);
+        }
+
+        taken.insert(name.clone(), &candidate.location);
+        names.push(name);
+    }
+
+    Ok(names)
+}
+
+// The schema path a case of a discriminator was lowered from, given the path
+// of the discriminator.
+fn variant_schema_path(decl_schema_path: &[String], variant: &Variant) -> Vec<String> {
+    let mut schema_path = decl_schema_path.to_vec();
+    schema_path.push("discriminator".to_owned());
+    schema_path.push("mapping".to_owned());
+    schema_path.push(variant.tag_value.clone());
+    schema_path
+}
+
+// Describe where a schema is, for error messages.
+fn describe(schema_path: &[String]) -> String {
+    format!("#{}", json_pointer(schema_path, &[]))
+}
+
+// The entries of a map, sorted by key.
+fn sorted<K: Ord, V>(map: &HashMap<K, V>) -> BTreeMap<&K, &V> {
+    map.iter().collect()
+}
+
+pub fn path_to_identifier(path: &[String]) -> String {
+    path.join("_").to_pascal_case()
+}
+
+// Render a schema path, plus some extra tokens after it, as a JSON Pointer.
+pub fn json_pointer(base: &[String], rest: &[&str]) -> String {
+    base.iter()
+        .map(String::as_str)
+        .chain(rest.iter().cloned())
+        .map(|token| format!("/{}", token.replace('~', "~0").replace('/', "~1")))
+        .collect()
+}
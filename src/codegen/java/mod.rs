@@ -1,22 +1,130 @@
 use crate::codegen;
+use crate::codegen::ir::{self, Decl, DeclKind, EnumMember, Field, Ir, Type};
+use crate::config::{Collisions, Config, TypeMapping};
+use crate::input::Input;
 use failure::{format_err, Error};
 use inflector::Inflector;
-use jsl::schema::{Form, Type};
-use jsl::Schema;
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufWriter, Write};
+use std::io::Write;
 use std::path::PathBuf;
 
+// Words which can't be used as identifiers.
+const KEYWORDS: &[&str] = &[
+    "_",
+    "abstract",
+    "assert",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "strictfp",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "true",
+    "try",
+    "void",
+    "volatile",
+    "while",
+];
+
+// Names generated classes can't have, since generated code uses them itself,
+// either from java.lang or from imports.
+const RESERVED_TYPE_NAMES: &[&str] = &[
+    "ArrayList",
+    "Arrays",
+    "Boolean",
+    "DateTimeParseException",
+    "Double",
+    "Instant",
+    "Integer",
+    "Iterator",
+    "JsonIgnoreProperties",
+    "JsonNode",
+    "JsonProperty",
+    "JsonSubTypes",
+    "JsonTypeInfo",
+    "List",
+    "Map",
+    "Object",
+    "OffsetDateTime",
+    "String",
+    "StringBuilder",
+    "ValidationError",
+];
+
+// The import for annotations giving properties and enum values their names in
+// JSON, when those aren't valid Java identifiers.
+const JSON_PROPERTY_IMPORT: &str = "com.fasterxml.jackson.annotation.JsonProperty";
+const JSON_INCLUDE_IMPORT: &str = "com.fasterxml.jackson.annotation.JsonInclude";
+
+// The imports needed by classes holding the properties of an object.
+const TYPE_IMPORTS: &[&str] = &[
+    "com.fasterxml.jackson.annotation.JsonIgnoreProperties",
+    "java.time.Instant",
+    "java.util.List",
+    "java.util.Map",
+];
+
+// The imports needed by validators.
+const VALIDATOR_IMPORTS: &[&str] = &[
+    "com.fasterxml.jackson.databind.JsonNode",
+    "java.util.ArrayList",
+    "java.util.Iterator",
+    "java.util.List",
+    "java.util.Map",
+];
+
 #[derive(Debug)]
 pub struct Codegen {
-    root_name: String,
+    strict: bool,
+    names: HashMap<String, String>,
+    collisions: Collisions,
+    types: HashMap<String, TypeMapping>,
     out_dir: PathBuf,
     out_pkg: Vec<String>,
 }
 
 impl codegen::Codegen for Codegen {
-    type Ast = Vec<TopLevel>;
+    type Ast = Ir;
 
     fn args<'a, 'b>(app: clap::App<'a, 'b>) -> clap::App<'a, 'b> {
         app.args(&[
@@ -31,26 +139,34 @@ impl codegen::Codegen for Codegen {
         ])
     }
 
-    fn from_args(matches: &clap::ArgMatches) -> Result<Option<Codegen>, Error> {
+    fn from_args(matches: &clap::ArgMatches, config: &Config) -> Result<Option<Codegen>, Error> {
+        let mut config = config.clone();
         if let Some(java_out) = matches.value_of("java-out") {
-            let input = PathBuf::from(matches.value_of("INPUT").unwrap());
-            let input_stem = input
-                .file_stem()
-                .ok_or(format_err!("Could not infer file stem from input"))?;
-            let input_stem_str = input_stem
-                .to_str()
-                .ok_or(format_err!("Could not convert input file name to UTF-8"))?;
-            let root_name = input_stem_str.to_pascal_case();
-
-            let java_pkg: Vec<_> = matches
-                .value_of("java-pkg")
+            config.java.out = Some(java_out.to_owned());
+        }
+        if let Some(pkg) = matches.value_of("java-pkg") {
+            config.java.package = Some(pkg.to_owned());
+        }
+
+        Self::from_config(&config)
+    }
+
+    fn from_config(config: &Config) -> Result<Option<Codegen>, Error> {
+        if let Some(ref java_out) = config.java.out {
+            let java_pkg: Vec<_> = config
+                .java
+                .package
+                .as_deref()
                 .ok_or(format_err!("--java-pkg required for Java output"))?
-                .split(".")
+                .split('.')
                 .map(|s| s.to_owned())
                 .collect();
 
             Ok(Some(Codegen {
-                root_name,
+                strict: config.strict,
+                names: config.names.clone(),
+                collisions: config.collisions,
+                types: config.java.types.clone(),
                 out_dir: PathBuf::from(java_out).join(java_pkg.join("/")),
                 out_pkg: java_pkg,
             }))
@@ -59,49 +175,136 @@ impl codegen::Codegen for Codegen {
         }
     }
 
-    fn transform(&self, schema: &Schema) -> Result<Self::Ast, Error> {
-        let mut out = Vec::new();
-
-        // First, generate each of the definitions.
-        for (name, def) in schema.definitions().as_ref().unwrap() {
-            let mut path = vec![name.clone()];
-            self.transform_subschema(&mut out, &mut path, def);
-        }
-
-        // Then, generate the root schema.
-        let mut path = vec![self.root_name.clone()];
-        self.transform_subschema(&mut out, &mut path, schema);
+    fn transform(&self, input: &Input) -> Result<Self::Ast, Error> {
+        let mut ir = Ir::from_input(
+            &input.name.to_pascal_case(),
+            input,
+            self.strict,
+            &self.names,
+            self.collisions,
+            RESERVED_TYPE_NAMES,
+            "java",
+            &self.types,
+        )?;
 
-        Ok(out)
+        ir.name_members(
+            self.collisions,
+            &|_, field| Self::field_name(field),
+            &|_, val| Self::constant_name(val),
+        )?;
+        Ok(ir)
     }
 
-    fn serialize(&self, ast: &Self::Ast) -> Result<(), Error> {
-        for top_level in ast {
-            let name = match top_level {
-                TopLevel::Class(ref name, _) => name,
-                TopLevel::Enum(ref name, _) => name,
-            };
+    fn serialize(&self, ir: &Self::Ast, output: &mut codegen::Output) -> Result<(), Error> {
+        self.serialize_validation_error(output)?;
 
-            let path = self.out_dir.join(format!("{}.java", name));
-            let mut out = BufWriter::new(File::create(path.clone())?);
+        // Everything is generated into the same package, so declarations
+        // from other inputs need no imports.
+        for decl in ir.local_decls() {
+            match decl.kind {
+                // Java has no type aliases. References to aliases are resolved
+                // to the aliased type instead, but the schemas they come from
+                // still get a class of their own to hold their validator.
+                DeclKind::Alias(_) => {
+                    let mut out = self.create_file(output, &decl.name, VALIDATOR_IMPORTS)?;
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    writeln!(out, "public final class {} {{", decl.name)?;
+                    writeln!(out, "    private {}() {{}}", decl.name)?;
+                    writeln!(out)?;
+                    self.serialize_validator(&mut out, ir, decl)?;
+                    writeln!(out, "}}")?;
+                }
+                DeclKind::Record(ref fields) => {
+                    let mut imports = [TYPE_IMPORTS, VALIDATOR_IMPORTS].concat();
+                    imports.extend(Self::mapped_imports(ir, fields));
+                    if Self::renames_fields(fields) {
+                        imports.push(JSON_PROPERTY_IMPORT);
+                    }
+                    if fields.iter().any(|field| !field.required) {
+                        imports.push(JSON_INCLUDE_IMPORT);
+                    }
 
-            writeln!(out, "package {};", self.out_pkg.join("."))?;
-            writeln!(out, "")?;
+                    let mut out = self.create_file(output, &decl.name, &imports)?;
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    self.serialize_class(&mut out, ir, &decl.name, None, fields)?;
+                    writeln!(out)?;
+                    self.serialize_validator(&mut out, ir, decl)?;
+                    writeln!(out, "}}")?;
+                }
+                DeclKind::Enum(ref vals) => {
+                    // Jackson goes by the names of constants, unless they're
+                    // given a name of their own.
+                    let mut imports = VALIDATOR_IMPORTS.to_vec();
+                    if vals.iter().any(|val| Self::constant_name(val) != val.value) {
+                        imports.push(JSON_PROPERTY_IMPORT);
+                    }
 
-            match top_level {
-                TopLevel::Class(ref name, ref props) => {
-                    writeln!(out, "public class {} {{", name)?;
-                    for (name, value) in props {
-                        writeln!(out, "    public {} {};", value.unboxed(), name)?;
+                    let mut out = self.create_file(output, &decl.name, &imports)?;
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    writeln!(out, "public enum {} {{", decl.name)?;
+                    for val in vals {
+                        let name = Self::constant_name(val);
+                        if name != val.value {
+                            writeln!(out, "   @JsonProperty({:?})", val.value)?;
+                        }
+                        writeln!(out, "   {},", name)?;
                     }
+                    writeln!(out, "   ;")?;
+                    writeln!(out)?;
+                    self.serialize_validator(&mut out, ir, decl)?;
                     writeln!(out, "}}")?;
                 }
-                TopLevel::Enum(ref name, ref vals) => {
-                    writeln!(out, "public enum {} {{", name)?;
-                    for val in vals {
-                        writeln!(out, "   {},", val)?;
+                DeclKind::Union(ref tag, ref variants) => {
+                    let imports = [
+                        &[
+                            "com.fasterxml.jackson.annotation.JsonSubTypes",
+                            "com.fasterxml.jackson.annotation.JsonTypeInfo",
+                        ],
+                        VALIDATOR_IMPORTS,
+                    ]
+                    .concat();
+
+                    let mut out = self.create_file(output, &decl.name, &imports)?;
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    writeln!(
+                        out,
+                        "@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = {:?})",
+                        tag
+                    )?;
+                    writeln!(out, "@JsonSubTypes({{")?;
+                    for variant in variants {
+                        writeln!(
+                            out,
+                            "    @JsonSubTypes.Type(value = {}.class, name = {:?}),",
+                            variant.name, variant.tag_value
+                        )?;
                     }
+                    writeln!(out, "}})")?;
+                    writeln!(out, "public abstract class {} {{", decl.name)?;
+                    self.serialize_validator(&mut out, ir, decl)?;
                     writeln!(out, "}}")?;
+
+                    for variant in variants {
+                        let mut imports = TYPE_IMPORTS.to_vec();
+                        imports.extend(Self::mapped_imports(ir, &variant.fields));
+                        if Self::renames_fields(&variant.fields) {
+                            imports.push(JSON_PROPERTY_IMPORT);
+                        }
+                        if variant.fields.iter().any(|field| !field.required) {
+                            imports.push(JSON_INCLUDE_IMPORT);
+                        }
+
+                        let mut out = self.create_file(output, &variant.name, &imports)?;
+                        self.serialize_description(&mut out, "", &variant.description)?;
+                        self.serialize_class(
+                            &mut out,
+                            ir,
+                            &variant.name,
+                            Some(&decl.name),
+                            &variant.fields,
+                        )?;
+                        writeln!(out, "}}")?;
+                    }
                 }
             };
         }
@@ -111,105 +314,646 @@ impl codegen::Codegen for Codegen {
 }
 
 impl Codegen {
-    fn transform_subschema(
+    // Create the file for a top-level class, and write out its package
+    // declaration and imports.
+    fn create_file<'a>(
         &self,
-        out: &mut Vec<TopLevel>,
-        path: &mut Vec<String>,
-        schema: &Schema,
-    ) -> JavaType {
-        match schema.form() {
-            Form::Empty => JavaType::Object,
-            Form::Ref(ref def) => JavaType::Identifer(Self::path_to_identifier(&[def.to_owned()])),
-            Form::Type(Type::Boolean) => JavaType::Boolean,
-            Form::Type(Type::Number) => JavaType::Double,
-            Form::Type(Type::String) => JavaType::String,
-            Form::Type(Type::Timestamp) => JavaType::Timestamp,
-            Form::Enum(ref vals) => {
-                let name = Self::path_to_identifier(&path);
-                out.push(TopLevel::Enum(name.clone(), vals.iter().cloned().collect()));
-                JavaType::Identifer(name)
+        output: &'a mut codegen::Output,
+        name: &str,
+        imports: &[&str],
+    ) -> Result<&'a mut Vec<u8>, Error> {
+        let path = self.out_dir.join(format!("{}.java", name));
+        let out = output.create(path);
+
+        writeln!(out, "package {};", self.out_pkg.join("."))?;
+        writeln!(out)?;
+
+        let mut imports = imports.to_vec();
+        imports.sort();
+        imports.dedup();
+        for import in &imports {
+            writeln!(out, "import {};", import)?;
+        }
+
+        if !imports.is_empty() {
+            writeln!(out)?;
+        }
+
+        Ok(out)
+    }
+
+    // Write out the class that validators report errors with, along with the
+    // helpers they share. Errors are reported the same way the jsl crate does:
+    // as a pair of JSON Pointers into the instance and the schema.
+    fn serialize_validation_error(&self, output: &mut codegen::Output) -> Result<(), Error> {
+        let out = self.create_file(
+            output,
+            "ValidationError",
+            &[
+                "com.fasterxml.jackson.databind.JsonNode",
+                "java.time.OffsetDateTime",
+                "java.time.format.DateTimeParseException",
+                "java.util.ArrayList",
+                "java.util.Arrays",
+                "java.util.List",
+            ],
+        )?;
+
+        writeln!(out, "public class ValidationError {{")?;
+        writeln!(out, "    public final String instancePath;")?;
+        writeln!(out, "    public final String schemaPath;")?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "    public ValidationError(List<String> instancePath, String schemaPath) {{"
+        )?;
+        writeln!(out, "        StringBuilder pointer = new StringBuilder();")?;
+        writeln!(out, "        for (String token : instancePath) {{")?;
+        writeln!(
+            out,
+            "            pointer.append('/').append(token.replace(\"~\", \"~0\").replace(\"/\", \"~1\"));"
+        )?;
+        writeln!(out, "        }}")?;
+        writeln!(out)?;
+        writeln!(out, "        this.instancePath = pointer.toString();")?;
+        writeln!(out, "        this.schemaPath = schemaPath;")?;
+        writeln!(out, "    }}")?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "    static List<String> append(List<String> path, String... tokens) {{"
+        )?;
+        writeln!(out, "        List<String> out = new ArrayList<>(path);")?;
+        writeln!(out, "        out.addAll(Arrays.asList(tokens));")?;
+        writeln!(out, "        return out;")?;
+        writeln!(out, "    }}")?;
+        writeln!(out)?;
+        writeln!(out, "    static boolean isTimestamp(JsonNode instance) {{")?;
+        writeln!(out, "        if (!instance.isTextual()) {{")?;
+        writeln!(out, "            return false;")?;
+        writeln!(out, "        }}")?;
+        writeln!(out)?;
+        writeln!(out, "        try {{")?;
+        writeln!(out, "            OffsetDateTime.parse(instance.asText());")?;
+        writeln!(out, "            return true;")?;
+        writeln!(out, "        }} catch (DateTimeParseException e) {{")?;
+        writeln!(out, "            return false;")?;
+        writeln!(out, "        }}")?;
+        writeln!(out, "    }}")?;
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
+
+    // Write out a class, leaving it open for the caller to add more members.
+    // If a parent is provided, the class is one case of a discriminated union,
+    // and extends the union's abstract class.
+    fn serialize_class(
+        &self,
+        out: &mut dyn Write,
+        ir: &Ir,
+        name: &str,
+        parent: Option<&str>,
+        fields: &[Field],
+    ) -> Result<(), Error> {
+        // Jackson rejects unknown properties by default, which is only what
+        // the schema calls for when it's being used strictly.
+        writeln!(out, "@JsonIgnoreProperties(ignoreUnknown = {})", !ir.strict)?;
+        match parent {
+            Some(parent) => writeln!(out, "public class {} extends {} {{", name, parent)?,
+            None => writeln!(out, "public class {} {{", name)?,
+        };
+
+        for field in fields {
+            let name = Self::field_name(field);
+
+            self.serialize_description(out, "    ", &field.description)?;
+            if name != field.name {
+                writeln!(out, "    @JsonProperty({:?})", field.name)?;
             }
-            Form::Elements(ref sub_schema) => {
-                JavaType::List(Box::new(self.transform_subschema(out, path, sub_schema)))
+
+            // Optional properties have to be able to be missing, so they're
+            // never primitives, and are left out of the output when null.
+            if !field.required {
+                writeln!(out, "    @JsonInclude(JsonInclude.Include.NON_NULL)")?;
             }
-            Form::Properties(ref required, ref optional, _) => {
-                let mut props = HashMap::new();
-                for (name, prop) in required {
-                    path.push(name.clone());
-                    let value = self.transform_subschema(out, path, prop);
-                    path.pop();
-
-                    props.insert(name.clone(), value);
+            writeln!(
+                out,
+                "    public {} {};",
+                self.type_expr(ir, &field.value, !field.required),
+                name
+            )?;
+        }
+
+        Ok(())
+    }
+
+    // Write out the validator methods for a declaration. The public method
+    // collects all of the errors for an instance, and the other method is how
+    // validators for other declarations refer to this one.
+    fn serialize_validator(&self, out: &mut dyn Write, ir: &Ir, decl: &Decl) -> Result<(), Error> {
+        let schema_path = &decl.schema_path;
+
+        writeln!(
+            out,
+            "    public static List<ValidationError> validate(JsonNode instance) {{"
+        )?;
+        writeln!(
+            out,
+            "        List<ValidationError> errors = new ArrayList<>();"
+        )?;
+        writeln!(
+            out,
+            "        validate(instance, new ArrayList<>(), errors);"
+        )?;
+        writeln!(out, "        return errors;")?;
+        writeln!(out, "    }}")?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {{"
+        )?;
+
+        match decl.kind {
+            DeclKind::Alias(ref typ) => {
+                self.serialize_check(out, ir, typ, "instance", &[], schema_path, 0, "        ")?;
+            }
+            DeclKind::Record(ref fields) => {
+                // As in jsl, which keyword an error about a non-object is
+                // attributed to depends on which keywords are present.
+                let keyword = if fields.iter().any(|field| field.required) {
+                    "properties"
+                } else {
+                    "optionalProperties"
+                };
+
+                writeln!(out, "        if (!instance.isObject()) {{")?;
+                self.serialize_error(out, &[], schema_path, &[keyword], "            ")?;
+                writeln!(out, "            return;")?;
+                writeln!(out, "        }}")?;
+                self.serialize_field_checks(out, ir, fields, schema_path, "        ")?;
+                if ir.strict {
+                    self.serialize_unknown_check(out, fields, None, schema_path, "        ")?;
                 }
+            }
+            DeclKind::Enum(ref vals) => {
+                writeln!(out, "        if (instance.isTextual()) {{")?;
+                writeln!(out, "            switch (instance.asText()) {{")?;
+                for val in vals {
+                    writeln!(out, "            case {:?}:", val.value)?;
+                }
+                writeln!(out, "                return;")?;
+                writeln!(out, "            }}")?;
+                writeln!(out, "        }}")?;
+                writeln!(out)?;
+                self.serialize_error(out, &[], schema_path, &["enum"], "        ")?;
+            }
+            DeclKind::Union(ref tag, ref variants) => {
+                let tag_path = [format!("{:?}", tag)];
 
-                for (name, prop) in optional {
-                    path.push(name.clone());
-                    let value = self.transform_subschema(out, path, prop);
-                    path.pop();
+                writeln!(out, "        if (!instance.isObject()) {{")?;
+                self.serialize_error(out, &[], schema_path, &["discriminator"], "            ")?;
+                writeln!(out, "            return;")?;
+                writeln!(out, "        }}")?;
+                writeln!(out, "        if (!instance.has({:?})) {{", tag)?;
+                self.serialize_error(
+                    out,
+                    &[],
+                    schema_path,
+                    &["discriminator", "tag"],
+                    "            ",
+                )?;
+                writeln!(out, "            return;")?;
+                writeln!(out, "        }}")?;
+                writeln!(out, "        if (!instance.get({:?}).isTextual()) {{", tag)?;
+                self.serialize_error(
+                    out,
+                    &tag_path,
+                    schema_path,
+                    &["discriminator", "tag"],
+                    "            ",
+                )?;
+                writeln!(out, "            return;")?;
+                writeln!(out, "        }}")?;
+                writeln!(out)?;
+                writeln!(out, "        switch (instance.get({:?}).asText()) {{", tag)?;
+                for variant in variants {
+                    let variant_path = [
+                        &schema_path[..],
+                        &[
+                            "discriminator".to_owned(),
+                            "mapping".to_owned(),
+                            variant.tag_value.clone(),
+                        ],
+                    ]
+                    .concat();
 
-                    props.insert(name.clone(), value);
+                    writeln!(out, "        case {:?}:", variant.tag_value)?;
+                    self.serialize_field_checks(
+                        out,
+                        ir,
+                        &variant.fields,
+                        &variant_path,
+                        "            ",
+                    )?;
+                    if ir.strict {
+                        self.serialize_unknown_check(
+                            out,
+                            &variant.fields,
+                            Some(tag),
+                            &variant_path,
+                            "            ",
+                        )?;
+                    }
+                    writeln!(out, "            break;")?;
                 }
+                writeln!(out, "        default:")?;
+                self.serialize_error(
+                    out,
+                    &tag_path,
+                    schema_path,
+                    &["discriminator", "mapping"],
+                    "            ",
+                )?;
+                writeln!(out, "        }}")?;
+            }
+        };
+
+        writeln!(out, "    }}")?;
+
+        Ok(())
+    }
+
+    // Write out the checks on the properties of `instance`, which the caller
+    // has already made sure is an object.
+    fn serialize_field_checks(
+        &self,
+        out: &mut dyn Write,
+        ir: &Ir,
+        fields: &[Field],
+        schema_path: &[String],
+        indent: &str,
+    ) -> Result<(), Error> {
+        for field in fields {
+            let keyword = if field.required {
+                "properties"
+            } else {
+                "optionalProperties"
+            };
 
-                let name = Self::path_to_identifier(&path);
-                out.push(TopLevel::Class(name.clone(), props));
-                JavaType::Identifer(name)
+            let field_path = [schema_path, &[keyword.to_owned(), field.name.clone()]].concat();
+            let inner_indent = format!("{}    ", indent);
+
+            if let Type::Any = field.value {
+                // Anything goes, so all that's left to check is whether a
+                // required property is present.
+                if field.required {
+                    writeln!(out, "{}if (!instance.has({:?})) {{", indent, field.name)?;
+                    self.serialize_error(out, &[], &field_path, &[], &inner_indent)?;
+                    writeln!(out, "{}}}", indent)?;
+                }
+
+                continue;
             }
-            Form::Values(ref sub_schema) => {
-                JavaType::Map(Box::new(self.transform_subschema(out, path, sub_schema)))
+
+            writeln!(out, "{}if (instance.has({:?})) {{", indent, field.name)?;
+            writeln!(
+                out,
+                "{}JsonNode v0 = instance.get({:?});",
+                inner_indent, field.name
+            )?;
+            self.serialize_check(
+                out,
+                ir,
+                &field.value,
+                "v0",
+                &[format!("{:?}", field.name)],
+                &field_path,
+                0,
+                &inner_indent,
+            )?;
+
+            if field.required {
+                writeln!(out, "{}}} else {{", indent)?;
+                self.serialize_error(out, &[], &field_path, &[], &inner_indent)?;
             }
-            Form::Discriminator(_, _) => {
-                JavaType::Object // TODO
+
+            writeln!(out, "{}}}", indent)?;
+        }
+
+        Ok(())
+    }
+
+    // Write out a check that `instance` has no properties other than the
+    // fields and, for the cases of a discriminated union, the tag.
+    fn serialize_unknown_check(
+        &self,
+        out: &mut dyn Write,
+        fields: &[Field],
+        tag: Option<&str>,
+        schema_path: &[String],
+        indent: &str,
+    ) -> Result<(), Error> {
+        writeln!(
+            out,
+            "{}for (Iterator<String> it = instance.fieldNames(); it.hasNext();) {{",
+            indent
+        )?;
+        writeln!(out, "{}    String key = it.next();", indent)?;
+        writeln!(out, "{}    switch (key) {{", indent)?;
+        for name in fields.iter().map(|field| field.name.as_str()).chain(tag) {
+            writeln!(out, "{}    case {:?}:", indent, name)?;
+        }
+        if !fields.is_empty() || tag.is_some() {
+            writeln!(out, "{}        break;", indent)?;
+        }
+        writeln!(out, "{}    default:", indent)?;
+        self.serialize_error(
+            out,
+            &["key".to_owned()],
+            schema_path,
+            &[],
+            &format!("{}        ", indent),
+        )?;
+        writeln!(out, "{}    }}", indent)?;
+        writeln!(out, "{}}}", indent)?;
+
+        Ok(())
+    }
+
+    // Write out the statements checking that `expr` is a valid instance of
+    // `typ`. The instance path of `expr` is `path` followed by the tokens in
+    // `path_tokens`, which are Java expressions. Depth is used to keep loop
+    // variables from shadowing one another.
+    #[allow(clippy::too_many_arguments)]
+    fn serialize_check(
+        &self,
+        out: &mut dyn Write,
+        ir: &Ir,
+        typ: &Type,
+        expr: &str,
+        path_tokens: &[String],
+        schema_path: &[String],
+        depth: usize,
+        indent: &str,
+    ) -> Result<(), Error> {
+        let inner_indent = format!("{}    ", indent);
+        let cond = match typ {
+            Type::Any => return Ok(()),
+            Type::Boolean => format!("!{}.isBoolean()", expr),
+            Type::Number => format!("!{}.isNumber()", expr),
+            Type::String => format!("!{}.isTextual()", expr),
+            Type::Timestamp => format!("!ValidationError.isTimestamp({})", expr),
+            Type::Mapped(ref typ, _) => {
+                return self.serialize_check(
+                    out,
+                    ir,
+                    typ,
+                    expr,
+                    path_tokens,
+                    schema_path,
+                    depth,
+                    indent,
+                );
+            }
+            Type::Ref(ref id) => {
+                // Aliases don't get a class, so their checks are inlined. The
+                // errors they produce are still attributed to the definition
+                // they come from.
+                if let Some(Decl {
+                    kind: DeclKind::Alias(ref aliased),
+                    schema_path: ref alias_path,
+                    ..
+                }) = ir.decl(id)
+                {
+                    return self.serialize_check(
+                        out,
+                        ir,
+                        aliased,
+                        expr,
+                        path_tokens,
+                        alias_path,
+                        depth,
+                        indent,
+                    );
+                }
+
+                writeln!(
+                    out,
+                    "{}{}.validate({}, {}, errors);",
+                    indent,
+                    id,
+                    expr,
+                    Self::path_expr(path_tokens)
+                )?;
+                return Ok(());
+            }
+            Type::Elements(ref sub_typ) => {
+                if let Type::Any = **sub_typ {
+                    writeln!(out, "{}if (!{}.isArray()) {{", indent, expr)?;
+                } else {
+                    let index = format!("i{}", depth);
+                    let elem = format!("v{}", depth + 1);
+
+                    writeln!(out, "{}if ({}.isArray()) {{", indent, expr)?;
+                    writeln!(
+                        out,
+                        "{}for (int {} = 0; {} < {}.size(); {}++) {{",
+                        inner_indent, index, index, expr, index
+                    )?;
+                    writeln!(
+                        out,
+                        "{}    JsonNode {} = {}.get({});",
+                        inner_indent, elem, expr, index
+                    )?;
+                    self.serialize_check(
+                        out,
+                        ir,
+                        sub_typ,
+                        &elem,
+                        &[path_tokens, &[format!("Integer.toString({})", index)]].concat(),
+                        &[schema_path, &["elements".to_owned()]].concat(),
+                        depth + 1,
+                        &format!("{}    ", inner_indent),
+                    )?;
+                    writeln!(out, "{}}}", inner_indent)?;
+                    writeln!(out, "{}}} else {{", indent)?;
+                }
+                self.serialize_error(out, path_tokens, schema_path, &["elements"], &inner_indent)?;
+                writeln!(out, "{}}}", indent)?;
+                return Ok(());
             }
+            Type::Values(ref sub_typ) => {
+                if let Type::Any = **sub_typ {
+                    writeln!(out, "{}if (!{}.isObject()) {{", indent, expr)?;
+                } else {
+                    let iter = format!("it{}", depth);
+                    let entry = format!("e{}", depth);
+                    let elem = format!("v{}", depth + 1);
+
+                    writeln!(out, "{}if ({}.isObject()) {{", indent, expr)?;
+                    writeln!(
+                        out,
+                        "{}for (Iterator<Map.Entry<String, JsonNode>> {} = {}.fields(); {}.hasNext();) {{",
+                        inner_indent, iter, expr, iter
+                    )?;
+                    writeln!(
+                        out,
+                        "{}    Map.Entry<String, JsonNode> {} = {}.next();",
+                        inner_indent, entry, iter
+                    )?;
+                    writeln!(
+                        out,
+                        "{}    JsonNode {} = {}.getValue();",
+                        inner_indent, elem, entry
+                    )?;
+                    self.serialize_check(
+                        out,
+                        ir,
+                        sub_typ,
+                        &elem,
+                        &[path_tokens, &[format!("{}.getKey()", entry)]].concat(),
+                        &[schema_path, &["values".to_owned()]].concat(),
+                        depth + 1,
+                        &format!("{}    ", inner_indent),
+                    )?;
+                    writeln!(out, "{}}}", inner_indent)?;
+                    writeln!(out, "{}}} else {{", indent)?;
+                }
+                self.serialize_error(out, path_tokens, schema_path, &["values"], &inner_indent)?;
+                writeln!(out, "{}}}", indent)?;
+                return Ok(());
+            }
+        };
+
+        writeln!(out, "{}if ({}) {{", indent, cond)?;
+        self.serialize_error(out, path_tokens, schema_path, &["type"], &inner_indent)?;
+        writeln!(out, "{}}}", indent)?;
+
+        Ok(())
+    }
+
+    // Write out a statement adding an error to `errors`. The schema path is
+    // `schema_path` followed by the keywords in `rest`.
+    fn serialize_error(
+        &self,
+        out: &mut dyn Write,
+        path_tokens: &[String],
+        schema_path: &[String],
+        rest: &[&str],
+        indent: &str,
+    ) -> Result<(), Error> {
+        writeln!(
+            out,
+            "{}errors.add(new ValidationError({}, {:?}));",
+            indent,
+            Self::path_expr(path_tokens),
+            ir::json_pointer(schema_path, rest)
+        )?;
+
+        Ok(())
+    }
+
+    // The name of the Java field for a property. Properties are named just as
+    // they are in JSON where possible.
+    fn field_name(field: &Field) -> String {
+        let name = &field.name;
+        if let Some(ref identifier) = field.identifier {
+            identifier.clone()
+        } else if Self::is_identifier(name) {
+            codegen::identifier(name, "_", KEYWORDS)
+        } else {
+            codegen::identifier(&name.to_camel_case(), "_", KEYWORDS)
         }
     }
 
-    fn path_to_identifier(path: &[String]) -> String {
-        path.join("_").to_pascal_case()
+    // The name of the enum constant for a value. As with fields, values are
+    // used as they are where possible.
+    fn constant_name(val: &EnumMember) -> String {
+        if let Some(ref identifier) = val.identifier {
+            identifier.clone()
+        } else if Self::is_identifier(&val.value) {
+            codegen::identifier(&val.value, "_", KEYWORDS)
+        } else {
+            codegen::identifier(&val.value.to_screaming_snake_case(), "_", KEYWORDS)
+        }
     }
-}
 
-#[derive(Debug)]
-pub enum TopLevel {
-    Class(String, HashMap<String, JavaType>),
-    Enum(String, Vec<String>),
-}
+    // The imports needed for the types mapped by the user among some fields.
+    // Aliases are looked through, since Java has no way to refer to them.
+    fn mapped_imports<'a>(ir: &'a Ir, fields: &'a [Field]) -> Vec<&'a str> {
+        let mut imports = Vec::new();
+        for field in fields {
+            Self::type_imports(ir, &field.value, &mut imports);
+        }
 
-#[derive(Debug)]
-pub enum JavaType {
-    Object,
-    Boolean,
-    Double,
-    String,
-    Timestamp,
-    Identifer(String),
-    List(Box<JavaType>),
-    Map(Box<JavaType>),
-}
+        imports
+    }
 
-impl JavaType {
-    fn unboxed(&self) -> String {
-        match self {
-            JavaType::Object => "Object".to_owned(),
-            JavaType::Boolean => "boolean".to_owned(),
-            JavaType::Double => "double".to_owned(),
-            JavaType::String => "String".to_owned(),
-            JavaType::Timestamp => "Instant".to_owned(),
-            JavaType::Identifer(ref id) => id.to_owned(),
-            JavaType::List(ref typ) => format!("List<{}>", typ.boxed()),
-            JavaType::Map(ref typ) => format!("Map<String, {}>", typ.boxed()),
-        }
-    }
-
-    fn boxed(&self) -> String {
-        match self {
-            JavaType::Object => "Object".to_owned(),
-            JavaType::Boolean => "Boolean".to_owned(),
-            JavaType::Double => "Double".to_owned(),
-            JavaType::String => "String".to_owned(),
-            JavaType::Timestamp => "Instant".to_owned(),
-            JavaType::Identifer(ref id) => id.to_owned(),
-            JavaType::List(ref typ) => format!("List<{}>", typ.boxed()),
-            JavaType::Map(ref typ) => format!("Map<String, {}>", typ.boxed()),
+    fn type_imports<'a>(ir: &'a Ir, typ: &'a Type, imports: &mut Vec<&'a str>) {
+        match ir.resolve(typ) {
+            Type::Mapped(_, ref mapping) => imports.extend(mapping.import.as_deref()),
+            Type::Elements(ref typ) | Type::Values(ref typ) => Self::type_imports(ir, typ, imports),
+            _ => {}
         }
     }
+
+    // Whether the Java fields for a set of properties are named differently
+    // from the properties themselves.
+    fn renames_fields(fields: &[Field]) -> bool {
+        fields
+            .iter()
+            .any(|field| Self::field_name(field) != field.name)
+    }
+
+    // Whether a name can be used as it is. Java allows more than this, such
+    // as dollar signs, but they're best avoided.
+    fn is_identifier(name: &str) -> bool {
+        name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    }
+
+    fn path_expr(path_tokens: &[String]) -> String {
+        if path_tokens.is_empty() {
+            "path".to_owned()
+        } else {
+            format!("ValidationError.append(path, {})", path_tokens.join(", "))
+        }
+    }
+
+    // Render a type. Generic type parameters can't be primitives, so those
+    // positions ask for the boxed version of the type.
+    fn type_expr(&self, ir: &Ir, typ: &Type, boxed: bool) -> String {
+        match ir.resolve(typ) {
+            Type::Any => "Object".to_owned(),
+            Type::Boolean if boxed => "Boolean".to_owned(),
+            Type::Boolean => "boolean".to_owned(),
+            Type::Number if boxed => "Double".to_owned(),
+            Type::Number => "double".to_owned(),
+            Type::String => "String".to_owned(),
+            Type::Timestamp => "Instant".to_owned(),
+            Type::Mapped(_, ref mapping) => mapping.name.clone(),
+            Type::Ref(ref id) => id.clone(),
+            Type::Elements(ref typ) => format!("List<{}>", self.type_expr(ir, typ, true)),
+            Type::Values(ref typ) => format!("Map<String, {}>", self.type_expr(ir, typ, true)),
+        }
+    }
+
+    fn serialize_description(
+        &self,
+        out: &mut dyn Write,
+        indent: &str,
+        description: &Option<String>,
+    ) -> Result<(), Error> {
+        if let Some(ref description) = description {
+            writeln!(out, "{}/**", indent)?;
+            for line in description.lines() {
+                let line = format!("{} * {}", indent, line.replace("*/", "*&#47;"));
+                writeln!(out, "{}", line.trim_end())?;
+            }
+            writeln!(out, "{} */", indent)?;
+        }
+
+        Ok(())
+    }
 }
@@ -0,0 +1,403 @@
+use crate::codegen;
+use crate::codegen::ir::{DeclKind, EnumMember, Field, Ir, Type};
+use crate::config::{Collisions, Config, TypeMapping};
+use crate::input::Input;
+use failure::{format_err, Error};
+use inflector::Inflector;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+
+// Words which can't be used as identifiers. Kotlin's soft and modifier
+// keywords are fine as property names, so only the hard keywords are here.
+const KEYWORDS: &[&str] = &[
+    "_",
+    "as",
+    "break",
+    "class",
+    "continue",
+    "do",
+    "else",
+    "false",
+    "for",
+    "fun",
+    "if",
+    "in",
+    "interface",
+    "is",
+    "null",
+    "object",
+    "package",
+    "return",
+    "super",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typealias",
+    "typeof",
+    "val",
+    "var",
+    "when",
+    "while",
+];
+
+// Names generated classes can't have, since generated code uses them itself,
+// either from the standard library or from imports.
+const RESERVED_TYPE_NAMES: &[&str] = &[
+    "Any",
+    "Boolean",
+    "Double",
+    "ExperimentalSerializationApi",
+    "JsonClassDiscriminator",
+    "JsonElement",
+    "JsonIgnoreUnknownKeys",
+    "List",
+    "Map",
+    "OptIn",
+    "SerialName",
+    "Serializable",
+    "String",
+];
+
+#[derive(Debug)]
+pub struct Codegen {
+    strict: bool,
+    names: HashMap<String, String>,
+    collisions: Collisions,
+    types: HashMap<String, TypeMapping>,
+    out_dir: PathBuf,
+    out_pkg: String,
+}
+
+impl codegen::Codegen for Codegen {
+    type Ast = Ir;
+
+    fn args<'a, 'b>(app: clap::App<'a, 'b>) -> clap::App<'a, 'b> {
+        app.args(&[
+            clap::Arg::with_name("kotlin-out")
+                .help("Kotlin output directory")
+                .takes_value(true)
+                .long("kotlin-out"),
+            clap::Arg::with_name("kotlin-pkg")
+                .help("Kotlin output package")
+                .takes_value(true)
+                .long("kotlin-pkg"),
+        ])
+    }
+
+    fn from_args(matches: &clap::ArgMatches, config: &Config) -> Result<Option<Codegen>, Error> {
+        let mut config = config.clone();
+        if let Some(kotlin_out) = matches.value_of("kotlin-out") {
+            config.kotlin.out = Some(kotlin_out.to_owned());
+        }
+        if let Some(pkg) = matches.value_of("kotlin-pkg") {
+            config.kotlin.package = Some(pkg.to_owned());
+        }
+
+        Self::from_config(&config)
+    }
+
+    fn from_config(config: &Config) -> Result<Option<Codegen>, Error> {
+        if let Some(ref kotlin_out) = config.kotlin.out {
+            let kotlin_pkg = config
+                .kotlin
+                .package
+                .clone()
+                .ok_or(format_err!("--kotlin-pkg required for Kotlin output"))?;
+
+            Ok(Some(Codegen {
+                strict: config.strict,
+                names: config.names.clone(),
+                collisions: config.collisions,
+                types: config.kotlin.types.clone(),
+                out_dir: PathBuf::from(kotlin_out).join(kotlin_pkg.replace('.', "/")),
+                out_pkg: kotlin_pkg,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn transform(&self, input: &Input) -> Result<Self::Ast, Error> {
+        let mut ir = Ir::from_input(
+            &input.name.to_pascal_case(),
+            input,
+            self.strict,
+            &self.names,
+            self.collisions,
+            RESERVED_TYPE_NAMES,
+            "kotlin",
+            &self.types,
+        )?;
+
+        ir.name_members(
+            self.collisions,
+            &|_, field| Self::field_name(field),
+            &|_, val| Self::constant_name(val),
+        )?;
+        Ok(ir)
+    }
+
+    fn serialize(&self, ir: &Self::Ast, output: &mut codegen::Output) -> Result<(), Error> {
+        let out_file_name = format!("{}.kt", ir.module.to_pascal_case());
+        let mut out = output.create(self.out_dir.join(out_file_name));
+
+        // Only import what the code below uses, so that the output doesn't
+        // trip warnings about unused imports.
+        let mut imports = Vec::new();
+        let mut experimental = false;
+        for decl in ir.local_decls() {
+            match decl.kind {
+                DeclKind::Alias(_) => {}
+                DeclKind::Record(ref fields) => {
+                    imports.push("kotlinx.serialization.Serializable");
+                    if Self::renames_fields(fields) {
+                        imports.push("kotlinx.serialization.SerialName");
+                    }
+                    if !ir.strict {
+                        imports.push("kotlinx.serialization.json.JsonIgnoreUnknownKeys");
+                        experimental = true;
+                    }
+                }
+                DeclKind::Enum(ref vals) => {
+                    imports.push("kotlinx.serialization.Serializable");
+                    if vals.iter().any(|val| Self::constant_name(val) != val.value) {
+                        imports.push("kotlinx.serialization.SerialName");
+                    }
+                }
+                DeclKind::Union(..) => {
+                    imports.push("kotlinx.serialization.Serializable");
+                    imports.push("kotlinx.serialization.SerialName");
+                    imports.push("kotlinx.serialization.json.JsonClassDiscriminator");
+                    if !ir.strict {
+                        imports.push("kotlinx.serialization.json.JsonIgnoreUnknownKeys");
+                    }
+                    experimental = true;
+                }
+            }
+        }
+
+        if ir.uses(&|typ| matches!(typ, Type::Any)) {
+            imports.push("kotlinx.serialization.json.JsonElement");
+        }
+
+        // Setting the discriminator, and ignoring unknown properties one class
+        // at a time, are both still experimental in kotlinx.serialization.
+        if experimental {
+            imports.push("kotlinx.serialization.ExperimentalSerializationApi");
+            writeln!(out, "@file:OptIn(ExperimentalSerializationApi::class)")?;
+            writeln!(out)?;
+        }
+
+        // The output for every input goes into the same package, so types
+        // from other inputs need no imports.
+        imports.extend(ir.mapped_imports().keys());
+        imports.sort();
+        imports.dedup();
+
+        writeln!(out, "package {}", self.out_pkg)?;
+        if !imports.is_empty() {
+            writeln!(out)?;
+        }
+        for import in imports {
+            writeln!(out, "import {}", import)?;
+        }
+
+        for decl in ir.local_decls() {
+            match decl.kind {
+                DeclKind::Alias(ref typ) => {
+                    writeln!(out)?;
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    writeln!(out, "typealias {} = {}", decl.name, self.type_expr(typ))?;
+                }
+                DeclKind::Record(ref fields) => {
+                    writeln!(out)?;
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    self.serialize_class(&mut out, ir, &decl.name, None, fields)?;
+                }
+                DeclKind::Enum(ref vals) => {
+                    writeln!(out)?;
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    writeln!(out, "@Serializable")?;
+                    writeln!(out, "enum class {} {{", decl.name)?;
+                    for val in vals {
+                        let name = Self::constant_name(val);
+                        if name != val.value {
+                            writeln!(out, "    @SerialName({})", Self::string(&val.value))?;
+                        }
+                        writeln!(out, "    {},", name)?;
+                    }
+                    writeln!(out, "}}")?;
+                }
+                DeclKind::Union(ref tag, ref variants) => {
+                    writeln!(out)?;
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    writeln!(out, "@Serializable")?;
+                    writeln!(out, "@JsonClassDiscriminator({})", Self::string(tag))?;
+                    writeln!(out, "sealed class {}", decl.name)?;
+
+                    for variant in variants {
+                        writeln!(out)?;
+                        self.serialize_description(&mut out, "", &variant.description)?;
+                        self.serialize_class(
+                            &mut out,
+                            ir,
+                            &variant.name,
+                            Some((&decl.name, &variant.tag_value)),
+                            &variant.fields,
+                        )?;
+                    }
+                }
+            };
+        }
+
+        Ok(())
+    }
+}
+
+impl Codegen {
+    // Write out a class holding the properties of an object. If a parent is
+    // provided, the class is one case of a discriminated union. The parent is
+    // given as the name of the union's sealed class and the tag value for this
+    // case.
+    fn serialize_class(
+        &self,
+        out: &mut dyn Write,
+        ir: &Ir,
+        name: &str,
+        parent: Option<(&str, &str)>,
+        fields: &[Field],
+    ) -> Result<(), Error> {
+        writeln!(out, "@Serializable")?;
+        if let Some((_, tag_value)) = parent {
+            writeln!(out, "@SerialName({})", Self::string(tag_value))?;
+        }
+
+        // kotlinx.serialization rejects unknown properties by default, which
+        // is only what the schema calls for when it's being used strictly.
+        if !ir.strict {
+            writeln!(out, "@JsonIgnoreUnknownKeys")?;
+        }
+
+        let supertype = match parent {
+            Some((parent, _)) => format!(" : {}()", parent),
+            None => "".to_owned(),
+        };
+
+        // Data classes need at least one property, so objects without any
+        // get a plain class.
+        if fields.is_empty() {
+            writeln!(out, "class {}{}", name, supertype)?;
+            return Ok(());
+        }
+
+        writeln!(out, "data class {}(", name)?;
+        for field in fields {
+            let field_name = Self::field_name(field);
+
+            self.serialize_description(out, "    ", &field.description)?;
+            if field_name != field.name {
+                writeln!(out, "    @SerialName({})", Self::string(&field.name))?;
+            }
+
+            if field.required {
+                writeln!(
+                    out,
+                    "    val {}: {},",
+                    field_name,
+                    self.type_expr(&field.value)
+                )?;
+            } else {
+                writeln!(
+                    out,
+                    "    val {}: {}? = null,",
+                    field_name,
+                    self.type_expr(&field.value)
+                )?;
+            }
+        }
+        writeln!(out, "){}", supertype)?;
+
+        Ok(())
+    }
+
+    // The name of the Kotlin property for a JSON property. Properties are named
+    // just as they are in JSON where possible.
+    fn field_name(field: &Field) -> String {
+        let name = &field.name;
+        if let Some(ref identifier) = field.identifier {
+            identifier.clone()
+        } else if Self::is_identifier(name) {
+            codegen::identifier(name, "_", KEYWORDS)
+        } else {
+            codegen::identifier(&name.to_camel_case(), "_", KEYWORDS)
+        }
+    }
+
+    // The name of the enum constant for a value. Names made up only of
+    // underscores are reserved in Kotlin, so values which don't start with a
+    // letter get a letter in front of them instead.
+    fn constant_name(val: &EnumMember) -> String {
+        match val.identifier {
+            Some(ref identifier) => identifier.clone(),
+            None => codegen::identifier(&val.value.to_screaming_snake_case(), "X", &[]),
+        }
+    }
+
+    // Whether the Kotlin properties for a set of JSON properties are named
+    // differently from the JSON properties themselves.
+    fn renames_fields(fields: &[Field]) -> bool {
+        fields
+            .iter()
+            .any(|field| Self::field_name(field) != field.name)
+    }
+
+    // Whether a name can be used as it is. Kotlin allows more than this with
+    // backticks, but they're best avoided.
+    fn is_identifier(name: &str) -> bool {
+        name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    }
+
+    // A string literal. Kotlin strings are templates, so dollar signs have to
+    // be escaped on top of what Rust escapes.
+    fn string(s: &str) -> String {
+        format!("{:?}", s).replace('$', "\\$")
+    }
+
+    fn type_expr(&self, typ: &Type) -> String {
+        match typ {
+            Type::Any => "JsonElement".to_owned(),
+            Type::Boolean => "Boolean".to_owned(),
+            Type::Number => "Double".to_owned(),
+            Type::String => "String".to_owned(),
+
+            // kotlinx.serialization has no timestamp type of its own, so
+            // these are left as they are in JSON.
+            Type::Timestamp => "String".to_owned(),
+            Type::Mapped(_, ref mapping) => mapping.name.clone(),
+            Type::Ref(ref id) => id.clone(),
+            Type::Elements(ref typ) => format!("List<{}>", self.type_expr(typ)),
+            Type::Values(ref typ) => format!("Map<String, {}>", self.type_expr(typ)),
+        }
+    }
+
+    fn serialize_description(
+        &self,
+        out: &mut dyn Write,
+        indent: &str,
+        description: &Option<String>,
+    ) -> Result<(), Error> {
+        if let Some(ref description) = description {
+            writeln!(out, "{}/**", indent)?;
+            for line in description.lines() {
+                let line = format!("{} * {}", indent, line.replace("*/", "*&#47;"));
+                writeln!(out, "{}", line.trim_end())?;
+            }
+            writeln!(out, "{} */", indent)?;
+        }
+
+        Ok(())
+    }
+}
@@ -1,9 +1,23 @@
+pub mod csharp;
+pub mod dart;
 pub mod go;
+pub mod ir;
 pub mod java;
+pub mod kotlin;
+pub mod protobuf;
+pub mod python;
+pub mod rust;
+pub mod swift;
 pub mod typescript;
 
+use crate::config::Config;
+use crate::input::Input;
 use failure::Error;
+use inflector::Inflector;
 use jsl::Schema;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
 
 pub trait Codegen
 where
@@ -12,7 +26,71 @@ where
     type Ast;
 
     fn args<'a, 'b>(app: clap::App<'a, 'b>) -> clap::App<'a, 'b>;
-    fn from_args(matches: &clap::ArgMatches) -> Result<Option<Self>, Error>;
-    fn transform(&self, schema: &Schema) -> Result<Self::Ast, Error>;
-    fn serialize(&self, ast: &Self::Ast) -> Result<(), Error>;
+    fn from_args(matches: &clap::ArgMatches, config: &Config) -> Result<Option<Self>, Error>;
+    fn from_config(config: &Config) -> Result<Option<Self>, Error>;
+    fn transform(&self, input: &Input) -> Result<Self::Ast, Error>;
+    fn serialize(&self, ast: &Self::Ast, out: &mut Output) -> Result<(), Error>;
+}
+
+// The files generated by code generators, by path. Nothing here is written to
+// disk until the caller decides to.
+#[derive(Debug, Default)]
+pub struct Output {
+    files: BTreeMap<PathBuf, Vec<u8>>,
+}
+
+impl Output {
+    // Start a file, replacing any earlier file at the same path.
+    pub fn create(&mut self, path: PathBuf) -> &mut Vec<u8> {
+        let file = self.files.entry(path).or_default();
+        file.clear();
+        file
+    }
+
+    pub fn into_files(self) -> BTreeMap<PathBuf, String> {
+        self.files
+            .into_iter()
+            .map(|(path, contents)| (path, String::from_utf8(contents).unwrap()))
+            .collect()
+    }
+}
+
+// Make a name safe to use as an identifier. The name should already be in
+// whatever case the target uses, which leaves only letters, digits and
+// underscores in it. Names which don't start with a letter or underscore get a
+// prefix, and reserved words get an underscore on the end.
+pub fn identifier(name: &str, prefix: &str, reserved: &[&str]) -> String {
+    let mut name = if name.starts_with(|c: char| c.is_alphabetic() || c == '_') {
+        name.to_owned()
+    } else {
+        format!("{}{}", prefix, name)
+    };
+
+    if reserved.contains(&name.as_str()) {
+        name.push('_');
+    }
+
+    name
+}
+
+// Get a setting for a target from a schema's metadata. Settings live in an
+// object under `metadata`, and may be given for every target, as `name`, or for
+// just one, as `goName`. The latter takes precedence.
+pub fn metadata<'a>(schema: &'a Schema, target: &str, key: &str) -> Option<&'a Value> {
+    let metadata = schema.extra().get("metadata")?;
+    let target_key = format!("{}{}", target, key.to_pascal_case());
+
+    metadata.get(&target_key).or_else(|| metadata.get(key))
+}
+
+// Get the human-readable description of a schema, if it has one.
+//
+// JSL doesn't reserve a keyword for descriptions, so these live among the
+// schema's extra, non-JSL properties.
+pub fn description(schema: &Schema) -> Option<String> {
+    schema
+        .extra()
+        .get("description")
+        .and_then(|description| description.as_str())
+        .map(|description| description.to_owned())
 }
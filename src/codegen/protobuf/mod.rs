@@ -0,0 +1,638 @@
+use crate::codegen;
+use crate::codegen::ir::{DeclKind, EnumMember, Field, Ir, Type, Variant};
+use crate::config::{Collisions, Config, TypeMapping};
+use crate::input::Input;
+use failure::{format_err, Error};
+use inflector::Inflector;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::io::Write;
+use std::path::PathBuf;
+
+#[derive(Debug)]
+pub struct Codegen {
+    names: HashMap<String, String>,
+    collisions: Collisions,
+    types: HashMap<String, TypeMapping>,
+    out_dir: PathBuf,
+    out_pkg: Option<String>,
+}
+
+// A schema ready to be written out as a .proto file, along with the numbers
+// its fields and enum values are to get.
+#[derive(Debug)]
+pub struct Proto {
+    ir: Ir,
+    lock: Lock,
+}
+
+// The numbers given out for each input, kept in a file alongside its .proto
+// file. Numbers are what identify fields on the wire, so once given out, they
+// have to stay the same for as long as anything might still be using them.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Lock {
+    // The numbers for the fields of each message, by message name. The fields
+    // of a message for a discriminator are its cases, by tag value.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    messages: BTreeMap<String, Numbers>,
+
+    // The numbers for the values of each enum, by enum name.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    enums: BTreeMap<String, Numbers>,
+}
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+struct Numbers {
+    // The numbers in use, by the name of what they number in JSON.
+    numbers: BTreeMap<String, u32>,
+
+    // The numbers of whatever has since been removed. These are never given to
+    // anything else, though something added back under the same name gets its
+    // old number back.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    reserved: BTreeMap<String, u32>,
+}
+
+impl Numbers {
+    // Number a set of names, keeping whatever numbers they were given before.
+    // New names are numbered from after the last number ever given out.
+    fn assign(old: Option<&Numbers>, names: &[&str]) -> Numbers {
+        let mut old = old.cloned().unwrap_or_default();
+        let mut next = old
+            .numbers
+            .values()
+            .chain(old.reserved.values())
+            .max()
+            .map_or(1, |number| number + 1);
+
+        let mut numbers = BTreeMap::new();
+        for &name in names {
+            let number = old
+                .numbers
+                .remove(name)
+                .or_else(|| old.reserved.remove(name))
+                .unwrap_or_else(|| {
+                    let number = next;
+                    next += 1;
+                    number
+                });
+
+            numbers.insert(name.to_owned(), number);
+        }
+
+        // Whatever wasn't given a number again has been removed.
+        let mut reserved = old.reserved;
+        reserved.extend(old.numbers);
+
+        Numbers { numbers, reserved }
+    }
+
+    // The reserved numbers, in order.
+    fn reserved(&self) -> Vec<String> {
+        let mut reserved: Vec<_> = self.reserved.values().collect();
+        reserved.sort();
+        reserved.iter().map(|number| number.to_string()).collect()
+    }
+}
+
+impl codegen::Codegen for Codegen {
+    type Ast = Proto;
+
+    fn args<'a, 'b>(app: clap::App<'a, 'b>) -> clap::App<'a, 'b> {
+        app.args(&[
+            clap::Arg::with_name("protobuf-out")
+                .help("Protocol Buffers output directory")
+                .takes_value(true)
+                .long("protobuf-out"),
+            clap::Arg::with_name("protobuf-pkg")
+                .help("Protocol Buffers output package")
+                .takes_value(true)
+                .long("protobuf-pkg"),
+        ])
+    }
+
+    fn from_args(matches: &clap::ArgMatches, config: &Config) -> Result<Option<Codegen>, Error> {
+        let mut config = config.clone();
+        if let Some(protobuf_out) = matches.value_of("protobuf-out") {
+            config.protobuf.out = Some(protobuf_out.to_owned());
+        }
+        if let Some(pkg) = matches.value_of("protobuf-pkg") {
+            config.protobuf.package = Some(pkg.to_owned());
+        }
+
+        Self::from_config(&config)
+    }
+
+    fn from_config(config: &Config) -> Result<Option<Codegen>, Error> {
+        if let Some(ref protobuf_out) = config.protobuf.out {
+            Ok(Some(Codegen {
+                names: config.names.clone(),
+                collisions: config.collisions,
+                types: config.protobuf.types.clone(),
+                out_dir: PathBuf::from(protobuf_out),
+                out_pkg: config.protobuf.package.clone(),
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    // Without a lock file to go on, everything is numbered afresh.
+    fn transform(&self, input: &Input) -> Result<Self::Ast, Error> {
+        self.transform_with_locks(input, &BTreeMap::new())
+    }
+
+    fn serialize(&self, proto: &Self::Ast, output: &mut codegen::Output) -> Result<(), Error> {
+        let ir = &proto.ir;
+        let lock = &proto.lock;
+
+        let out_file_name = format!("{}.proto", ir.module.to_snake_case());
+        let mut out = output.create(self.out_dir.join(out_file_name));
+
+        writeln!(out, "syntax = \"proto3\";")?;
+        if let Some(ref pkg) = self.out_pkg {
+            writeln!(out)?;
+            writeln!(out, "package {};", pkg)?;
+        }
+
+        // The output for each input is a file of its own, and they're expected
+        // to live in the same directory, and to share a package.
+        let imports = Self::imports(ir);
+        if !imports.is_empty() {
+            writeln!(out)?;
+        }
+        for import in imports {
+            writeln!(out, "import {:?};", import)?;
+        }
+
+        for decl in ir.local_decls() {
+            match decl.kind {
+                // Protocol Buffers has no type aliases. References to aliases
+                // are resolved to the aliased type instead.
+                DeclKind::Alias(_) => {}
+                DeclKind::Record(ref fields) => {
+                    writeln!(out)?;
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    self.serialize_message(
+                        &mut out,
+                        ir,
+                        &decl.name,
+                        fields,
+                        &lock.messages[&decl.name],
+                    )?;
+                }
+                DeclKind::Enum(ref vals) => {
+                    writeln!(out)?;
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    self.serialize_enum(&mut out, &decl.name, vals, &lock.enums[&decl.name])?;
+                }
+                DeclKind::Union(ref tag, ref variants) => {
+                    writeln!(out)?;
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    self.serialize_union(
+                        &mut out,
+                        &decl.name,
+                        tag,
+                        variants,
+                        &lock.messages[&decl.name],
+                    )?;
+
+                    for variant in variants {
+                        writeln!(out)?;
+                        self.serialize_description(&mut out, "", &variant.description)?;
+                        self.serialize_message(
+                            &mut out,
+                            ir,
+                            &variant.name,
+                            &variant.fields,
+                            &lock.messages[&variant.name],
+                        )?;
+                    }
+                }
+            };
+        }
+
+        // The numbers are written out alongside the .proto file, so that they
+        // can be checked in with it, and picked up again the next time code
+        // is generated.
+        let out = output.create(self.out_dir.join(Self::lock_file_name(&ir.module)));
+        serde_json::to_writer_pretty(&mut *out, lock)?;
+        writeln!(out)?;
+
+        Ok(())
+    }
+}
+
+impl Codegen {
+    // Transform an input, keeping the numbers in the lock files given, which
+    // are keyed by the paths they'd be written to. Reading those files is up
+    // to the caller, so that code can be generated without touching disk.
+    pub fn transform_with_locks(
+        &self,
+        input: &Input,
+        locks: &BTreeMap<PathBuf, String>,
+    ) -> Result<Proto, Error> {
+        // Unknown properties are a matter for whatever converts JSON to and
+        // from messages, so strict mode doesn't apply to Protocol Buffers.
+        // Well-known types are always referred to by their full names, so
+        // there are no names for messages to steer clear of either.
+        let mut ir = Ir::from_input(
+            &input.name.to_pascal_case(),
+            input,
+            false,
+            &self.names,
+            self.collisions,
+            &[],
+            "protobuf",
+            &self.types,
+        )?;
+
+        ir.name_members(
+            self.collisions,
+            &|_, field| Self::field_name(field),
+            &Self::constant_name,
+        )?;
+        ir.name_variants(self.collisions, &Self::variant_field_name)?;
+
+        let path = self.lock_file(input);
+        let old = match locks.get(&path) {
+            Some(contents) => serde_json::from_str(contents)
+                .map_err(|err| format_err!("{}: {}", path.display(), err))?,
+            None => Lock::default(),
+        };
+
+        let lock = Self::lock(&ir, &old);
+        Ok(Proto { ir, lock })
+    }
+
+    // The path of the lock file for an input.
+    pub fn lock_file(&self, input: &Input) -> PathBuf {
+        self.out_dir.join(Self::lock_file_name(&input.name))
+    }
+
+    // The name of the file numbers are kept in. It's deliberately not a .json
+    // file, so that it isn't taken for a schema if it's kept among them.
+    fn lock_file_name(module: &str) -> String {
+        format!("{}.proto.lock", module.to_snake_case())
+    }
+
+    // Number the fields and enum values of every local declaration, starting
+    // from the numbers given out before.
+    fn lock(ir: &Ir, old: &Lock) -> Lock {
+        let mut lock = Lock::default();
+        for decl in ir.local_decls() {
+            match decl.kind {
+                DeclKind::Alias(_) => {}
+                DeclKind::Record(ref fields) => {
+                    let names: Vec<_> = fields.iter().map(|field| field.name.as_str()).collect();
+                    lock.messages.insert(
+                        decl.name.clone(),
+                        Numbers::assign(old.messages.get(&decl.name), &names),
+                    );
+                }
+                DeclKind::Enum(ref vals) => {
+                    let names: Vec<_> = vals.iter().map(|val| val.value.as_str()).collect();
+                    lock.enums.insert(
+                        decl.name.clone(),
+                        Numbers::assign(old.enums.get(&decl.name), &names),
+                    );
+                }
+                DeclKind::Union(_, ref variants) => {
+                    let names: Vec<_> = variants
+                        .iter()
+                        .map(|variant| variant.tag_value.as_str())
+                        .collect();
+                    lock.messages.insert(
+                        decl.name.clone(),
+                        Numbers::assign(old.messages.get(&decl.name), &names),
+                    );
+
+                    for variant in variants {
+                        let names: Vec<_> = variant
+                            .fields
+                            .iter()
+                            .map(|field| field.name.as_str())
+                            .collect();
+                        lock.messages.insert(
+                            variant.name.clone(),
+                            Numbers::assign(old.messages.get(&variant.name), &names),
+                        );
+                    }
+                }
+            }
+        }
+
+        lock
+    }
+
+    // The files messages need imported. Aliases aren't written out, so this
+    // looks through them to whatever they refer to.
+    fn imports(ir: &Ir) -> Vec<String> {
+        fn walk<'a>(ir: &'a Ir, typ: &'a Type, types: &mut Vec<&'a Type>) {
+            match ir.resolve(typ) {
+                Type::Elements(ref typ) | Type::Values(ref typ) => walk(ir, typ, types),
+                typ => types.push(typ),
+            }
+        }
+
+        let mut types = Vec::new();
+        for decl in ir.local_decls() {
+            if let DeclKind::Alias(_) = decl.kind {
+                continue;
+            }
+
+            for typ in decl.types() {
+                walk(ir, typ, &mut types);
+            }
+        }
+
+        let mut imports = Vec::new();
+        for typ in types {
+            match typ {
+                Type::Any => imports.push("google/protobuf/struct.proto".to_owned()),
+                Type::Timestamp => imports.push("google/protobuf/timestamp.proto".to_owned()),
+                Type::Mapped(_, ref mapping) => imports.extend(mapping.import.clone()),
+                Type::Ref(ref name) => {
+                    if let Some(module) = ir.decl(name).and_then(|decl| decl.module.as_ref()) {
+                        imports.push(format!("{}.proto", module.to_snake_case()));
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        imports.sort();
+        imports.dedup();
+        imports
+    }
+
+    fn serialize_message(
+        &self,
+        out: &mut dyn Write,
+        ir: &Ir,
+        name: &str,
+        fields: &[Field],
+        numbers: &Numbers,
+    ) -> Result<(), Error> {
+        let mut wrappers = Vec::new();
+        let mut lines = Vec::new();
+        for field in fields {
+            let field_name = Self::field_name(field);
+            let typ = Self::field_type(
+                ir,
+                &field.value,
+                &field_name.to_pascal_case(),
+                &mut wrappers,
+            );
+
+            // Repeated fields and maps can't be marked optional. Missing ones
+            // are just empty.
+            let label = if field.required || typ.starts_with("repeated ") || typ.starts_with("map<")
+            {
+                ""
+            } else {
+                "optional "
+            };
+
+            let mut line = String::new();
+            if let Some(ref description) = field.description {
+                for description_line in description.lines() {
+                    line.push_str(format!("  // {}", description_line).trim_end());
+                    line.push('\n');
+                }
+            }
+
+            line.push_str(&format!(
+                "  {}{} {} = {}{};",
+                label,
+                typ,
+                field_name,
+                numbers.numbers[&field.name],
+                Self::json_name(&field_name, &field.name)
+            ));
+            lines.push(line);
+        }
+
+        let reserved = numbers.reserved();
+        if wrappers.is_empty() && lines.is_empty() && reserved.is_empty() {
+            writeln!(out, "message {} {{}}", name)?;
+            return Ok(());
+        }
+
+        writeln!(out, "message {} {{", name)?;
+
+        // Collections can't hold other collections directly, so these are
+        // wrapped in messages of their own, declared within the message that
+        // uses them.
+        for (wrapper, typ) in &wrappers {
+            writeln!(out, "  message {} {{", wrapper)?;
+            writeln!(out, "    {} values = 1;", typ)?;
+            writeln!(out, "  }}")?;
+            writeln!(out)?;
+        }
+
+        for line in lines {
+            writeln!(out, "{}", line)?;
+        }
+
+        if !reserved.is_empty() {
+            if !fields.is_empty() {
+                writeln!(out)?;
+            }
+            writeln!(out, "  reserved {};", reserved.join(", "))?;
+        }
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
+
+    // Write out the message for a discriminator, which holds whichever of its
+    // cases it is in a oneof named after the tag.
+    fn serialize_union(
+        &self,
+        out: &mut dyn Write,
+        name: &str,
+        tag: &str,
+        variants: &[Variant],
+        numbers: &Numbers,
+    ) -> Result<(), Error> {
+        writeln!(out, "message {} {{", name)?;
+        writeln!(out, "  oneof {} {{", Self::identifier(&tag.to_snake_case()))?;
+        for variant in variants {
+            let field_name = Self::variant_field_name(variant);
+            writeln!(
+                out,
+                "    {} {} = {}{};",
+                variant.name,
+                field_name,
+                numbers.numbers[&variant.tag_value],
+                Self::json_name(&field_name, &variant.tag_value)
+            )?;
+        }
+        writeln!(out, "  }}")?;
+
+        let reserved = numbers.reserved();
+        if !reserved.is_empty() {
+            writeln!(out)?;
+            writeln!(out, "  reserved {};", reserved.join(", "))?;
+        }
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
+
+    // Write out an enum. Enum values are scoped like their enum's siblings
+    // are, so they're prefixed with the enum's name to keep them apart from
+    // the values of other enums. The first value has to be zero, and is taken
+    // to mean the value is missing.
+    fn serialize_enum(
+        &self,
+        out: &mut dyn Write,
+        name: &str,
+        vals: &[EnumMember],
+        numbers: &Numbers,
+    ) -> Result<(), Error> {
+        let prefix = name.to_screaming_snake_case();
+        let names: Vec<_> = vals
+            .iter()
+            .map(|val| Self::constant_name(name, val))
+            .collect();
+
+        let unspecified = format!("{}_UNSPECIFIED", prefix);
+        let reserved_names: Vec<_> = names.iter().map(String::as_str).collect();
+
+        writeln!(out, "enum {} {{", name)?;
+        writeln!(
+            out,
+            "  {} = 0;",
+            codegen::identifier(&unspecified, "", &reserved_names)
+        )?;
+        for (val, name) in vals.iter().zip(&names) {
+            writeln!(out, "  {} = {};", name, numbers.numbers[&val.value])?;
+        }
+
+        let reserved = numbers.reserved();
+        if !reserved.is_empty() {
+            writeln!(out)?;
+            writeln!(out, "  reserved {};", reserved.join(", "))?;
+        }
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
+
+    fn constant_name(enum_name: &str, val: &EnumMember) -> String {
+        match val.identifier {
+            Some(ref identifier) => identifier.clone(),
+            None => Self::identifier(&format!(
+                "{}_{}",
+                enum_name.to_screaming_snake_case(),
+                val.value.to_screaming_snake_case()
+            )),
+        }
+    }
+
+    // The name of the field in a oneof holding a case of a discriminator.
+    fn variant_field_name(variant: &Variant) -> String {
+        match variant.identifier {
+            Some(ref identifier) => identifier.clone(),
+            None => Self::identifier(&variant.tag_value.to_snake_case()),
+        }
+    }
+
+    fn field_name(field: &Field) -> String {
+        match field.identifier {
+            Some(ref identifier) => identifier.clone(),
+            None => Self::identifier(&field.name.to_snake_case()),
+        }
+    }
+
+    // Make a name safe to use as an identifier. Identifiers in .proto files
+    // are ASCII only, so anything else is left out of them.
+    fn identifier(name: &str) -> String {
+        let name: String = name.chars().filter(char::is_ascii).collect();
+        codegen::identifier(&name, "_", &[])
+    }
+
+    // The option keeping the name a field has in JSON, if the one protoc would
+    // derive from the field's name isn't it. protoc derives names by dropping
+    // underscores, and capitalizing whatever follows them.
+    fn json_name(field_name: &str, json_name: &str) -> String {
+        let mut derived = String::new();
+        let mut upper = false;
+        for c in field_name.chars() {
+            if c == '_' {
+                upper = true;
+            } else if upper {
+                derived.extend(c.to_uppercase());
+                upper = false;
+            } else {
+                derived.push(c);
+            }
+        }
+
+        if derived == json_name {
+            "".to_owned()
+        } else {
+            format!(" [json_name = {:?}]", json_name)
+        }
+    }
+
+    // The type of a field, including `repeated` for arrays. Any collections
+    // held by arrays or maps get a message to wrap them, which is added to
+    // `wrappers` along with the type it wraps. These are named after `name`.
+    fn field_type(ir: &Ir, typ: &Type, name: &str, wrappers: &mut Vec<(String, String)>) -> String {
+        match ir.resolve(typ) {
+            Type::Elements(ref typ) => format!(
+                "repeated {}",
+                Self::element_type(ir, typ, &format!("{}Item", name), wrappers)
+            ),
+            Type::Values(ref typ) => format!(
+                "map<string, {}>",
+                Self::element_type(ir, typ, &format!("{}Value", name), wrappers)
+            ),
+            typ => Self::element_type(ir, typ, name, wrappers),
+        }
+    }
+
+    // The type of a value held by a field, or by an array or map.
+    fn element_type(
+        ir: &Ir,
+        typ: &Type,
+        name: &str,
+        wrappers: &mut Vec<(String, String)>,
+    ) -> String {
+        match ir.resolve(typ) {
+            Type::Any => "google.protobuf.Value".to_owned(),
+            Type::Boolean => "bool".to_owned(),
+            Type::Number => "double".to_owned(),
+            Type::String => "string".to_owned(),
+            Type::Timestamp => "google.protobuf.Timestamp".to_owned(),
+            Type::Mapped(_, ref mapping) => mapping.name.clone(),
+            Type::Ref(ref id) => id.clone(),
+            Type::Elements(_) | Type::Values(_) => {
+                let wrapped = Self::field_type(ir, typ, name, wrappers);
+                wrappers.push((name.to_owned(), wrapped));
+                name.to_owned()
+            }
+        }
+    }
+
+    fn serialize_description(
+        &self,
+        out: &mut dyn Write,
+        indent: &str,
+        description: &Option<String>,
+    ) -> Result<(), Error> {
+        if let Some(ref description) = description {
+            for line in description.lines() {
+                let line = format!("{}// {}", indent, line);
+                writeln!(out, "{}", line.trim_end())?;
+            }
+        }
+
+        Ok(())
+    }
+}
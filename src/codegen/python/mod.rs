@@ -0,0 +1,525 @@
+use crate::codegen;
+use crate::codegen::ir::{DeclKind, EnumMember, Field, Ir, Type};
+use crate::config::{Collisions, Config, TypeMapping};
+use crate::input::Input;
+use failure::Error;
+use inflector::Inflector;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+
+// Words which can't be used as identifiers. A field named `self` would clash
+// with the `self` parameter of the constructor dataclasses generate.
+const KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "self", "try",
+    "while", "with", "yield",
+];
+
+// Names generated classes can't have, since generated code uses them itself.
+const RESERVED_TYPE_NAMES: &[&str] = &[
+    "Any", "Dict", "Enum", "False", "List", "None", "Optional", "True",
+];
+
+#[derive(Debug)]
+pub struct Codegen {
+    strict: bool,
+    names: HashMap<String, String>,
+    collisions: Collisions,
+    types: HashMap<String, TypeMapping>,
+    out_dir: PathBuf,
+}
+
+impl codegen::Codegen for Codegen {
+    type Ast = Ir;
+
+    fn args<'a, 'b>(app: clap::App<'a, 'b>) -> clap::App<'a, 'b> {
+        app.arg(
+            clap::Arg::with_name("python-out")
+                .help("Python output directory")
+                .takes_value(true)
+                .long("python-out"),
+        )
+    }
+
+    fn from_args(matches: &clap::ArgMatches, config: &Config) -> Result<Option<Codegen>, Error> {
+        let mut config = config.clone();
+        if let Some(python_out) = matches.value_of("python-out") {
+            config.python.out = Some(python_out.to_owned());
+        }
+
+        Self::from_config(&config)
+    }
+
+    fn from_config(config: &Config) -> Result<Option<Codegen>, Error> {
+        if let Some(ref python_out) = config.python.out {
+            Ok(Some(Codegen {
+                strict: config.strict,
+                names: config.names.clone(),
+                collisions: config.collisions,
+                types: config.python.types.clone(),
+                out_dir: PathBuf::from(python_out),
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn transform(&self, input: &Input) -> Result<Self::Ast, Error> {
+        let mut ir = Ir::from_input(
+            &input.name.to_pascal_case(),
+            input,
+            self.strict,
+            &self.names,
+            self.collisions,
+            RESERVED_TYPE_NAMES,
+            "python",
+            &self.types,
+        )?;
+
+        ir.name_members(
+            self.collisions,
+            &|_, field| Self::field_name(field),
+            &|_, val| Self::constant_name(val),
+        )?;
+        Ok(ir)
+    }
+
+    fn serialize(&self, ir: &Self::Ast, output: &mut codegen::Output) -> Result<(), Error> {
+        let out_file_name = format!("{}.py", ir.module.to_snake_case());
+        let mut out = output.create(self.out_dir.join(out_file_name));
+
+        // Only what's used is imported, so that the output lints cleanly.
+        // Records and unions are dataclasses, converted to and from dicts.
+        let has_classes = ir.local_decls().any(|decl| match decl.kind {
+            DeclKind::Record(_) | DeclKind::Union(..) => true,
+            DeclKind::Alias(_) | DeclKind::Enum(_) => false,
+        });
+        let has_enums = ir
+            .local_decls()
+            .any(|decl| matches!(decl.kind, DeclKind::Enum(_)));
+        let has_optional = ir.local_decls().any(|decl| match decl.kind {
+            DeclKind::Record(ref fields) => fields.iter().any(|field| !field.required),
+            DeclKind::Union(_, ref variants) => variants
+                .iter()
+                .any(|variant| variant.fields.iter().any(|field| !field.required)),
+            DeclKind::Alias(_) | DeclKind::Enum(_) => false,
+        });
+
+        // Conversions look through aliases, so timestamps behind an alias
+        // from another module still need parsing here.
+        let has_timestamps = ir.uses_resolved(&|typ| matches!(typ, Type::Timestamp));
+
+        let mut typing = Vec::new();
+        if has_classes || ir.uses(&|typ| matches!(typ, Type::Any)) {
+            typing.push("Any");
+        }
+        if has_classes || ir.uses(&|typ| matches!(typ, Type::Values(_))) {
+            typing.push("Dict");
+        }
+        if ir.uses(&|typ| matches!(typ, Type::Elements(_))) {
+            typing.push("List");
+        }
+        if has_optional {
+            typing.push("Optional");
+        }
+
+        writeln!(out, "from __future__ import annotations")?;
+        writeln!(out)?;
+        if has_classes {
+            writeln!(out, "from dataclasses import dataclass")?;
+        }
+        if has_timestamps {
+            writeln!(out, "from datetime import datetime")?;
+        }
+        if has_enums {
+            writeln!(out, "from enum import Enum")?;
+        }
+        if !typing.is_empty() {
+            writeln!(out, "from typing import {}", typing.join(", "))?;
+        }
+
+        for (module, names) in ir.mapped_imports() {
+            let names: Vec<_> = names.into_iter().collect();
+            writeln!(out, "from {} import {}", module, names.join(", "))?;
+        }
+
+        // The output for each input is a module of its own, and they're
+        // expected to live in the same package.
+        let imports = ir.imports();
+        if !imports.is_empty() {
+            writeln!(out)?;
+        }
+
+        for (module, names) in imports {
+            let names: Vec<_> = names.into_iter().collect();
+            writeln!(
+                out,
+                "from .{} import {}",
+                module.to_snake_case(),
+                names.join(", ")
+            )?;
+        }
+        if has_timestamps {
+            writeln!(out)?;
+            writeln!(out)?;
+            writeln!(out, "def _parse_timestamp(value: str) -> datetime:")?;
+            writeln!(
+                out,
+                "    return datetime.fromisoformat(value.replace(\"Z\", \"+00:00\"))"
+            )?;
+        }
+
+        for decl in ir.local_decls() {
+            writeln!(out)?;
+            writeln!(out)?;
+
+            match decl.kind {
+                DeclKind::Alias(ref typ) => {
+                    self.serialize_comment(&mut out, "", &decl.description)?;
+
+                    // Aliases are evaluated eagerly, so references to classes
+                    // declared further down the module have to be quoted.
+                    writeln!(out, "{} = {}", decl.name, Self::type_hint(typ, true))?;
+                }
+                DeclKind::Record(ref fields) => {
+                    self.serialize_class(
+                        &mut out,
+                        ir,
+                        &decl.name,
+                        None,
+                        fields,
+                        &decl.description,
+                    )?;
+                }
+                DeclKind::Enum(ref vals) => {
+                    writeln!(out, "class {}(Enum):", decl.name)?;
+                    self.serialize_docstring(&mut out, &decl.description)?;
+                    for val in vals {
+                        writeln!(out, "    {} = {:?}", Self::constant_name(val), val.value)?;
+                    }
+                }
+                DeclKind::Union(ref tag, ref variants) => {
+                    // The base class has to be declared before any of the
+                    // subclasses for each case.
+                    writeln!(out, "@dataclass")?;
+                    writeln!(out, "class {}:", decl.name)?;
+                    if decl.description.is_some() {
+                        self.serialize_docstring(&mut out, &decl.description)?;
+                        writeln!(out)?;
+                    }
+
+                    writeln!(out, "    @staticmethod")?;
+                    writeln!(
+                        out,
+                        "    def from_json(data: Dict[str, Any]) -> {}:",
+                        decl.name
+                    )?;
+                    writeln!(out, "        variants = {{")?;
+                    for variant in variants {
+                        writeln!(
+                            out,
+                            "            {:?}: {},",
+                            variant.tag_value, variant.name
+                        )?;
+                    }
+                    writeln!(out, "        }}")?;
+                    writeln!(out)?;
+                    writeln!(
+                        out,
+                        "        return variants[data[{:?}]].from_json(data)",
+                        tag
+                    )?;
+                    writeln!(out)?;
+                    writeln!(out, "    def to_json(self) -> Dict[str, Any]:")?;
+                    writeln!(out, "        raise NotImplementedError")?;
+
+                    for variant in variants {
+                        writeln!(out)?;
+                        writeln!(out)?;
+                        self.serialize_class(
+                            &mut out,
+                            ir,
+                            &variant.name,
+                            Some((&decl.name, tag, &variant.tag_value)),
+                            &variant.fields,
+                            &variant.description,
+                        )?;
+                    }
+                }
+            };
+        }
+
+        Ok(())
+    }
+}
+
+impl Codegen {
+    // The name of the dataclass field for a property.
+    fn field_name(field: &Field) -> String {
+        match field.identifier {
+            Some(ref identifier) => identifier.clone(),
+            None => codegen::identifier(&field.name.to_snake_case(), "_", KEYWORDS),
+        }
+    }
+
+    fn constant_name(val: &EnumMember) -> String {
+        match val.identifier {
+            Some(ref identifier) => identifier.clone(),
+            None => codegen::identifier(&val.value.to_screaming_snake_case(), "_", &[]),
+        }
+    }
+
+    // Write out a dataclass. If a parent is provided, the class is one case of
+    // a discriminated union. The parent is given as the name of the union's
+    // base class, the name of the tag, and the tag value for this case.
+    fn serialize_class(
+        &self,
+        out: &mut dyn Write,
+        ir: &Ir,
+        name: &str,
+        parent: Option<(&str, &str, &str)>,
+        fields: &[Field],
+        description: &Option<String>,
+    ) -> Result<(), Error> {
+        writeln!(out, "@dataclass")?;
+        match parent {
+            Some((parent, _, _)) => writeln!(out, "class {}({}):", name, parent)?,
+            None => writeln!(out, "class {}:", name)?,
+        };
+
+        self.serialize_docstring(out, description)?;
+        for field in fields {
+            self.serialize_comment(out, "    ", &field.description)?;
+            if field.required {
+                writeln!(
+                    out,
+                    "    {}: {}",
+                    Self::field_name(field),
+                    Self::type_hint(&field.value, false)
+                )?;
+            } else {
+                writeln!(
+                    out,
+                    "    {}: Optional[{}] = None",
+                    Self::field_name(field),
+                    Self::type_hint(&field.value, false)
+                )?;
+            }
+        }
+
+        if !fields.is_empty() || description.is_some() {
+            writeln!(out)?;
+        }
+
+        writeln!(out, "    @classmethod")?;
+        writeln!(
+            out,
+            "    def from_json(cls, data: Dict[str, Any]) -> {}:",
+            name
+        )?;
+        if ir.strict {
+            let mut known: Vec<_> = fields
+                .iter()
+                .map(|field| format!("{:?}", field.name))
+                .collect();
+            if let Some((_, tag, _)) = parent {
+                known.push(format!("{:?}", tag));
+            }
+
+            writeln!(
+                out,
+                "        unknown = set(data).difference([{}])",
+                known.join(", ")
+            )?;
+            writeln!(out, "        if unknown:")?;
+            writeln!(
+                out,
+                "            raise ValueError(\"unknown properties: \" + \", \".join(sorted(unknown)))"
+            )?;
+        }
+
+        writeln!(out, "        return cls(")?;
+        for field in fields {
+            let field_name = Self::field_name(field);
+            let access = format!("data[{:?}]", field.name);
+            let value = Self::from_json_expr(ir, &field.value, &access, 0);
+
+            if field.required {
+                writeln!(out, "            {}={},", field_name, value)?;
+            } else if value == access {
+                writeln!(
+                    out,
+                    "            {}=data.get({:?}),",
+                    field_name, field.name
+                )?;
+            } else {
+                writeln!(
+                    out,
+                    "            {}={} if {:?} in data else None,",
+                    field_name, value, field.name
+                )?;
+            }
+        }
+        writeln!(out, "        )")?;
+        writeln!(out)?;
+        writeln!(out, "    def to_json(self) -> Dict[str, Any]:")?;
+        match parent {
+            Some((_, tag, tag_value)) => writeln!(
+                out,
+                "        data: Dict[str, Any] = {{{:?}: {:?}}}",
+                tag, tag_value
+            )?,
+            None => writeln!(out, "        data: Dict[str, Any] = {{}}")?,
+        };
+
+        for field in fields {
+            let access = format!("self.{}", Self::field_name(field));
+            let value = Self::to_json_expr(ir, &field.value, &access, 0);
+
+            if field.required {
+                writeln!(out, "        data[{:?}] = {}", field.name, value)?;
+            } else {
+                writeln!(out, "        if {} is not None:", access)?;
+                writeln!(out, "            data[{:?}] = {}", field.name, value)?;
+            }
+        }
+        writeln!(out, "        return data")?;
+
+        Ok(())
+    }
+
+    fn serialize_comment(
+        &self,
+        out: &mut dyn Write,
+        indent: &str,
+        description: &Option<String>,
+    ) -> Result<(), Error> {
+        if let Some(ref description) = description {
+            for line in description.lines() {
+                let line = format!("{}# {}", indent, line);
+                writeln!(out, "{}", line.trim_end())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Docstrings are always emitted as the first statement of a class body,
+    // so they are always indented one level.
+    fn serialize_docstring(
+        &self,
+        out: &mut dyn Write,
+        description: &Option<String>,
+    ) -> Result<(), Error> {
+        if let Some(ref description) = description {
+            let description = description
+                .replace('\\', "\\\\")
+                .replace("\"\"\"", "\\\"\"\"");
+            let lines: Vec<_> = description.lines().collect();
+
+            if lines.len() == 1 {
+                writeln!(out, "    \"\"\"{}\"\"\"", lines[0])?;
+            } else {
+                writeln!(out, "    \"\"\"")?;
+                for line in lines {
+                    writeln!(out, "{}", format!("    {}", line).trim_end())?;
+                }
+                writeln!(out, "    \"\"\"")?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn type_hint(typ: &Type, quote_ids: bool) -> String {
+        match typ {
+            Type::Any => "Any".to_owned(),
+            Type::Boolean => "bool".to_owned(),
+            Type::Number => "float".to_owned(),
+            Type::String => "str".to_owned(),
+            Type::Timestamp => "datetime".to_owned(),
+            Type::Mapped(_, ref mapping) => mapping.name.clone(),
+            Type::Ref(ref id) if quote_ids => format!("{:?}", id),
+            Type::Ref(ref id) => id.clone(),
+            Type::Elements(ref typ) => format!("List[{}]", Self::type_hint(typ, quote_ids)),
+            Type::Values(ref typ) => format!("Dict[str, {}]", Self::type_hint(typ, quote_ids)),
+        }
+    }
+
+    // Produce a Python expression converting `expr`, the parsed JSON for an
+    // instance of `typ`, into its typed representation. Depth is used to keep
+    // comprehension variables from shadowing one another.
+    fn from_json_expr(ir: &Ir, typ: &Type, expr: &str, depth: usize) -> String {
+        match ir.resolve(typ) {
+            Type::Any | Type::Boolean | Type::Number | Type::String => expr.to_owned(),
+            Type::Timestamp => format!("_parse_timestamp({})", expr),
+
+            // Values of types mapped by the user are passed along as they are
+            // in JSON.
+            Type::Mapped(..) => expr.to_owned(),
+            Type::Ref(ref id) => match ir.decl(id).map(|decl| &decl.kind) {
+                Some(DeclKind::Enum(_)) => format!("{}({})", id, expr),
+                _ => format!("{}.from_json({})", id, expr),
+            },
+            Type::Elements(ref typ) => {
+                let var = format!("v{}", depth);
+                let value = Self::from_json_expr(ir, typ, &var, depth + 1);
+                if value == var {
+                    expr.to_owned()
+                } else {
+                    format!("[{} for {} in {}]", value, var, expr)
+                }
+            }
+            Type::Values(ref typ) => {
+                let key = format!("k{}", depth);
+                let var = format!("v{}", depth);
+                let value = Self::from_json_expr(ir, typ, &var, depth + 1);
+                if value == var {
+                    expr.to_owned()
+                } else {
+                    format!(
+                        "{{{}: {} for {}, {} in {}.items()}}",
+                        key, value, key, var, expr
+                    )
+                }
+            }
+        }
+    }
+
+    // The inverse of `from_json_expr`.
+    fn to_json_expr(ir: &Ir, typ: &Type, expr: &str, depth: usize) -> String {
+        match ir.resolve(typ) {
+            Type::Any | Type::Boolean | Type::Number | Type::String => expr.to_owned(),
+            Type::Timestamp => format!("{}.isoformat()", expr),
+            Type::Mapped(..) => expr.to_owned(),
+            Type::Ref(ref id) => match ir.decl(id).map(|decl| &decl.kind) {
+                Some(DeclKind::Enum(_)) => format!("{}.value", expr),
+                _ => format!("{}.to_json()", expr),
+            },
+            Type::Elements(ref typ) => {
+                let var = format!("v{}", depth);
+                let value = Self::to_json_expr(ir, typ, &var, depth + 1);
+                if value == var {
+                    expr.to_owned()
+                } else {
+                    format!("[{} for {} in {}]", value, var, expr)
+                }
+            }
+            Type::Values(ref typ) => {
+                let key = format!("k{}", depth);
+                let var = format!("v{}", depth);
+                let value = Self::to_json_expr(ir, typ, &var, depth + 1);
+                if value == var {
+                    expr.to_owned()
+                } else {
+                    format!(
+                        "{{{}: {} for {}, {} in {}.items()}}",
+                        key, value, key, var, expr
+                    )
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,296 @@
+use crate::codegen;
+use crate::codegen::ir::{DeclKind, Field, Ir, Type};
+use crate::config::{Collisions, Config, TypeMapping};
+use crate::input::Input;
+use failure::Error;
+use inflector::Inflector;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+
+// Words which can't be used as identifiers. Some of these could be used as raw
+// identifiers, but not all of them, so they are all escaped the same way.
+const KEYWORDS: &[&str] = &[
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if", "impl",
+    "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+// Names generated types can't have, since generated code uses them itself.
+const RESERVED_TYPE_NAMES: &[&str] = &[
+    "Deserialize",
+    "Option",
+    "Self",
+    "Serialize",
+    "String",
+    "Vec",
+];
+
+#[derive(Debug)]
+pub struct Codegen {
+    strict: bool,
+    names: HashMap<String, String>,
+    collisions: Collisions,
+    types: HashMap<String, TypeMapping>,
+    out_dir: PathBuf,
+}
+
+impl codegen::Codegen for Codegen {
+    type Ast = Ir;
+
+    fn args<'a, 'b>(app: clap::App<'a, 'b>) -> clap::App<'a, 'b> {
+        app.arg(
+            clap::Arg::with_name("rust-out")
+                .help("Rust output directory")
+                .takes_value(true)
+                .long("rust-out"),
+        )
+    }
+
+    fn from_args(matches: &clap::ArgMatches, config: &Config) -> Result<Option<Codegen>, Error> {
+        let mut config = config.clone();
+        if let Some(rust_out) = matches.value_of("rust-out") {
+            config.rust.out = Some(rust_out.to_owned());
+        }
+
+        Self::from_config(&config)
+    }
+
+    fn from_config(config: &Config) -> Result<Option<Codegen>, Error> {
+        if let Some(ref rust_out) = config.rust.out {
+            Ok(Some(Codegen {
+                strict: config.strict,
+                names: config.names.clone(),
+                collisions: config.collisions,
+                types: config.rust.types.clone(),
+                out_dir: PathBuf::from(rust_out),
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn transform(&self, input: &Input) -> Result<Self::Ast, Error> {
+        let mut ir = Ir::from_input(
+            &input.name.to_pascal_case(),
+            input,
+            self.strict,
+            &self.names,
+            self.collisions,
+            RESERVED_TYPE_NAMES,
+            "rust",
+            &self.types,
+        )?;
+
+        ir.name_members(
+            self.collisions,
+            &|_, field| Self::field_name(field),
+            &|_, val| Self::variant_name(&val.value, &val.identifier),
+        )?;
+        ir.name_variants(self.collisions, &|variant| {
+            Self::variant_name(&variant.tag_value, &variant.identifier)
+        })?;
+        Ok(ir)
+    }
+
+    fn serialize(&self, ir: &Self::Ast, output: &mut codegen::Output) -> Result<(), Error> {
+        let out_file_name = format!("{}.rs", ir.module.to_snake_case());
+        let mut out = output.create(self.out_dir.join(out_file_name));
+
+        // Only bring in the serde derives if something is going to use them,
+        // so that alias-only output doesn't trip the unused_imports lint.
+        let needs_serde = ir
+            .local_decls()
+            .any(|decl| !matches!(decl.kind, DeclKind::Alias(_)));
+
+        if needs_serde {
+            writeln!(out, "use serde::{{Deserialize, Serialize}};")?;
+        }
+
+        for import in ir.mapped_imports().keys() {
+            writeln!(out, "use {};", import)?;
+        }
+
+        // The output for each input is a module of its own. They're expected
+        // to be siblings of one another.
+        for (module, names) in ir.imports() {
+            let names: Vec<_> = names.into_iter().collect();
+            writeln!(
+                out,
+                "use super::{}::{{{}}};",
+                module.to_snake_case(),
+                names.join(", ")
+            )?;
+        }
+
+        for decl in ir.local_decls() {
+            match decl.kind {
+                DeclKind::Alias(ref typ) => {
+                    writeln!(out)?;
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    writeln!(out, "pub type {} = {};", decl.name, self.type_expr(typ))?;
+                }
+                DeclKind::Record(ref fields) => {
+                    writeln!(out)?;
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    self.serialize_struct(&mut out, ir, &decl.name, fields)?;
+                }
+                DeclKind::Enum(ref vals) => {
+                    writeln!(out)?;
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    writeln!(
+                        out,
+                        "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]"
+                    )?;
+                    writeln!(out, "pub enum {} {{", decl.name)?;
+                    for val in vals {
+                        self.serialize_variant_name(&mut out, &val.value, &val.identifier)?;
+                        writeln!(out, ",")?;
+                    }
+                    writeln!(out, "}}")?;
+                }
+                DeclKind::Union(ref tag, ref variants) => {
+                    for variant in variants {
+                        writeln!(out)?;
+                        self.serialize_description(&mut out, "", &variant.description)?;
+                        self.serialize_struct(&mut out, ir, &variant.name, &variant.fields)?;
+                    }
+
+                    writeln!(out)?;
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    writeln!(
+                        out,
+                        "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]"
+                    )?;
+                    writeln!(out, "#[serde(tag = {:?})]", tag)?;
+                    writeln!(out, "pub enum {} {{", decl.name)?;
+                    for variant in variants {
+                        self.serialize_variant_name(
+                            &mut out,
+                            &variant.tag_value,
+                            &variant.identifier,
+                        )?;
+                        writeln!(out, "({}),", variant.name)?;
+                    }
+                    writeln!(out, "}}")?;
+                }
+            };
+        }
+
+        Ok(())
+    }
+}
+
+impl Codegen {
+    fn serialize_struct(
+        &self,
+        out: &mut dyn Write,
+        ir: &Ir,
+        name: &str,
+        fields: &[Field],
+    ) -> Result<(), Error> {
+        writeln!(
+            out,
+            "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]"
+        )?;
+        if ir.strict {
+            writeln!(out, "#[serde(deny_unknown_fields)]")?;
+        }
+        writeln!(out, "pub struct {} {{", name)?;
+        for field in fields {
+            let field_name = Self::field_name(field);
+
+            self.serialize_description(out, "    ", &field.description)?;
+            if field_name != field.name {
+                writeln!(out, "    #[serde(rename = {:?})]", field.name)?;
+            }
+
+            // Fields leading back to the struct they're in have to be boxed,
+            // or the struct would have to contain itself.
+            let typ = if ir.is_recursive(name, field) {
+                format!("Box<{}>", self.type_expr(&field.value))
+            } else {
+                self.type_expr(&field.value)
+            };
+
+            if field.required {
+                writeln!(out, "    pub {}: {},", field_name, typ)?;
+            } else {
+                writeln!(
+                    out,
+                    "    #[serde(skip_serializing_if = \"Option::is_none\")]"
+                )?;
+                writeln!(out, "    pub {}: Option<{}>,", field_name, typ)?;
+            }
+        }
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
+
+    fn field_name(field: &Field) -> String {
+        match field.identifier {
+            Some(ref identifier) => identifier.clone(),
+            None => codegen::identifier(&field.name.to_snake_case(), "_", KEYWORDS),
+        }
+    }
+
+    // The name of the enum variant for a JSON value.
+    fn variant_name(json_name: &str, identifier: &Option<String>) -> String {
+        match identifier {
+            Some(ref identifier) => identifier.clone(),
+            None => codegen::identifier(&json_name.to_pascal_case(), "X", &["Self"]),
+        }
+    }
+
+    // Write out the name of an enum variant for a JSON value, renaming it if
+    // the two differ. The caller is responsible for finishing the line.
+    fn serialize_variant_name(
+        &self,
+        out: &mut dyn Write,
+        json_name: &str,
+        identifier: &Option<String>,
+    ) -> Result<(), Error> {
+        let name = Self::variant_name(json_name, identifier);
+        if name != json_name {
+            writeln!(out, "    #[serde(rename = {:?})]", json_name)?;
+        }
+
+        write!(out, "    {}", name)?;
+        Ok(())
+    }
+
+    fn type_expr(&self, typ: &Type) -> String {
+        match typ {
+            Type::Any => "serde_json::Value".to_owned(),
+            Type::Boolean => "bool".to_owned(),
+            Type::Number => "f64".to_owned(),
+            Type::String => "String".to_owned(),
+            Type::Timestamp => "chrono::DateTime<chrono::Utc>".to_owned(),
+            Type::Mapped(_, ref mapping) => mapping.name.clone(),
+            Type::Ref(ref id) => id.clone(),
+            Type::Elements(ref typ) => format!("Vec<{}>", self.type_expr(typ)),
+            Type::Values(ref typ) => {
+                format!("std::collections::HashMap<String, {}>", self.type_expr(typ))
+            }
+        }
+    }
+
+    fn serialize_description(
+        &self,
+        out: &mut dyn Write,
+        indent: &str,
+        description: &Option<String>,
+    ) -> Result<(), Error> {
+        if let Some(ref description) = description {
+            for line in description.lines() {
+                let line = format!("{}/// {}", indent, line);
+                writeln!(out, "{}", line.trim_end())?;
+            }
+        }
+
+        Ok(())
+    }
+}
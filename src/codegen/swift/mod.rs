@@ -0,0 +1,530 @@
+use crate::codegen;
+use crate::codegen::ir::{DeclKind, EnumMember, Field, Ir, Type, Variant};
+use crate::config::{Collisions, Config, TypeMapping};
+use crate::input::Input;
+use failure::{bail, Error};
+use inflector::Inflector;
+use std::collections::HashMap;
+use std::io::Write;
+use std::path::PathBuf;
+
+// The file holding the helpers shared by the code generated for every input.
+const HELPERS_FILE_NAME: &str = "JSONValue.swift";
+
+// Words which can't be used as identifiers without backticks.
+const KEYWORDS: &[&str] = &[
+    "Any",
+    "Self",
+    "_",
+    "as",
+    "associatedtype",
+    "await",
+    "break",
+    "case",
+    "catch",
+    "class",
+    "continue",
+    "default",
+    "defer",
+    "deinit",
+    "do",
+    "else",
+    "enum",
+    "extension",
+    "fallthrough",
+    "false",
+    "fileprivate",
+    "for",
+    "func",
+    "guard",
+    "if",
+    "import",
+    "in",
+    "init",
+    "inout",
+    "internal",
+    "is",
+    "let",
+    "nil",
+    "open",
+    "operator",
+    "precedencegroup",
+    "private",
+    "protocol",
+    "public",
+    "repeat",
+    "rethrows",
+    "return",
+    "self",
+    "static",
+    "struct",
+    "subscript",
+    "super",
+    "switch",
+    "throw",
+    "throws",
+    "true",
+    "try",
+    "typealias",
+    "var",
+    "where",
+    "while",
+];
+
+// Names generated types can't have, since generated code uses them itself,
+// or since Swift gives them a meaning of its own.
+const RESERVED_TYPE_NAMES: &[&str] = &[
+    "Any",
+    "Bool",
+    "Codable",
+    "CodingKey",
+    "CodingKeys",
+    "Decoder",
+    "DecodingError",
+    "Double",
+    "Encoder",
+    "Equatable",
+    "Indirect",
+    "JSONValue",
+    "Protocol",
+    "Self",
+    "String",
+    "Type",
+];
+
+#[derive(Debug)]
+pub struct Codegen {
+    names: HashMap<String, String>,
+    collisions: Collisions,
+    types: HashMap<String, TypeMapping>,
+    out_dir: PathBuf,
+}
+
+impl codegen::Codegen for Codegen {
+    type Ast = Ir;
+
+    fn args<'a, 'b>(app: clap::App<'a, 'b>) -> clap::App<'a, 'b> {
+        app.arg(
+            clap::Arg::with_name("swift-out")
+                .help("Swift output directory")
+                .takes_value(true)
+                .long("swift-out"),
+        )
+    }
+
+    fn from_args(matches: &clap::ArgMatches, config: &Config) -> Result<Option<Codegen>, Error> {
+        let mut config = config.clone();
+        if let Some(swift_out) = matches.value_of("swift-out") {
+            config.swift.out = Some(swift_out.to_owned());
+        }
+
+        Self::from_config(&config)
+    }
+
+    fn from_config(config: &Config) -> Result<Option<Codegen>, Error> {
+        if let Some(ref swift_out) = config.swift.out {
+            Ok(Some(Codegen {
+                names: config.names.clone(),
+                collisions: config.collisions,
+                types: config.swift.types.clone(),
+                out_dir: PathBuf::from(swift_out),
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    fn transform(&self, input: &Input) -> Result<Self::Ast, Error> {
+        // File systems are often case-insensitive, so a schema named
+        // `json_value` would clash with the helpers too.
+        if Self::out_file_name(&input.name).eq_ignore_ascii_case(HELPERS_FILE_NAME) {
+            bail!(
+                "Swift output for {} would overwrite {}",
+                input.name,
+                HELPERS_FILE_NAME
+            );
+        }
+
+        // Codable skips over unknown properties, and there's no switching
+        // that off, so strict mode doesn't apply to Swift.
+        let mut ir = Ir::from_input(
+            &input.name.to_pascal_case(),
+            input,
+            false,
+            &self.names,
+            self.collisions,
+            RESERVED_TYPE_NAMES,
+            "swift",
+            &self.types,
+        )?;
+
+        ir.name_members(
+            self.collisions,
+            &|_, field| Self::property_name(field),
+            &|_, val| Self::case_name(val),
+        )?;
+        ir.name_variants(self.collisions, &Self::variant_case_name)?;
+        Ok(ir)
+    }
+
+    fn serialize(&self, ir: &Self::Ast, output: &mut codegen::Output) -> Result<(), Error> {
+        // All of the output is expected to be in the same module, so the
+        // helpers it all shares live in a file of their own.
+        self.serialize_helpers(output)?;
+
+        let mut out = output.create(self.out_dir.join(Self::out_file_name(&ir.module)));
+
+        // Types from other inputs are in the same module, and need no imports.
+        let mut imports = vec!["Foundation"];
+        imports.extend(ir.mapped_imports().keys());
+        imports.sort();
+        imports.dedup();
+
+        for import in imports {
+            writeln!(out, "import {}", import)?;
+        }
+
+        for decl in ir.local_decls() {
+            writeln!(out)?;
+
+            match decl.kind {
+                DeclKind::Alias(ref typ) => {
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    writeln!(out, "typealias {} = {}", decl.name, self.type_expr(typ))?;
+                }
+                DeclKind::Record(ref fields) => {
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    self.serialize_struct(&mut out, ir, &decl.name, fields)?;
+                }
+                DeclKind::Enum(ref vals) => {
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    writeln!(out, "enum {}: String, Codable, Equatable {{", decl.name)?;
+                    for val in vals {
+                        let name = Self::case_name(val);
+                        if name == val.value {
+                            writeln!(out, "    case {}", name)?;
+                        } else {
+                            writeln!(out, "    case {} = {:?}", name, val.value)?;
+                        }
+                    }
+                    writeln!(out, "}}")?;
+                }
+                DeclKind::Union(ref tag, ref variants) => {
+                    for variant in variants {
+                        self.serialize_description(&mut out, "", &variant.description)?;
+                        self.serialize_struct(&mut out, ir, &variant.name, &variant.fields)?;
+                        writeln!(out)?;
+                    }
+
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    self.serialize_union(&mut out, &decl.name, tag, variants)?;
+                }
+            };
+        }
+
+        Ok(())
+    }
+}
+
+impl Codegen {
+    fn out_file_name(name: &str) -> String {
+        format!("{}.swift", name.to_pascal_case())
+    }
+
+    fn serialize_struct(
+        &self,
+        out: &mut dyn Write,
+        ir: &Ir,
+        name: &str,
+        fields: &[Field],
+    ) -> Result<(), Error> {
+        writeln!(out, "struct {}: Codable, Equatable {{", name)?;
+        for field in fields {
+            // Structs can't contain themselves, so fields leading back to the
+            // struct they're in hold their value indirectly.
+            let typ = if ir.is_recursive(name, field) {
+                format!("Indirect<{}>", self.type_expr(&field.value))
+            } else {
+                self.type_expr(&field.value)
+            };
+
+            self.serialize_description(out, "    ", &field.description)?;
+            if field.required {
+                writeln!(out, "    var {}: {}", Self::property_name(field), typ)?;
+            } else {
+                writeln!(out, "    var {}: {}?", Self::property_name(field), typ)?;
+            }
+        }
+
+        // Coding keys are only needed when properties are named differently
+        // in Swift, but then they're needed for every property.
+        if fields
+            .iter()
+            .any(|field| Self::property_name(field) != field.name)
+        {
+            writeln!(out)?;
+            writeln!(out, "    enum CodingKeys: String, CodingKey {{")?;
+            for field in fields {
+                let name = Self::property_name(field);
+                if name == field.name {
+                    writeln!(out, "        case {}", name)?;
+                } else {
+                    writeln!(out, "        case {} = {:?}", name, field.name)?;
+                }
+            }
+            writeln!(out, "    }}")?;
+        }
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
+
+    // Write out an enum with a case for each variant of a discriminator. The
+    // tag lives alongside the variant's properties, so Codable can't be
+    // synthesized, and has to be written out by hand.
+    fn serialize_union(
+        &self,
+        out: &mut dyn Write,
+        name: &str,
+        tag: &str,
+        variants: &[Variant],
+    ) -> Result<(), Error> {
+        writeln!(out, "enum {}: Codable, Equatable {{", name)?;
+        for variant in variants {
+            writeln!(
+                out,
+                "    case {}({})",
+                Self::variant_case_name(variant),
+                variant.name
+            )?;
+        }
+        writeln!(out)?;
+        writeln!(out, "    enum CodingKeys: String, CodingKey {{")?;
+        writeln!(out, "        case tag = {:?}", tag)?;
+        writeln!(out, "    }}")?;
+        writeln!(out)?;
+        writeln!(out, "    init(from decoder: Decoder) throws {{")?;
+        writeln!(
+            out,
+            "        let container = try decoder.container(keyedBy: CodingKeys.self)"
+        )?;
+        writeln!(
+            out,
+            "        let tag = try container.decode(String.self, forKey: .tag)"
+        )?;
+        writeln!(out, "        switch tag {{")?;
+        for variant in variants {
+            writeln!(out, "        case {:?}:", variant.tag_value)?;
+            writeln!(
+                out,
+                "            self = .{}(try {}(from: decoder))",
+                Self::variant_case_name(variant),
+                variant.name
+            )?;
+        }
+        writeln!(out, "        default:")?;
+        writeln!(
+            out,
+            "            throw DecodingError.dataCorruptedError(forKey: .tag, in: container, debugDescription: \"Unknown tag: \\(tag)\")"
+        )?;
+        writeln!(out, "        }}")?;
+        writeln!(out, "    }}")?;
+        writeln!(out)?;
+        writeln!(out, "    func encode(to encoder: Encoder) throws {{")?;
+        writeln!(
+            out,
+            "        var container = encoder.container(keyedBy: CodingKeys.self)"
+        )?;
+        writeln!(out, "        switch self {{")?;
+        for variant in variants {
+            writeln!(
+                out,
+                "        case .{}(let value):",
+                Self::variant_case_name(variant)
+            )?;
+            writeln!(
+                out,
+                "            try container.encode({:?}, forKey: .tag)",
+                variant.tag_value
+            )?;
+            writeln!(out, "            try value.encode(to: encoder)")?;
+        }
+        writeln!(out, "        }}")?;
+        writeln!(out, "    }}")?;
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
+
+    // Write out the type that schemas accepting any value are given, and the
+    // box recursive fields are kept in. Swift has nothing like either built
+    // in.
+    fn serialize_helpers(&self, output: &mut codegen::Output) -> Result<(), Error> {
+        let out = output.create(self.out_dir.join(HELPERS_FILE_NAME));
+        writeln!(out, "import Foundation")?;
+        writeln!(out)?;
+        writeln!(out, "/// Any JSON value.")?;
+        writeln!(out, "enum JSONValue: Codable, Equatable {{")?;
+        writeln!(out, "    case null")?;
+        writeln!(out, "    case bool(Bool)")?;
+        writeln!(out, "    case number(Double)")?;
+        writeln!(out, "    case string(String)")?;
+        writeln!(out, "    case array([JSONValue])")?;
+        writeln!(out, "    case object([String: JSONValue])")?;
+        writeln!(out)?;
+        writeln!(out, "    init(from decoder: Decoder) throws {{")?;
+        writeln!(
+            out,
+            "        let container = try decoder.singleValueContainer()"
+        )?;
+        writeln!(out, "        if container.decodeNil() {{")?;
+        writeln!(out, "            self = .null")?;
+        writeln!(
+            out,
+            "        }} else if let value = try? container.decode(Bool.self) {{"
+        )?;
+        writeln!(out, "            self = .bool(value)")?;
+        writeln!(
+            out,
+            "        }} else if let value = try? container.decode(Double.self) {{"
+        )?;
+        writeln!(out, "            self = .number(value)")?;
+        writeln!(
+            out,
+            "        }} else if let value = try? container.decode(String.self) {{"
+        )?;
+        writeln!(out, "            self = .string(value)")?;
+        writeln!(
+            out,
+            "        }} else if let value = try? container.decode([JSONValue].self) {{"
+        )?;
+        writeln!(out, "            self = .array(value)")?;
+        writeln!(out, "        }} else {{")?;
+        writeln!(
+            out,
+            "            self = .object(try container.decode([String: JSONValue].self))"
+        )?;
+        writeln!(out, "        }}")?;
+        writeln!(out, "    }}")?;
+        writeln!(out)?;
+        writeln!(out, "    func encode(to encoder: Encoder) throws {{")?;
+        writeln!(
+            out,
+            "        var container = encoder.singleValueContainer()"
+        )?;
+        writeln!(out, "        switch self {{")?;
+        writeln!(out, "        case .null:")?;
+        writeln!(out, "            try container.encodeNil()")?;
+        for case in &["bool", "number", "string", "array", "object"] {
+            writeln!(out, "        case .{}(let value):", case)?;
+            writeln!(out, "            try container.encode(value)")?;
+        }
+        writeln!(out, "        }}")?;
+        writeln!(out, "    }}")?;
+        writeln!(out, "}}")?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "/// A value stored out of line, so that types can contain themselves."
+        )?;
+        writeln!(
+            out,
+            "indirect enum Indirect<Value: Codable & Equatable>: Codable, Equatable {{"
+        )?;
+        writeln!(out, "    case wrapped(Value)")?;
+        writeln!(out)?;
+        writeln!(out, "    init(_ value: Value) {{")?;
+        writeln!(out, "        self = .wrapped(value)")?;
+        writeln!(out, "    }}")?;
+        writeln!(out)?;
+        writeln!(out, "    var value: Value {{")?;
+        writeln!(out, "        get {{")?;
+        writeln!(out, "            switch self {{")?;
+        writeln!(out, "            case .wrapped(let value):")?;
+        writeln!(out, "                return value")?;
+        writeln!(out, "            }}")?;
+        writeln!(out, "        }}")?;
+        writeln!(out, "        set {{")?;
+        writeln!(out, "            self = .wrapped(newValue)")?;
+        writeln!(out, "        }}")?;
+        writeln!(out, "    }}")?;
+        writeln!(out)?;
+        writeln!(out, "    init(from decoder: Decoder) throws {{")?;
+        writeln!(out, "        self = .wrapped(try Value(from: decoder))")?;
+        writeln!(out, "    }}")?;
+        writeln!(out)?;
+        writeln!(out, "    func encode(to encoder: Encoder) throws {{")?;
+        writeln!(out, "        try value.encode(to: encoder)")?;
+        writeln!(out, "    }}")?;
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
+
+    // The name of the Swift property for a JSON property. Properties are named
+    // just as they are in JSON where possible.
+    fn property_name(field: &Field) -> String {
+        let name = &field.name;
+        if let Some(ref identifier) = field.identifier {
+            identifier.clone()
+        } else if Self::is_identifier(name) {
+            codegen::identifier(name, "_", KEYWORDS)
+        } else {
+            codegen::identifier(&name.to_camel_case(), "_", KEYWORDS)
+        }
+    }
+
+    fn case_name(val: &EnumMember) -> String {
+        match val.identifier {
+            Some(ref identifier) => identifier.clone(),
+            None => codegen::identifier(&val.value.to_camel_case(), "_", KEYWORDS),
+        }
+    }
+
+    fn variant_case_name(variant: &Variant) -> String {
+        match variant.identifier {
+            Some(ref identifier) => identifier.clone(),
+            None => codegen::identifier(&variant.tag_value.to_camel_case(), "_", KEYWORDS),
+        }
+    }
+
+    // Whether a name can be used as it is. Swift allows more than this, but
+    // it's best to stick to ASCII.
+    fn is_identifier(name: &str) -> bool {
+        name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    }
+
+    fn type_expr(&self, typ: &Type) -> String {
+        match typ {
+            Type::Any => "JSONValue".to_owned(),
+            Type::Boolean => "Bool".to_owned(),
+            Type::Number => "Double".to_owned(),
+            Type::String => "String".to_owned(),
+
+            // Decoding dates depends on how the decoder is set up, so
+            // timestamps are left as they are in JSON.
+            Type::Timestamp => "String".to_owned(),
+            Type::Mapped(_, ref mapping) => mapping.name.clone(),
+            Type::Ref(ref id) => id.clone(),
+            Type::Elements(ref typ) => format!("[{}]", self.type_expr(typ)),
+            Type::Values(ref typ) => format!("[String: {}]", self.type_expr(typ)),
+        }
+    }
+
+    fn serialize_description(
+        &self,
+        out: &mut dyn Write,
+        indent: &str,
+        description: &Option<String>,
+    ) -> Result<(), Error> {
+        if let Some(ref description) = description {
+            for line in description.lines() {
+                let line = format!("{}/// {}", indent, line);
+                writeln!(out, "{}", line.trim_end())?;
+            }
+        }
+
+        Ok(())
+    }
+}
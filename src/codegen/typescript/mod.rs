@@ -1,20 +1,27 @@
 use crate::codegen;
-use failure::{format_err, Error};
+use crate::codegen::ir::{self, Decl, DeclKind, Field, Ir, Type};
+use crate::config::{Collisions, Config, TypeMapping};
+use crate::input::Input;
+use failure::Error;
 use inflector::Inflector;
-use jsl::schema::{Form, Type};
-use jsl::Schema;
-use std::fs::File;
-use std::io::{BufWriter, Write};
+use std::collections::HashMap;
+use std::io::Write;
 use std::path::PathBuf;
 
+// Names generated types can't have, since generated code uses them itself.
+const RESERVED_TYPE_NAMES: &[&str] = &["ValidationError"];
+
 #[derive(Debug)]
 pub struct Codegen {
-    root_name: String,
-    out_path: PathBuf,
+    strict: bool,
+    names: HashMap<String, String>,
+    collisions: Collisions,
+    types: HashMap<String, TypeMapping>,
+    out_dir: PathBuf,
 }
 
 impl codegen::Codegen for Codegen {
-    type Ast = Vec<Ast>;
+    type Ast = Ir;
 
     fn args<'a, 'b>(app: clap::App<'a, 'b>) -> clap::App<'a, 'b> {
         app.arg(
@@ -25,49 +32,155 @@ impl codegen::Codegen for Codegen {
         )
     }
 
-    fn from_args(matches: &clap::ArgMatches) -> Result<Option<Codegen>, Error> {
+    fn from_args(matches: &clap::ArgMatches, config: &Config) -> Result<Option<Codegen>, Error> {
+        let mut config = config.clone();
         if let Some(ts_out) = matches.value_of("ts-out") {
-            let input = PathBuf::from(matches.value_of("INPUT").unwrap());
-            let input_stem = input
-                .file_stem()
-                .ok_or(format_err!("Could not infer file stem from input"))?;
-            let input_stem_str = input_stem
-                .to_str()
-                .ok_or(format_err!("Could not convert input file name to UTF-8"))?;
-            let root_name = input_stem_str.to_pascal_case();
-            let out_file_name = format!("{}.ts", root_name);
+            config.typescript.out = Some(ts_out.to_owned());
+        }
 
+        Self::from_config(&config)
+    }
+
+    fn from_config(config: &Config) -> Result<Option<Codegen>, Error> {
+        if let Some(ref ts_out) = config.typescript.out {
             Ok(Some(Codegen {
-                root_name,
-                out_path: PathBuf::from(ts_out).join(out_file_name),
+                strict: config.strict,
+                names: config.names.clone(),
+                collisions: config.collisions,
+                types: config.typescript.types.clone(),
+                out_dir: PathBuf::from(ts_out),
             }))
         } else {
             Ok(None)
         }
     }
 
-    fn transform(&self, schema: &Schema) -> Result<Self::Ast, Error> {
-        let mut out = Vec::new();
+    fn transform(&self, input: &Input) -> Result<Self::Ast, Error> {
+        Ir::from_input(
+            &input.name.to_pascal_case(),
+            input,
+            self.strict,
+            &self.names,
+            self.collisions,
+            RESERVED_TYPE_NAMES,
+            "typescript",
+            &self.types,
+        )
+    }
+
+    fn serialize(&self, ir: &Self::Ast, output: &mut codegen::Output) -> Result<(), Error> {
+        let out_file_name = format!("{}.ts", ir.module.to_pascal_case());
+        let mut out = output.create(self.out_dir.join(out_file_name));
+
+        // Types mapped by the user only ever appear in type positions.
+        for (module, names) in ir.mapped_imports() {
+            let names: Vec<_> = names.into_iter().collect();
+            writeln!(
+                out,
+                "import type {{ {} }} from {:?};",
+                names.join(", "),
+                module
+            )?;
+        }
 
-        // First, generate each of the definitions.
-        for (name, def) in schema.definitions().as_ref().unwrap() {
-            let mut path = vec![name.clone()];
-            let ast = self.transform_subschema(&mut out, &mut path, def);
-            self.transform_for_id(&mut out, &path, ast);
+        // Types from other inputs are imported from the modules generated for
+        // them, along with their validators.
+        for (module, names) in ir.imports() {
+            let names: Vec<_> = names
+                .into_iter()
+                .flat_map(|name| vec![name.to_owned(), format!("validate{}At", name)])
+                .collect();
+
+            writeln!(
+                out,
+                "import {{ {} }} from \"./{}\";",
+                names.join(", "),
+                module.to_pascal_case()
+            )?;
         }
 
-        // Then, generate the root schema.
-        let mut path = vec![self.root_name.clone()];
-        let ast = self.transform_subschema(&mut out, &mut path, schema);
-        self.transform_for_id(&mut out, &path, ast);
+        // Every type gets a validator, which reports errors the same way the
+        // jsl crate does: as a pair of JSON Pointers into the instance and the
+        // schema.
+        writeln!(out, "export interface ValidationError {{")?;
+        writeln!(out, "  instancePath: string;")?;
+        writeln!(out, "  schemaPath: string;")?;
+        writeln!(out, "}}")?;
+        writeln!(out, "function jslPointer(path: string[]): string {{")?;
+        writeln!(
+            out,
+            "  return path.map((token) => \"/\" + token.replace(/~/g, \"~0\").replace(/\\//g, \"~1\")).join(\"\");"
+        )?;
+        writeln!(out, "}}")?;
+        writeln!(
+            out,
+            "function jslIsObject(instance: unknown): instance is {{ [key: string]: unknown }} {{"
+        )?;
+        writeln!(
+            out,
+            "  return typeof instance === \"object\" && instance !== null && !Array.isArray(instance);"
+        )?;
+        writeln!(out, "}}")?;
+        writeln!(
+            out,
+            "function jslHas(instance: {{ [key: string]: unknown }}, key: string): boolean {{"
+        )?;
+        writeln!(
+            out,
+            "  return Object.prototype.hasOwnProperty.call(instance, key);"
+        )?;
+        writeln!(out, "}}")?;
+
+        if ir.uses(&|typ| matches!(typ.unmapped(), Type::Timestamp)) {
+            writeln!(
+                out,
+                "function jslIsTimestamp(instance: unknown): boolean {{"
+            )?;
+            writeln!(
+                out,
+                "  return typeof instance === \"string\" && /^\\d{{4}}-\\d{{2}}-\\d{{2}}[Tt]\\d{{2}}:\\d{{2}}:\\d{{2}}(\\.\\d+)?([Zz]|[+-]\\d{{2}}:\\d{{2}})$/.test(instance) && !isNaN(Date.parse(instance));"
+            )?;
+            writeln!(out, "}}")?;
+        }
 
-        Ok(out)
-    }
+        for decl in ir.local_decls() {
+            match decl.kind {
+                DeclKind::Alias(ref typ) => {
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    writeln!(out, "export type {} = {};", decl.name, self.type_expr(typ))?;
+                }
+                DeclKind::Record(ref fields) => {
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    self.serialize_interface(&mut out, ir, &decl.name, None, fields)?;
+                }
+                DeclKind::Enum(ref vals) => {
+                    // TypeScript enums are plain string unions, so there are
+                    // no member names to choose.
+                    let lits: Vec<_> = vals.iter().map(|val| format!("{:?}", val.value)).collect();
+
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    writeln!(out, "export type {} = {};", decl.name, lits.join(" | "))?;
+                }
+                DeclKind::Union(ref tag, ref variants) => {
+                    for variant in variants {
+                        self.serialize_description(&mut out, "", &variant.description)?;
+                        self.serialize_interface(
+                            &mut out,
+                            ir,
+                            &variant.name,
+                            Some((tag, &variant.tag_value)),
+                            &variant.fields,
+                        )?;
+                    }
 
-    fn serialize(&self, asts: &Self::Ast) -> Result<(), Error> {
-        let mut out = BufWriter::new(File::create(self.out_path.clone())?);
-        for ast in asts {
-            self.serialize_subschema(&mut out, ast)?;
+                    let names: Vec<_> = variants.iter().map(|v| v.name.as_ref()).collect();
+
+                    self.serialize_description(&mut out, "", &decl.description)?;
+                    writeln!(out, "export type {} = {};", decl.name, names.join(" | "))?;
+                }
+            };
+
+            self.serialize_validator(&mut out, ir, decl)?;
         }
 
         Ok(())
@@ -75,195 +188,438 @@ impl codegen::Codegen for Codegen {
 }
 
 impl Codegen {
-    fn transform_subschema(
+    // Write out an interface. If a tag is provided, the interface is one case
+    // of a discriminated union, and the tag is included as a literal property.
+    fn serialize_interface(
         &self,
-        out: &mut Vec<Ast>,
-        path: &mut Vec<String>,
-        schema: &Schema,
-    ) -> Ast {
-        match schema.form() {
-            Form::Empty => Ast::Any,
-            Form::Ref(ref def) => Ast::Identifier(Self::path_to_identifier(&[def.clone()])),
-            Form::Type(ref typ) => match typ {
-                Type::Boolean => Ast::Boolean,
-                Type::Number => Ast::Number,
-                Type::String => Ast::String,
-                Type::Timestamp => Ast::String,
-            },
-            Form::Enum(ref vals) => {
-                Ast::Union(vals.iter().map(|v| Ast::Literal(v.clone())).collect())
-            }
-            Form::Elements(ref sub_schema) => {
-                Ast::Array(Box::new(self.transform_subschema(out, path, sub_schema)))
-            }
-            Form::Properties(ref required, ref optional, _) => {
-                let mut props = Vec::new();
-                for (name, prop) in required {
-                    props.push(self.transform_prop(out, path, true, name, prop));
-                }
+        out: &mut dyn Write,
+        ir: &Ir,
+        name: &str,
+        tag: Option<(&str, &str)>,
+        fields: &[Field],
+    ) -> Result<(), Error> {
+        writeln!(out, "export interface {} {{", name)?;
+        if let Some((tag, tag_value)) = tag {
+            writeln!(out, "  {}: {:?};", Self::property_name(tag), tag_value)?;
+        }
 
-                for (name, prop) in optional {
-                    props.push(self.transform_prop(out, path, false, name, prop));
-                }
+        for field in fields {
+            let q_mark = if field.required { "" } else { "?" };
+
+            self.serialize_description(out, "  ", &field.description)?;
+            writeln!(
+                out,
+                "  {}{}: {};",
+                Self::property_name(&field.name),
+                q_mark,
+                self.type_expr(&field.value)
+            )?;
+        }
+
+        // Unless the schema is being used strictly, objects may also contain
+        // properties it doesn't describe.
+        if !ir.strict {
+            writeln!(out, "  [key: string]: unknown;")?;
+        }
+        writeln!(out, "}}")?;
+
+        Ok(())
+    }
 
-                let id = Self::path_to_identifier(path);
-                out.push(Ast::Interface(id.clone(), props));
-                Ast::Identifier(id)
+    // Write out the validator for a declaration. The first function collects
+    // all of the errors for an instance, and the second is how validators for
+    // other declarations, possibly in other modules, refer to this one.
+    fn serialize_validator(&self, out: &mut dyn Write, ir: &Ir, decl: &Decl) -> Result<(), Error> {
+        let schema_path = &decl.schema_path;
+
+        writeln!(
+            out,
+            "export function validate{}(instance: unknown): ValidationError[] {{",
+            decl.name
+        )?;
+        writeln!(out, "  const errors: ValidationError[] = [];")?;
+        writeln!(out, "  validate{}At(instance, [], errors);", decl.name)?;
+        writeln!(out, "  return errors;")?;
+        writeln!(out, "}}")?;
+        writeln!(
+            out,
+            "export function validate{}At(instance: unknown, path: string[], errors: ValidationError[]): void {{",
+            decl.name
+        )?;
+
+        match decl.kind {
+            DeclKind::Alias(ref typ) => {
+                self.serialize_check(out, typ, "instance", &[], schema_path, 0, "  ")?;
             }
-            Form::Values(ref sub_schema) => {
-                Ast::Map(Box::new(self.transform_subschema(out, path, sub_schema)))
+            DeclKind::Record(ref fields) => {
+                // As in jsl, which keyword an error about a non-object is
+                // attributed to depends on which keywords are present.
+                let keyword = if fields.iter().any(|field| field.required) {
+                    "properties"
+                } else {
+                    "optionalProperties"
+                };
+
+                writeln!(out, "  if (!jslIsObject(instance)) {{")?;
+                self.serialize_error(out, &[], schema_path, &[keyword], "    ")?;
+                writeln!(out, "    return;")?;
+                writeln!(out, "  }}")?;
+                self.serialize_field_checks(out, fields, schema_path, "  ")?;
+                if ir.strict {
+                    self.serialize_unknown_check(out, fields, None, schema_path, "  ")?;
+                }
+            }
+            DeclKind::Enum(ref vals) => {
+                let conds: Vec<_> = vals
+                    .iter()
+                    .map(|val| format!("instance !== {:?}", val.value))
+                    .collect();
+
+                writeln!(out, "  if ({}) {{", conds.join(" && "))?;
+                self.serialize_error(out, &[], schema_path, &["enum"], "    ")?;
+                writeln!(out, "  }}")?;
             }
-            Form::Discriminator(ref tag, ref mapping) => {
-                let mut cases = Vec::new();
-                for (name, case) in mapping {
-                    path.push(name.clone());
-
-                    let mut props = Vec::new();
-                    props.push(Property {
-                        name: tag.clone(),
-                        required: true,
-                        value: Ast::Literal(name.clone()),
-                    });
-
-                    let (required, optional) = match case.form() {
-                        Form::Properties(ref required, ref optional, _) => (required, optional),
-                        _ => unreachable!("non-prop form in mapping"),
-                    };
-
-                    for (name, prop) in required {
-                        props.push(self.transform_prop(out, path, true, name, prop));
+            DeclKind::Union(ref tag, ref variants) => {
+                let tag_path = [format!("{:?}", tag)];
+
+                writeln!(out, "  if (!jslIsObject(instance)) {{")?;
+                self.serialize_error(out, &[], schema_path, &["discriminator"], "    ")?;
+                writeln!(out, "    return;")?;
+                writeln!(out, "  }}")?;
+                writeln!(out, "  if (!jslHas(instance, {:?})) {{", tag)?;
+                self.serialize_error(out, &[], schema_path, &["discriminator", "tag"], "    ")?;
+                writeln!(out, "    return;")?;
+                writeln!(out, "  }}")?;
+                writeln!(out, "  switch (instance[{:?}]) {{", tag)?;
+                for variant in variants {
+                    let variant_path = [
+                        &schema_path[..],
+                        &[
+                            "discriminator".to_owned(),
+                            "mapping".to_owned(),
+                            variant.tag_value.clone(),
+                        ],
+                    ]
+                    .concat();
+
+                    writeln!(out, "    case {:?}:", variant.tag_value)?;
+                    self.serialize_field_checks(out, &variant.fields, &variant_path, "      ")?;
+                    if ir.strict {
+                        self.serialize_unknown_check(
+                            out,
+                            &variant.fields,
+                            Some(tag),
+                            &variant_path,
+                            "      ",
+                        )?;
                     }
+                    writeln!(out, "      break;")?;
+                }
+                writeln!(out, "    default:")?;
+                writeln!(
+                    out,
+                    "      if (typeof instance[{:?}] === \"string\") {{",
+                    tag
+                )?;
+                self.serialize_error(
+                    out,
+                    &tag_path,
+                    schema_path,
+                    &["discriminator", "mapping"],
+                    "        ",
+                )?;
+                writeln!(out, "      }} else {{")?;
+                self.serialize_error(
+                    out,
+                    &tag_path,
+                    schema_path,
+                    &["discriminator", "tag"],
+                    "        ",
+                )?;
+                writeln!(out, "      }}")?;
+                writeln!(out, "  }}")?;
+            }
+        };
 
-                    for (name, prop) in optional {
-                        props.push(self.transform_prop(out, path, false, name, prop));
-                    }
+        writeln!(out, "}}")?;
 
-                    let id = Self::path_to_identifier(path);
-                    out.push(Ast::Interface(id.clone(), props));
-                    cases.push(Ast::Identifier(id));
+        Ok(())
+    }
 
-                    path.pop();
+    // Write out the checks on the properties of `instance`, which the caller
+    // has already made sure is an object.
+    fn serialize_field_checks(
+        &self,
+        out: &mut dyn Write,
+        fields: &[Field],
+        schema_path: &[String],
+        indent: &str,
+    ) -> Result<(), Error> {
+        for field in fields {
+            let keyword = if field.required {
+                "properties"
+            } else {
+                "optionalProperties"
+            };
+
+            let field_path = [schema_path, &[keyword.to_owned(), field.name.clone()]].concat();
+            let inner_indent = format!("{}  ", indent);
+
+            if let Type::Any = field.value {
+                // Anything goes, so all that's left to check is whether a
+                // required property is present.
+                if field.required {
+                    writeln!(out, "{}if (!jslHas(instance, {:?})) {{", indent, field.name)?;
+                    self.serialize_error(out, &[], &field_path, &[], &inner_indent)?;
+                    writeln!(out, "{}}}", indent)?;
                 }
 
-                Ast::Union(cases)
+                continue;
             }
+
+            writeln!(out, "{}if (jslHas(instance, {:?})) {{", indent, field.name)?;
+            self.serialize_check(
+                out,
+                &field.value,
+                &format!("instance[{:?}]", field.name),
+                &[format!("{:?}", field.name)],
+                &field_path,
+                0,
+                &inner_indent,
+            )?;
+
+            if field.required {
+                writeln!(out, "{}}} else {{", indent)?;
+                self.serialize_error(out, &[], &field_path, &[], &inner_indent)?;
+            }
+
+            writeln!(out, "{}}}", indent)?;
         }
+
+        Ok(())
     }
 
-    fn transform_prop(
+    // Write out a check that `instance` has no properties other than the
+    // fields and, for the cases of a discriminated union, the tag.
+    fn serialize_unknown_check(
         &self,
-        out: &mut Vec<Ast>,
-        path: &mut Vec<String>,
-        required: bool,
-        name: &str,
-        prop: &Schema,
-    ) -> Property {
-        path.push(name.to_owned());
-        let value = self.transform_subschema(out, path, prop);
-        path.pop();
-
-        Property {
-            name: name.to_owned(),
-            required,
-            value,
+        out: &mut dyn Write,
+        fields: &[Field],
+        tag: Option<&str>,
+        schema_path: &[String],
+        indent: &str,
+    ) -> Result<(), Error> {
+        writeln!(
+            out,
+            "{}Object.keys(instance).forEach((key: string) => {{",
+            indent
+        )?;
+        writeln!(out, "{}  switch (key) {{", indent)?;
+        for name in fields.iter().map(|field| field.name.as_str()).chain(tag) {
+            writeln!(out, "{}    case {:?}:", indent, name)?;
         }
-    }
-
-    // Ensure that an AST will get a top-level identifier, and then return an
-    // AST for an identifier that refers to it.
-    fn transform_for_id(&self, out: &mut Vec<Ast>, path: &Vec<String>, ast: Ast) -> Ast {
-        match ast {
-            Ast::Interface(_, _) | Ast::Type(_, _) | Ast::Identifier(_) => {}
-            _ => {
-                out.push(Ast::Type(Self::path_to_identifier(path), Box::new(ast)));
-            }
-        };
+        if !fields.is_empty() || tag.is_some() {
+            writeln!(out, "{}      break;", indent)?;
+        }
+        writeln!(out, "{}    default:", indent)?;
+        self.serialize_error(
+            out,
+            &["key".to_owned()],
+            schema_path,
+            &[],
+            &format!("{}      ", indent),
+        )?;
+        writeln!(out, "{}  }}", indent)?;
+        writeln!(out, "{}}});", indent)?;
 
-        Ast::Identifier(Self::path_to_identifier(path))
+        Ok(())
     }
 
-    fn serialize_subschema(&self, out: &mut Write, ast: &Ast) -> Result<(), Error> {
-        match ast {
-            Ast::Any => write!(out, "any")?,
-            Ast::Boolean => write!(out, "boolean")?,
-            Ast::Number => write!(out, "number")?,
-            Ast::String => write!(out, "string")?,
-            Ast::Identifier(ref id) => write!(out, "{}", id)?,
-            Ast::Literal(ref lit) => write!(out, "{:?}", lit)?,
-            Ast::Array(ref ast) => {
-                self.serialize_subschema(out, ast)?;
-                write!(out, "[]")?;
-            }
-            Ast::Map(ref ast) => {
-                write!(out, "{{ [name: string]: ")?;
-                self.serialize_subschema(out, ast)?;
-                write!(out, " }}")?;
+    // Write out the statements checking that `expr` is a valid instance of
+    // `typ`. The instance path of `expr` is `path` followed by the tokens in
+    // `path_tokens`, which are TypeScript expressions. Depth is used to keep
+    // loop variables from shadowing one another.
+    #[allow(clippy::too_many_arguments)]
+    fn serialize_check(
+        &self,
+        out: &mut dyn Write,
+        typ: &Type,
+        expr: &str,
+        path_tokens: &[String],
+        schema_path: &[String],
+        depth: usize,
+        indent: &str,
+    ) -> Result<(), Error> {
+        let inner_indent = format!("{}  ", indent);
+        let cond = match typ {
+            Type::Any => return Ok(()),
+            Type::Boolean => format!("typeof {} !== \"boolean\"", expr),
+            Type::Number => format!("typeof {} !== \"number\"", expr),
+            Type::String => format!("typeof {} !== \"string\"", expr),
+            Type::Timestamp => format!("!jslIsTimestamp({})", expr),
+            Type::Mapped(ref typ, _) => {
+                return self.serialize_check(
+                    out,
+                    typ,
+                    expr,
+                    path_tokens,
+                    schema_path,
+                    depth,
+                    indent,
+                );
             }
-            Ast::Type(ref id, ref ast) => {
-                write!(out, "export type {} = ", id)?;
-                self.serialize_subschema(out, ast)?;
-                writeln!(out, ";")?;
+            Type::Ref(ref id) => {
+                writeln!(
+                    out,
+                    "{}validate{}At({}, {}, errors);",
+                    indent,
+                    id,
+                    expr,
+                    Self::path_expr(path_tokens)
+                )?;
+                return Ok(());
             }
-            Ast::Interface(ref name, ref props) => {
-                writeln!(out, "export interface {} {{", name)?;
-                for prop in props {
-                    let q_mark = if prop.required { "" } else { "?" };
-                    write!(out, "  {}{}: ", prop.name, q_mark)?;
-                    self.serialize_subschema(out, &prop.value)?;
-                    writeln!(out, ";")?;
+            Type::Elements(ref sub_typ) => {
+                let arr = format!("a{}", depth);
+                let index = format!("i{}", depth);
+                let elem = format!("v{}", depth);
+
+                // Bind the value to a constant first, so that narrowing its
+                // type carries over into the callback below.
+                writeln!(out, "{}const {}: unknown = {};", indent, arr, expr)?;
+                writeln!(out, "{}if (Array.isArray({})) {{", indent, arr)?;
+                if !matches!(**sub_typ, Type::Any) {
+                    writeln!(
+                        out,
+                        "{}{}.forEach(({}: unknown, {}: number) => {{",
+                        inner_indent, arr, elem, index
+                    )?;
+                    self.serialize_check(
+                        out,
+                        sub_typ,
+                        &elem,
+                        &[path_tokens, &[format!("String({})", index)]].concat(),
+                        &[schema_path, &["elements".to_owned()]].concat(),
+                        depth + 1,
+                        &format!("{}  ", inner_indent),
+                    )?;
+                    writeln!(out, "{}}});", inner_indent)?;
                 }
-                writeln!(out, "}}")?;
+                writeln!(out, "{}}} else {{", indent)?;
+                self.serialize_error(out, path_tokens, schema_path, &["elements"], &inner_indent)?;
+                writeln!(out, "{}}}", indent)?;
+                return Ok(());
             }
-            Ast::Union(ref asts) => {
-                for ast in &asts[..asts.len() - 1] {
-                    self.serialize_subschema(out, ast)?;
-                    write!(out, " | ")?;
+            Type::Values(ref sub_typ) => {
+                let obj = format!("o{}", depth);
+                let key = format!("k{}", depth);
+
+                writeln!(out, "{}const {}: unknown = {};", indent, obj, expr)?;
+                writeln!(out, "{}if (jslIsObject({})) {{", indent, obj)?;
+                if !matches!(**sub_typ, Type::Any) {
+                    writeln!(
+                        out,
+                        "{}Object.keys({}).forEach(({}: string) => {{",
+                        inner_indent, obj, key
+                    )?;
+                    self.serialize_check(
+                        out,
+                        sub_typ,
+                        &format!("{}[{}]", obj, key),
+                        &[path_tokens, &[key]].concat(),
+                        &[schema_path, &["values".to_owned()]].concat(),
+                        depth + 1,
+                        &format!("{}  ", inner_indent),
+                    )?;
+                    writeln!(out, "{}}});", inner_indent)?;
                 }
-
-                self.serialize_subschema(out, asts.last().unwrap())?;
+                writeln!(out, "{}}} else {{", indent)?;
+                self.serialize_error(out, path_tokens, schema_path, &["values"], &inner_indent)?;
+                writeln!(out, "{}}}", indent)?;
+                return Ok(());
             }
         };
 
+        writeln!(out, "{}if ({}) {{", indent, cond)?;
+        self.serialize_error(out, path_tokens, schema_path, &["type"], &inner_indent)?;
+        writeln!(out, "{}}}", indent)?;
+
         Ok(())
     }
 
-    fn path_to_identifier(path: &[String]) -> String {
-        path.join("_").to_pascal_case()
-    }
-}
-
-#[derive(Debug)]
-pub enum Ast {
-    Any,
-    Boolean,
-    Number,
-    String,
-
-    // An identifier.
-    Identifier(String),
+    // Write out a statement adding an error to `errors`. The schema path is
+    // `schema_path` followed by the keywords in `rest`.
+    fn serialize_error(
+        &self,
+        out: &mut dyn Write,
+        path_tokens: &[String],
+        schema_path: &[String],
+        rest: &[&str],
+        indent: &str,
+    ) -> Result<(), Error> {
+        writeln!(
+            out,
+            "{}errors.push({{ instancePath: jslPointer({}), schemaPath: {:?} }});",
+            indent,
+            Self::path_expr(path_tokens),
+            ir::json_pointer(schema_path, rest)
+        )?;
 
-    // A constant string type.
-    Literal(String),
+        Ok(())
+    }
 
-    // An array with elements of some type.
-    Array(Box<Ast>),
+    // Render the name of a property, quoting it unless it's a valid
+    // identifier. Reserved words are fine as property names.
+    fn property_name(name: &str) -> String {
+        let valid = name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_' || c == '$')
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
 
-    // A map from strings to some type.
-    Map(Box<Ast>),
+        if valid {
+            name.to_owned()
+        } else {
+            format!("{:?}", name)
+        }
+    }
 
-    // A type declaration.
-    Type(String, Box<Ast>),
+    fn path_expr(path_tokens: &[String]) -> String {
+        if path_tokens.is_empty() {
+            "path".to_owned()
+        } else {
+            format!("[...path, {}]", path_tokens.join(", "))
+        }
+    }
 
-    // An interface with a name and properties.
-    Interface(String, Vec<Property>),
+    fn type_expr(&self, typ: &Type) -> String {
+        match typ {
+            Type::Any => "any".to_owned(),
+            Type::Boolean => "boolean".to_owned(),
+            Type::Number => "number".to_owned(),
+            Type::String => "string".to_owned(),
+            Type::Timestamp => "string".to_owned(),
+            Type::Mapped(_, ref mapping) => mapping.name.clone(),
+            Type::Ref(ref id) => id.clone(),
+            Type::Elements(ref typ) => format!("{}[]", self.type_expr(typ)),
+            Type::Values(ref typ) => format!("{{ [name: string]: {} }}", self.type_expr(typ)),
+        }
+    }
 
-    // A union of types.
-    Union(Vec<Ast>),
-}
+    fn serialize_description(
+        &self,
+        out: &mut dyn Write,
+        indent: &str,
+        description: &Option<String>,
+    ) -> Result<(), Error> {
+        if let Some(ref description) = description {
+            writeln!(out, "{}/**", indent)?;
+            for line in description.lines() {
+                let line = format!("{} * {}", indent, line.replace("*/", "*\\/"));
+                writeln!(out, "{}", line.trim_end())?;
+            }
+            writeln!(out, "{} */", indent)?;
+        }
 
-#[derive(Debug)]
-pub struct Property {
-    name: String,
-    required: bool,
-    value: Ast,
+        Ok(())
+    }
 }
@@ -0,0 +1,149 @@
+use failure::{format_err, Error};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// The file a config is read from when none is given explicitly.
+pub const DEFAULT_FILE_NAME: &str = "jsl-codegen.toml";
+
+// Settings read from a project configuration file. Everything here can also
+// be given on the command line, which takes precedence.
+//
+// Relative paths are resolved against the directory holding the config file,
+// so that it means the same thing wherever the generator is run from.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    // Schema files, directories, or globs to generate code from.
+    pub inputs: Vec<String>,
+    pub strict: bool,
+
+    // Names to use in place of the ones that would be generated for types,
+    // keyed by the generated name.
+    pub names: HashMap<String, String>,
+
+    // What to do when different schemas would get the same name.
+    pub collisions: Collisions,
+
+    pub typescript: Target,
+    pub java: Target,
+    pub go: Target,
+    pub rust: Target,
+    pub python: Target,
+    pub kotlin: Target,
+    pub csharp: Target,
+    pub swift: Target,
+    pub dart: Target,
+    pub protobuf: Target,
+}
+
+// Settings for one of the output languages.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Target {
+    // The directory to write output to. Output is only generated for targets
+    // with an output directory.
+    pub out: Option<String>,
+
+    // The package to generate code into, for targets which have them. For C#,
+    // this is the namespace.
+    pub package: Option<String>,
+
+    // Types to generate in place of the usual ones, keyed by the JSL type
+    // they replace, like `timestamp`, or by a name schemas give in their
+    // metadata.
+    pub types: HashMap<String, TypeMapping>,
+}
+
+// A type to use in generated code, in place of the one a schema would
+// otherwise be given.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TypeMapping {
+    // The type, as it's written in the target language.
+    #[serde(rename = "type")]
+    pub name: String,
+
+    // What the type needs imported, as the target language puts it: a class
+    // in Java or Kotlin, a namespace in C#, a module in Swift, a path in
+    // Rust, a package in Go, a library URI in Dart, a file in Protocol
+    // Buffers, or the module to import the type from in TypeScript and
+    // Python.
+    pub import: Option<String>,
+}
+
+// How to deal with different schemas that would be given the same name.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Collisions {
+    // Refuse to generate code.
+    #[default]
+    Error,
+
+    // Number the names after the first, in the order they're found.
+    Rename,
+}
+
+impl Config {
+    // Read a config file, in TOML or, if it has a `.json` extension, JSON.
+    pub fn from_file(path: &Path) -> Result<Config, Error> {
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format_err!("Could not read {}: {}", path.display(), err))?;
+
+        let mut config: Config = if path.extension().and_then(|ext| ext.to_str()) == Some("json") {
+            serde_json::from_str(&contents)
+                .map_err(|err| format_err!("Invalid config {}: {}", path.display(), err))?
+        } else {
+            toml::from_str(&contents)
+                .map_err(|err| format_err!("Invalid config {}: {}", path.display(), err))?
+        };
+
+        let base = path.parent().unwrap_or_else(|| Path::new(""));
+        config.inputs = config
+            .inputs
+            .iter()
+            .map(|input| relative_to(base, input))
+            .collect();
+
+        for target in config.targets_mut() {
+            target.out = target.out.as_ref().map(|out| relative_to(base, out));
+        }
+
+        Ok(config)
+    }
+
+    // Find the config to use, if any. An explicitly given file must exist;
+    // otherwise, the default file is used if it's in the working directory.
+    pub fn find(path: Option<&str>) -> Result<Config, Error> {
+        match path {
+            Some(path) => Self::from_file(Path::new(path)),
+            None if Path::new(DEFAULT_FILE_NAME).is_file() => {
+                Self::from_file(Path::new(DEFAULT_FILE_NAME))
+            }
+            None => Ok(Config::default()),
+        }
+    }
+
+    // The settings for each of the output languages.
+    pub fn targets_mut(&mut self) -> Vec<&mut Target> {
+        vec![
+            &mut self.typescript,
+            &mut self.java,
+            &mut self.go,
+            &mut self.rust,
+            &mut self.python,
+            &mut self.kotlin,
+            &mut self.csharp,
+            &mut self.swift,
+            &mut self.dart,
+            &mut self.protobuf,
+        ]
+    }
+}
+
+fn relative_to(base: &Path, path: &str) -> String {
+    let mut joined = PathBuf::from(base);
+    joined.push(path);
+    joined.to_string_lossy().into_owned()
+}
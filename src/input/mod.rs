@@ -0,0 +1,223 @@
+use failure::{bail, format_err, Error};
+use jsl::{Schema, SerdeSchema};
+use std::collections::HashMap;
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
+
+// A schema to generate code from, along with everything needed to resolve its
+// references to other schemas.
+#[derive(Debug)]
+pub struct Input {
+    // The name of the module generated from this schema. This is the file
+    // stem of the schema it came from.
+    pub name: String,
+
+    // The schema itself. Any definitions it refers to from other inputs have
+    // been copied into its definitions, so that it is valid on its own.
+    pub schema: Schema,
+
+    // The definitions this schema borrows from other inputs, mapped to the
+    // name of the input that declares them.
+    pub foreign: HashMap<String, String>,
+}
+
+// Find all of the schema files a list of command-line arguments refers to.
+// Each argument may be a file, a directory of `.json` files, or a glob
+// pattern.
+pub fn find(args: &[&str]) -> Result<Vec<PathBuf>, Error> {
+    let mut paths = Vec::new();
+    for arg in args {
+        let path = Path::new(arg);
+        if path.is_dir() {
+            let mut entries = Vec::new();
+            for entry in fs::read_dir(path)? {
+                let entry_path = entry?.path();
+                if entry_path.is_file()
+                    && entry_path.extension().and_then(|ext| ext.to_str()) == Some("json")
+                {
+                    entries.push(entry_path);
+                }
+            }
+
+            entries.sort();
+            paths.extend(entries);
+        } else if path.exists() {
+            paths.push(path.to_owned());
+        } else {
+            let matched = glob::glob(arg)?.collect::<Result<Vec<_>, _>>()?;
+            if matched.is_empty() {
+                bail!("No schema files found for input: {}", arg);
+            }
+
+            paths.extend(matched);
+        }
+    }
+
+    // The same file may be named more than once, for instance by overlapping
+    // globs. It only needs to be generated once.
+    let mut seen = Vec::new();
+    paths.retain(|path| {
+        let canonical = path.canonicalize().unwrap_or_else(|_| path.clone());
+        if seen.contains(&canonical) {
+            false
+        } else {
+            seen.push(canonical);
+            true
+        }
+    });
+
+    Ok(paths)
+}
+
+// Parse each of a set of schema files, and resolve references between them.
+pub fn load(paths: &[PathBuf]) -> Result<Vec<Input>, Error> {
+    let mut schemas = Vec::new();
+    for path in paths {
+        let name = path
+            .file_stem()
+            .ok_or_else(|| format_err!("Could not infer file stem from input"))?
+            .to_str()
+            .ok_or_else(|| format_err!("Could not convert input file name to UTF-8"))?
+            .to_owned();
+
+        let serde_schema: SerdeSchema = serde_json::from_reader(File::open(path)?)
+            .map_err(|err| format_err!("Could not parse {}: {}", path.display(), err))?;
+
+        schemas.push((name, serde_schema));
+    }
+
+    resolve(schemas)
+}
+
+// Turn a set of named schemas into inputs, resolving references between them.
+//
+// A reference resolves to a definition in the same schema if there is one.
+// Otherwise, it resolves to the definition of that name in whichever other
+// schema declares it, which must be unambiguous. Borrowed definitions are
+// copied into the schema borrowing them, along with anything they refer to in
+// turn.
+pub fn resolve(schemas: Vec<(String, SerdeSchema)>) -> Result<Vec<Input>, Error> {
+    for (i, (name, _)) in schemas.iter().enumerate() {
+        if schemas[..i].iter().any(|(other, _)| other == name) {
+            bail!("Multiple inputs are named {}", name);
+        }
+    }
+
+    let mut inputs = Vec::new();
+    for (index, (name, serde_schema)) in schemas.iter().enumerate() {
+        let mut merged = serde_schema.clone();
+        let mut defs = merged.defs.take().unwrap_or_default();
+
+        // Which input each of the definitions in the merged schema came from.
+        let mut owners: HashMap<String, usize> =
+            defs.keys().map(|def| (def.clone(), index)).collect();
+
+        // References yet to be resolved, and the input they were made from.
+        let mut pending = Vec::new();
+        collect_refs(serde_schema, index, &mut pending);
+        for def in defs.values() {
+            collect_refs(def, index, &mut pending);
+        }
+
+        while let Some((from, rxf)) = pending.pop() {
+            let owner = find_owner(&schemas, from, &rxf)?;
+            match owners.get(&rxf) {
+                Some(existing) if *existing == owner => {}
+                Some(existing) => bail!(
+                    "{} uses definition {} from both {} and {}",
+                    name,
+                    rxf,
+                    schemas[*existing].0,
+                    schemas[owner].0
+                ),
+                None => {
+                    let def = schemas[owner].1.defs.as_ref().unwrap()[&rxf].clone();
+                    collect_refs(&def, owner, &mut pending);
+                    defs.insert(rxf.clone(), def);
+                    owners.insert(rxf, owner);
+                }
+            }
+        }
+
+        merged.defs = Some(defs);
+        let schema = Schema::from_serde(merged)
+            .map_err(|err| format_err!("Invalid schema {}: {}", name, err))?;
+
+        let foreign = owners
+            .into_iter()
+            .filter(|(_, owner)| *owner != index)
+            .map(|(def, owner)| (def, schemas[owner].0.clone()))
+            .collect();
+
+        inputs.push(Input {
+            name: name.clone(),
+            schema,
+            foreign,
+        });
+    }
+
+    Ok(inputs)
+}
+
+// Work out which input declares the definition a reference points to.
+fn find_owner(schemas: &[(String, SerdeSchema)], from: usize, rxf: &str) -> Result<usize, Error> {
+    let declares = |schema: &SerdeSchema| {
+        schema
+            .defs
+            .as_ref()
+            .is_some_and(|defs| defs.contains_key(rxf))
+    };
+
+    if declares(&schemas[from].1) {
+        return Ok(from);
+    }
+
+    let owners: Vec<_> = (0..schemas.len())
+        .filter(|&i| declares(&schemas[i].1))
+        .collect();
+
+    match owners.len() {
+        0 => bail!(
+            "{} refers to {}, which no input defines",
+            schemas[from].0,
+            rxf
+        ),
+        1 => Ok(owners[0]),
+        _ => bail!(
+            "{} refers to {}, which is defined by more than one input: {}",
+            schemas[from].0,
+            rxf,
+            owners
+                .iter()
+                .map(|&i| schemas[i].0.as_str())
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    }
+}
+
+// Gather up the references a schema makes, not counting those made by its
+// definitions.
+fn collect_refs(schema: &SerdeSchema, from: usize, out: &mut Vec<(usize, String)>) {
+    if let Some(ref rxf) = schema.rxf {
+        out.push((from, rxf.clone()));
+    }
+
+    let children = schema
+        .elems
+        .iter()
+        .map(|elems| &**elems)
+        .chain(schema.values.iter().map(|values| &**values))
+        .chain(schema.props.iter().flat_map(|props| props.values()))
+        .chain(schema.opt_props.iter().flat_map(|props| props.values()))
+        .chain(
+            schema
+                .discriminator
+                .iter()
+                .flat_map(|discriminator| discriminator.mapping.values()),
+        );
+
+    for child in children {
+        collect_refs(child, from, out);
+    }
+}
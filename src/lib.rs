@@ -0,0 +1,193 @@
+pub mod build;
+pub mod codegen;
+pub mod config;
+pub mod input;
+
+use crate::codegen::Codegen;
+use crate::config::Config;
+use crate::input::Input;
+use failure::{format_err, Error};
+use jsl::Schema;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+// The code generators for each of the output languages, configured and ready
+// to generate code.
+#[derive(Debug)]
+pub struct Generator {
+    typescript: Option<codegen::typescript::Codegen>,
+    java: Option<codegen::java::Codegen>,
+    go: Option<codegen::go::Codegen>,
+    rust: Option<codegen::rust::Codegen>,
+    python: Option<codegen::python::Codegen>,
+    kotlin: Option<codegen::kotlin::Codegen>,
+    csharp: Option<codegen::csharp::Codegen>,
+    swift: Option<codegen::swift::Codegen>,
+    dart: Option<codegen::dart::Codegen>,
+    protobuf: Option<codegen::protobuf::Codegen>,
+}
+
+impl Generator {
+    // Set up the CLI for each of the code generators.
+    pub fn args<'a, 'b>(app: clap::App<'a, 'b>) -> clap::App<'a, 'b> {
+        let app = codegen::typescript::Codegen::args(app);
+        let app = codegen::java::Codegen::args(app);
+        let app = codegen::go::Codegen::args(app);
+        let app = codegen::rust::Codegen::args(app);
+        let app = codegen::python::Codegen::args(app);
+        let app = codegen::kotlin::Codegen::args(app);
+        let app = codegen::csharp::Codegen::args(app);
+        let app = codegen::swift::Codegen::args(app);
+        let app = codegen::dart::Codegen::args(app);
+        codegen::protobuf::Codegen::args(app)
+    }
+
+    pub fn from_args(matches: &clap::ArgMatches, config: &Config) -> Result<Generator, Error> {
+        Ok(Generator {
+            typescript: codegen::typescript::Codegen::from_args(matches, config)?,
+            java: codegen::java::Codegen::from_args(matches, config)?,
+            go: codegen::go::Codegen::from_args(matches, config)?,
+            rust: codegen::rust::Codegen::from_args(matches, config)?,
+            python: codegen::python::Codegen::from_args(matches, config)?,
+            kotlin: codegen::kotlin::Codegen::from_args(matches, config)?,
+            csharp: codegen::csharp::Codegen::from_args(matches, config)?,
+            swift: codegen::swift::Codegen::from_args(matches, config)?,
+            dart: codegen::dart::Codegen::from_args(matches, config)?,
+            protobuf: codegen::protobuf::Codegen::from_args(matches, config)?,
+        })
+    }
+
+    pub fn from_config(config: &Config) -> Result<Generator, Error> {
+        Ok(Generator {
+            typescript: codegen::typescript::Codegen::from_config(config)?,
+            java: codegen::java::Codegen::from_config(config)?,
+            go: codegen::go::Codegen::from_config(config)?,
+            rust: codegen::rust::Codegen::from_config(config)?,
+            python: codegen::python::Codegen::from_config(config)?,
+            kotlin: codegen::kotlin::Codegen::from_config(config)?,
+            csharp: codegen::csharp::Codegen::from_config(config)?,
+            swift: codegen::swift::Codegen::from_config(config)?,
+            dart: codegen::dart::Codegen::from_config(config)?,
+            protobuf: codegen::protobuf::Codegen::from_config(config)?,
+        })
+    }
+
+    // Generate code for a set of inputs, returning the contents of each file
+    // by path. Paths are under the output directories in the config.
+    //
+    // Nothing is read from an earlier run, so Protocol Buffers field numbers
+    // are given out afresh. Use `generate_with_locks` to keep them stable.
+    pub fn generate(&self, inputs: &[Input]) -> Result<BTreeMap<PathBuf, String>, Error> {
+        self.generate_with_locks(inputs, &BTreeMap::new())
+    }
+
+    // Generate code for a set of inputs, building on the lock files written
+    // along with the output of an earlier run. Locks are keyed by their path,
+    // as given by `lock_files`, and any which are missing are started afresh.
+    pub fn generate_with_locks(
+        &self,
+        inputs: &[Input],
+        locks: &BTreeMap<PathBuf, String>,
+    ) -> Result<BTreeMap<PathBuf, String>, Error> {
+        // Run each of the code generator transformation routines. If any fail,
+        // do not generate code.
+        let ts_asts = transform_all(&self.typescript, inputs)?;
+        let java_asts = transform_all(&self.java, inputs)?;
+        let go_asts = transform_all(&self.go, inputs)?;
+        let rust_asts = transform_all(&self.rust, inputs)?;
+        let python_asts = transform_all(&self.python, inputs)?;
+        let kotlin_asts = transform_all(&self.kotlin, inputs)?;
+        let csharp_asts = transform_all(&self.csharp, inputs)?;
+        let swift_asts = transform_all(&self.swift, inputs)?;
+        let dart_asts = transform_all(&self.dart, inputs)?;
+        let protobuf_asts = match self.protobuf {
+            Some(ref cg) => inputs
+                .iter()
+                .map(|input| cg.transform_with_locks(input, locks))
+                .collect::<Result<_, _>>()?,
+            None => Vec::new(),
+        };
+
+        let mut output = codegen::Output::default();
+        serialize_all(&self.typescript, &ts_asts, &mut output)?;
+        serialize_all(&self.java, &java_asts, &mut output)?;
+        serialize_all(&self.go, &go_asts, &mut output)?;
+        serialize_all(&self.rust, &rust_asts, &mut output)?;
+        serialize_all(&self.python, &python_asts, &mut output)?;
+        serialize_all(&self.kotlin, &kotlin_asts, &mut output)?;
+        serialize_all(&self.csharp, &csharp_asts, &mut output)?;
+        serialize_all(&self.swift, &swift_asts, &mut output)?;
+        serialize_all(&self.dart, &dart_asts, &mut output)?;
+        serialize_all(&self.protobuf, &protobuf_asts, &mut output)?;
+
+        Ok(output.into_files())
+    }
+
+    // The paths of the lock files generating code for a set of inputs would
+    // build on, whether or not they exist yet.
+    pub fn lock_files(&self, inputs: &[Input]) -> Vec<PathBuf> {
+        match self.protobuf {
+            Some(ref cg) => inputs.iter().map(|input| cg.lock_file(input)).collect(),
+            None => Vec::new(),
+        }
+    }
+
+    // Read back whichever of the lock files for a set of inputs exist. This is
+    // for the CLI and build scripts, which write their output to disk, and so
+    // are expected to find the lock files from their last run there too.
+    pub fn read_locks(&self, inputs: &[Input]) -> Result<BTreeMap<PathBuf, String>, Error> {
+        let mut locks = BTreeMap::new();
+        for path in self.lock_files(inputs) {
+            match fs::read_to_string(&path) {
+                Ok(contents) => {
+                    locks.insert(path, contents);
+                }
+                Err(ref err) if err.kind() == io::ErrorKind::NotFound => {}
+                Err(err) => return Err(format_err!("{}: {}", path.display(), err)),
+            }
+        }
+
+        Ok(locks)
+    }
+}
+
+// Generate code for a single schema, without reading or writing any files. The
+// name is what the schema's file would be called, and is used to name its root
+// type and the files generated for it. There's no lock file to build on, so
+// Protocol Buffers field numbers are given out afresh.
+pub fn generate(
+    name: &str,
+    schema: Schema,
+    config: &Config,
+) -> Result<BTreeMap<PathBuf, String>, Error> {
+    let input = Input {
+        name: name.to_owned(),
+        schema,
+        foreign: HashMap::new(),
+    };
+
+    Generator::from_config(config)?.generate(&[input])
+}
+
+fn transform_all<T: Codegen>(cg: &Option<T>, inputs: &[Input]) -> Result<Vec<T::Ast>, Error> {
+    match cg {
+        Some(cg) => inputs.iter().map(|input| cg.transform(input)).collect(),
+        None => Ok(Vec::new()),
+    }
+}
+
+fn serialize_all<T: Codegen>(
+    cg: &Option<T>,
+    asts: &[T::Ast],
+    output: &mut codegen::Output,
+) -> Result<(), Error> {
+    if let Some(cg) = cg {
+        for ast in asts {
+            cg.serialize(ast, output)?;
+        }
+    }
+
+    Ok(())
+}
@@ -1,10 +1,15 @@
-mod codegen;
-
-use crate::codegen::Codegen;
 use clap::{App, Arg};
-use failure::Error;
-use jsl::{Schema, SerdeSchema};
-use std::fs::File;
+use failure::{bail, Error};
+use jsl_codegen::config::{Collisions, Config};
+use jsl_codegen::{input, Generator};
+use notify::{DebouncedEvent, RecursiveMode, Watcher};
+use similar::TextDiff;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::mpsc;
+use std::time::Duration;
 
 fn main() -> Result<(), Error> {
     let app = App::new("jsl-codegen")
@@ -12,59 +17,188 @@ fn main() -> Result<(), Error> {
         .about("Generates code from a JSON Schema Language schema")
         .arg(
             Arg::with_name("INPUT")
-                .help("Input JSON Schema Language schema file")
+                .help("Input JSON Schema Language schema files, directories, or globs")
                 .last(true)
-                .required(true),
+                .multiple(true),
+        )
+        .arg(
+            Arg::with_name("config")
+                .help("Config file to read settings from [default: ./jsl-codegen.toml]")
+                .takes_value(true)
+                .long("config"),
+        )
+        .arg(
+            Arg::with_name("check")
+                .help("Check that generated files are up to date, instead of writing them")
+                .long("check"),
+        )
+        .arg(
+            Arg::with_name("watch")
+                .help("Keep running, and regenerate code whenever the input schemas change")
+                .long("watch")
+                .conflicts_with("check"),
+        )
+        .arg(
+            Arg::with_name("strict")
+                .help("Reject properties the schema does not describe")
+                .long("strict"),
+        )
+        .arg(
+            Arg::with_name("collisions")
+                .help("What to do when different schemas would get the same name")
+                .takes_value(true)
+                .possible_values(&["error", "rename"])
+                .long("collisions"),
         );
 
     // Set up the CLI for each of the code generators.
-    let app = codegen::typescript::Codegen::args(app);
-    let app = codegen::java::Codegen::args(app);
-    let app = codegen::go::Codegen::args(app);
+    let app = Generator::args(app);
 
     // Parse out the input args.
     let matches = app.get_matches();
 
+    // Settings on the command line override those in the config file.
+    let mut config = Config::find(matches.value_of("config"))?;
+    if matches.is_present("strict") {
+        config.strict = true;
+    }
+    match matches.value_of("collisions") {
+        Some("error") => config.collisions = Collisions::Error,
+        Some("rename") => config.collisions = Collisions::Rename,
+        _ => {}
+    }
+
     // Prepare the code generators from the input args.
-    let ts_codegen = codegen::typescript::Codegen::from_args(&matches)?;
-    let java_codegen = codegen::java::Codegen::from_args(&matches)?;
-    let go_codegen = codegen::go::Codegen::from_args(&matches)?;
-
-    // Parse out the input schema, and ensure it is valid.
-    let input = matches.value_of("INPUT").unwrap();
-    let file = File::open(input)?;
-    let serde_schema: SerdeSchema = serde_json::from_reader(file)?;
-    let schema = Schema::from_serde(serde_schema)?;
-
-    // Run each of the code generator transformation routines. If any fail, do
-    // not generate code.
-    let ts_ast = if let Some(ref cg) = ts_codegen {
-        Some(cg.transform(&schema)?)
-    } else {
-        None
-    };
-    let java_ast = if let Some(ref cg) = java_codegen {
-        Some(cg.transform(&schema)?)
-    } else {
-        None
-    };
-    let go_ast = if let Some(ref cg) = go_codegen {
-        Some(cg.transform(&schema)?)
-    } else {
-        None
+    let generator = Generator::from_args(&matches, &config)?;
+
+    // Parse out the input schemas, and ensure they are valid. Schemas may
+    // refer to definitions in one another, so they're all loaded together.
+    let args: Vec<_> = match matches.values_of("INPUT") {
+        Some(args) => args.collect(),
+        None => config.inputs.iter().map(String::as_str).collect(),
     };
 
-    // Serialize each of the ASTs. At this point, only IO errors can cause
-    // issues. That's sort of an inevitable state of affairs.
-    if let Some(ref cg) = ts_codegen {
-        cg.serialize(&ts_ast.unwrap())?;
+    if args.is_empty() {
+        bail!("No input schemas given, either as arguments or in a config file");
     }
-    if let Some(ref cg) = java_codegen {
-        cg.serialize(&java_ast.unwrap())?;
+
+    if matches.is_present("check") {
+        let inputs = input::load(&input::find(&args)?)?;
+        let locks = generator.read_locks(&inputs)?;
+        if !check(&generator.generate_with_locks(&inputs, &locks)?)? {
+            eprintln!("Generated code is out of date. Rerun jsl-codegen to update it.");
+            process::exit(1);
+        }
+
+        return Ok(());
+    }
+
+    if matches.is_present("watch") {
+        return watch(&generator, &args);
     }
-    if let Some(ref cg) = go_codegen {
-        cg.serialize(&go_ast.unwrap())?;
+
+    generate(&generator, &args)
+}
+
+fn generate(generator: &Generator, args: &[&str]) -> Result<(), Error> {
+    let inputs = input::load(&input::find(args)?)?;
+
+    // Generate all of the code before writing any of it out, so that an
+    // invalid schema doesn't leave output half-written. Lock files are read
+    // afresh each time, since in watch mode the last run will have replaced
+    // them.
+    let locks = generator.read_locks(&inputs)?;
+    for (path, contents) in generator.generate_with_locks(&inputs, &locks)? {
+        fs::write(path, contents)?;
     }
 
     Ok(())
 }
+
+// Regenerate code whenever any of the input schemas change, until the process
+// is interrupted. Errors are reported rather than ending the process, so that
+// a broken schema can be fixed without restarting.
+fn watch(generator: &Generator, args: &[&str]) -> Result<(), Error> {
+    let (tx, rx) = mpsc::channel();
+    let mut watcher = notify::watcher(tx, Duration::from_millis(100))?;
+
+    // Editors often save a file by replacing it, which would stop a watch on
+    // the file itself. Watching the directories schemas are in instead also
+    // picks up schemas being added to them.
+    let mut dirs = BTreeSet::new();
+    for arg in args {
+        if Path::new(arg).is_dir() {
+            dirs.insert(PathBuf::from(arg));
+        }
+    }
+
+    for path in input::find(args)? {
+        match path.parent() {
+            Some(parent) if parent != Path::new("") => dirs.insert(parent.to_owned()),
+            _ => dirs.insert(PathBuf::from(".")),
+        };
+    }
+
+    for dir in &dirs {
+        watcher.watch(dir, RecursiveMode::NonRecursive)?;
+    }
+
+    report(generate(generator, args));
+    loop {
+        let changed = match rx.recv()? {
+            DebouncedEvent::Create(path)
+            | DebouncedEvent::Write(path)
+            | DebouncedEvent::Remove(path) => is_schema(&path),
+            DebouncedEvent::Rename(from, to) => is_schema(&from) || is_schema(&to),
+            DebouncedEvent::Error(err, _) => {
+                eprintln!("Error watching schemas: {}", err);
+                false
+            }
+            _ => false,
+        };
+
+        if changed {
+            report(generate(generator, args));
+        }
+    }
+}
+
+fn is_schema(path: &Path) -> bool {
+    path.extension().and_then(|ext| ext.to_str()) == Some("json")
+}
+
+fn report(result: Result<(), Error>) {
+    match result {
+        Ok(()) => eprintln!("Generated code."),
+        Err(err) => eprintln!("Error: {}", err),
+    }
+}
+
+// Compare generated files against those on disk, printing a unified diff for
+// any that differ. Returns whether all of them were up to date.
+fn check(files: &BTreeMap<PathBuf, String>) -> Result<bool, Error> {
+    let mut up_to_date = true;
+    for (path, contents) in files {
+        // A file that doesn't exist yet is just as out of date as one with
+        // the wrong contents.
+        let existing = if path.exists() {
+            fs::read_to_string(path)?
+        } else {
+            String::new()
+        };
+
+        if existing != *contents {
+            up_to_date = false;
+
+            let name = path.display().to_string();
+            let diff = TextDiff::from_lines(&existing, contents);
+            print!(
+                "{}",
+                diff.unified_diff()
+                    .header(&format!("a/{}", name), &format!("b/{}", name))
+            );
+        }
+    }
+
+    Ok(up_to_date)
+}
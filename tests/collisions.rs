@@ -0,0 +1,262 @@
+// Tests for schemas whose generated names collide.
+
+use jsl::Schema;
+use jsl_codegen::config::{Collisions, Config};
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+fn config(collisions: Collisions) -> Config {
+    let mut config = Config::default();
+    config.typescript.out = Some("typescript".to_owned());
+    config.collisions = collisions;
+    config
+}
+
+fn generate(schema: serde_json::Value, config: &Config) -> Result<String, String> {
+    let schema = Schema::from_serde(serde_json::from_value(schema).unwrap()).unwrap();
+    jsl_codegen::generate("user", schema, config)
+        .map(|files| files[Path::new("typescript/User.ts")].clone())
+        .map_err(|err| err.to_string())
+}
+
+// A definition `user_name`, and the property `name` of a definition `user`.
+fn colliding_schema() -> serde_json::Value {
+    json!({
+        "definitions": {
+            "user": {
+                "properties": {
+                    "name": {
+                        "properties": {
+                            "first": { "type": "string" }
+                        }
+                    }
+                }
+            },
+            "user_name": { "type": "string" }
+        },
+        "properties": {
+            "user": { "ref": "user" },
+            "name": { "ref": "user_name" }
+        }
+    })
+}
+
+#[test]
+fn collisions_are_an_error_by_default() {
+    let err = generate(colliding_schema(), &config(Collisions::Error)).unwrap_err();
+    assert_eq!(
+        err,
+        "user: #/definitions/user_name and #/definitions/user/properties/name are both named UserName"
+    );
+}
+
+#[test]
+fn collisions_can_be_renamed() {
+    let code = generate(colliding_schema(), &config(Collisions::Rename)).unwrap();
+    assert!(code.contains("export type UserName = string;"));
+    assert!(code.contains("export interface UserName2 {"));
+    assert!(code.contains("name: UserName2;"));
+    assert!(code.contains("name: UserName;"));
+}
+
+#[test]
+fn colliding_definitions_are_never_renamed() {
+    let schema = json!({
+        "definitions": {
+            "user_name": { "type": "string" },
+            "userName": { "type": "string" }
+        }
+    });
+
+    let err = generate(schema, &config(Collisions::Rename)).unwrap_err();
+    assert_eq!(
+        err,
+        "user: #/definitions/userName and #/definitions/user_name are both named UserName, \
+         and definitions can't be renamed"
+    );
+}
+
+#[test]
+fn renamed_types_may_not_collide() {
+    let schema = json!({
+        "definitions": {
+            "id": { "type": "string" },
+            "key": { "type": "string" }
+        }
+    });
+
+    let mut config = config(Collisions::Rename);
+    config.names.insert("Key".to_owned(), "Id".to_owned());
+
+    let err = generate(schema, &config).unwrap_err();
+    assert_eq!(
+        err,
+        "user: #/definitions/id and #/definitions/key are both named Id"
+    );
+}
+
+#[test]
+fn chosen_names_are_never_renamed() {
+    let schema = json!({
+        "definitions": {
+            "id": { "type": "string" }
+        },
+        "properties": {
+            "key": {
+                "metadata": { "name": "Id" },
+                "properties": {}
+            }
+        }
+    });
+
+    let err = generate(schema, &config(Collisions::Rename)).unwrap_err();
+    assert_eq!(
+        err,
+        "user: #/definitions/id and #/properties/key are both named Id, \
+         and chosen names can't be changed"
+    );
+}
+
+// Fields and enum members only need names unique within their record or enum,
+// but those names are made by each target, so they're checked for each.
+fn colliding_members_schema() -> serde_json::Value {
+    json!({
+        "properties": {
+            "foo_bar": { "type": "string" },
+            "fooBar": { "type": "number" },
+            "e": { "enum": ["a-b", "a_b"] }
+        }
+    })
+}
+
+fn generate_all(
+    schema: serde_json::Value,
+    collisions: Collisions,
+) -> Result<BTreeMap<PathBuf, String>, String> {
+    let mut config = Config::default();
+    config.go.out = Some("go".to_owned());
+    config.rust.out = Some("rust".to_owned());
+    config.python.out = Some("python".to_owned());
+    config.swift.out = Some("swift".to_owned());
+    config.protobuf.out = Some("protobuf".to_owned());
+    config.collisions = collisions;
+
+    let schema = Schema::from_serde(serde_json::from_value(schema).unwrap()).unwrap();
+    jsl_codegen::generate("user", schema, &config).map_err(|err| err.to_string())
+}
+
+#[test]
+fn colliding_fields_are_an_error_by_default() {
+    let schema = json!({
+        "properties": {
+            "foo_bar": { "type": "string" },
+            "fooBar": { "type": "number" }
+        }
+    });
+
+    let err = generate_all(schema, Collisions::Error).unwrap_err();
+    assert_eq!(
+        err,
+        "user: #/properties/fooBar and #/properties/foo_bar are both named FooBar"
+    );
+}
+
+#[test]
+fn colliding_enum_members_are_an_error_by_default() {
+    let schema = json!({
+        "properties": {
+            "e": { "enum": ["a-b", "a_b"] }
+        }
+    });
+
+    let err = generate_all(schema, Collisions::Error).unwrap_err();
+    assert_eq!(
+        err,
+        "user: #/properties/e/enum value \"a-b\" and #/properties/e/enum value \"a_b\" \
+         are both named UserEAB"
+    );
+}
+
+#[test]
+fn colliding_fields_and_enum_members_can_be_renamed() {
+    let files = generate_all(colliding_members_schema(), Collisions::Rename).unwrap();
+
+    let go = &files[Path::new("go/user.go")];
+    assert!(go.contains("FooBar float64 `json:\"fooBar\"`"));
+    assert!(go.contains("FooBar2 string `json:\"foo_bar\"`"));
+    assert!(go.contains("UserEAB UserE = \"a-b\""));
+    assert!(go.contains("UserEAB2 UserE = \"a_b\""));
+
+    let rust = &files[Path::new("rust/user.rs")];
+    assert!(rust.contains("pub foo_bar: f64,"));
+    assert!(rust.contains("pub foo_bar2: String,"));
+    assert!(rust.contains("    AB,"));
+    assert!(rust.contains("    AB2,"));
+
+    let python = &files[Path::new("python/user.py")];
+    assert!(python.contains("foo_bar: float"));
+    assert!(python.contains("foo_bar2: str"));
+    assert!(python.contains("A_B = \"a-b\""));
+    assert!(python.contains("A_B2 = \"a_b\""));
+}
+
+#[test]
+fn chosen_field_names_are_never_renamed() {
+    let schema = json!({
+        "properties": {
+            "foo_bar": { "type": "string" },
+            "foo": {
+                "metadata": { "fieldName": "foo_bar" },
+                "type": "string"
+            }
+        }
+    });
+
+    let files = generate_all(schema, Collisions::Rename).unwrap();
+    let rust = &files[Path::new("rust/user.rs")];
+    assert!(rust.contains("    pub foo_bar: String,"));
+    assert!(rust.contains("    pub foo_bar2: String,"));
+    assert!(rust.contains("#[serde(rename = \"foo_bar\")]\n    pub foo_bar2"));
+}
+
+// Rust, Swift and Protocol Buffers name the cases of a discriminator after
+// their tag values. The cases here choose names for their types, so it's only
+// those identifiers which collide.
+fn colliding_tags_schema() -> serde_json::Value {
+    json!({
+        "discriminator": {
+            "tag": "t",
+            "mapping": {
+                "a-b": { "metadata": { "name": "First" }, "properties": {} },
+                "a_b": { "metadata": { "name": "Second" }, "properties": {} }
+            }
+        }
+    })
+}
+
+#[test]
+fn colliding_tag_values_are_an_error_by_default() {
+    let err = generate_all(colliding_tags_schema(), Collisions::Error).unwrap_err();
+    assert_eq!(
+        err,
+        "user: #/discriminator/mapping/a-b and #/discriminator/mapping/a_b are both named AB"
+    );
+}
+
+#[test]
+fn colliding_tag_values_can_be_renamed() {
+    let files = generate_all(colliding_tags_schema(), Collisions::Rename).unwrap();
+
+    let rust = &files[Path::new("rust/user.rs")];
+    assert!(rust.contains("    #[serde(rename = \"a-b\")]\n    AB(First),"));
+    assert!(rust.contains("    #[serde(rename = \"a_b\")]\n    AB2(Second),"));
+
+    let swift = &files[Path::new("swift/User.swift")];
+    assert!(swift.contains("    case aB(First)"));
+    assert!(swift.contains("    case aB2(Second)"));
+
+    let protobuf = &files[Path::new("protobuf/user.proto")];
+    assert!(protobuf.contains("First a_b = 1 [json_name = \"a-b\"];"));
+    assert!(protobuf.contains("Second a_b2 = 2 [json_name = \"a_b\"];"));
+}
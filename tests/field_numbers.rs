@@ -0,0 +1,171 @@
+// Tests for keeping Protocol Buffers field numbers stable as schemas change.
+
+use jsl::Schema;
+use jsl_codegen::config::Config;
+use jsl_codegen::input::Input;
+use jsl_codegen::Generator;
+use serde_json::json;
+use std::collections::{BTreeMap, HashMap};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Generate the .proto file for a schema, building on the lock files from the
+// last run, and updating them for the next, just as the CLI would on disk.
+fn generate(
+    locks: &mut BTreeMap<PathBuf, String>,
+    schema: serde_json::Value,
+) -> Result<String, String> {
+    let mut config = Config::default();
+    config.protobuf.out = Some("protobuf".to_owned());
+
+    let input = Input {
+        name: "user".to_owned(),
+        schema: Schema::from_serde(serde_json::from_value(schema).unwrap()).unwrap(),
+        foreign: HashMap::new(),
+    };
+
+    let generator = Generator::from_config(&config).unwrap();
+    let mut files = generator
+        .generate_with_locks(&[input], locks)
+        .map_err(|err| err.to_string())?;
+
+    let lock = Path::new("protobuf/user.proto.lock");
+    locks.insert(lock.to_owned(), files.remove(lock).unwrap());
+    Ok(files.remove(Path::new("protobuf/user.proto")).unwrap())
+}
+
+#[test]
+fn fields_keep_their_numbers() {
+    let mut locks = BTreeMap::new();
+    let proto = generate(
+        &mut locks,
+        json!({
+            "properties": {
+                "a": { "type": "string" },
+                "b": { "type": "string" },
+                "c": { "type": "string" }
+            }
+        }),
+    )
+    .unwrap();
+    assert!(proto.contains("string b = 2;"));
+
+    // Removing a field retires its number, and new fields are numbered after
+    // every number given out so far.
+    let proto = generate(
+        &mut locks,
+        json!({
+            "properties": {
+                "a": { "type": "string" },
+                "c": { "type": "string" },
+                "d": { "type": "string" }
+            }
+        }),
+    )
+    .unwrap();
+    assert!(proto.contains("string a = 1;"));
+    assert!(proto.contains("string c = 3;"));
+    assert!(proto.contains("string d = 4;"));
+    assert!(proto.contains("reserved 2;"));
+
+    // A field added back under its old name gets its old number back.
+    let proto = generate(
+        &mut locks,
+        json!({
+            "properties": {
+                "a": { "type": "string" },
+                "b": { "type": "string" },
+                "c": { "type": "string" },
+                "d": { "type": "string" }
+            }
+        }),
+    )
+    .unwrap();
+    assert!(proto.contains("string b = 2;"));
+    assert!(!proto.contains("reserved"));
+}
+
+#[test]
+fn enum_values_and_cases_keep_their_numbers() {
+    let mut locks = BTreeMap::new();
+    generate(
+        &mut locks,
+        json!({
+            "properties": {
+                "status": { "enum": ["active", "inactive"] },
+                "event": {
+                    "discriminator": {
+                        "tag": "type",
+                        "mapping": {
+                            "created": { "properties": {} },
+                            "deleted": { "properties": {} }
+                        }
+                    }
+                }
+            }
+        }),
+    )
+    .unwrap();
+
+    let proto = generate(
+        &mut locks,
+        json!({
+            "properties": {
+                "status": { "enum": ["inactive", "suspended"] },
+                "event": {
+                    "discriminator": {
+                        "tag": "type",
+                        "mapping": {
+                            "deleted": { "properties": {} },
+                            "updated": { "properties": {} }
+                        }
+                    }
+                }
+            }
+        }),
+    )
+    .unwrap();
+    assert!(proto.contains("USER_STATUS_INACTIVE = 2;"));
+    assert!(proto.contains("USER_STATUS_SUSPENDED = 3;"));
+    assert!(proto.contains("UserEventDeleted deleted = 2;"));
+    assert!(proto.contains("UserEventUpdated updated = 3;"));
+    assert_eq!(proto.matches("reserved 1;").count(), 2);
+}
+
+#[test]
+fn invalid_lock_files_are_an_error() {
+    let mut locks = BTreeMap::new();
+    locks.insert(PathBuf::from("protobuf/user.proto.lock"), "{".to_owned());
+
+    let err = generate(&mut locks, json!({})).unwrap_err();
+    assert!(err.starts_with("protobuf/user.proto.lock: "));
+}
+
+#[test]
+fn lock_files_are_read_back_from_the_output_directory() {
+    let dir = env::temp_dir().join(format!("jsl-codegen-locks-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("user.proto.lock"), "{}\n").unwrap();
+
+    let mut config = Config::default();
+    config.protobuf.out = Some(dir.to_str().unwrap().to_owned());
+
+    let inputs = ["user", "order"].iter().map(|name| Input {
+        name: name.to_string(),
+        schema: Schema::from_serde(serde_json::from_value(json!({})).unwrap()).unwrap(),
+        foreign: HashMap::new(),
+    });
+
+    // Only the lock files which exist are read back.
+    let locks = Generator::from_config(&config)
+        .unwrap()
+        .read_locks(&inputs.collect::<Vec<_>>())
+        .unwrap();
+    assert_eq!(
+        locks.keys().collect::<Vec<_>>(),
+        [&dir.join("user.proto.lock")]
+    );
+
+    fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,377 @@
+// Golden-file tests. Code is generated for every case in SCHEMA_DIRS, for every
+// target, and compared against the expected output checked in under
+// tests/golden/<case>. Each schema file is a case of its own, and each
+// subdirectory is a case whose schemas are generated together, so that they
+// can refer to one another.
+//
+// To update the expected output after an intentional change, or to add a new
+// case, run:
+//
+//     UPDATE_GOLDEN=1 cargo test --test golden
+//
+// Updating checks that the new output compiles before writing it out, using
+// whichever of tsc, go, javac, cargo, python3 and protoc are installed, so that
+// broken output doesn't get checked in. To check the output compiles without
+// updating it, run:
+//
+//     JSL_CODEGEN_COMPILE=1 cargo test --test golden
+//
+// javac needs Jackson to compile against, so Java is only checked if
+// JSL_CODEGEN_JAVA_CLASSPATH is set to a classpath which provides it. Rust is
+// checked as a crate depending on serde, serde_json and chrono, so cargo needs
+// to be able to fetch those. Python is checked by importing it, which catches
+// more than compiling it would, such as enums with duplicate members.
+//
+// Kotlin, C#, Swift and Dart output is not compiled by any of this, and is
+// only ever compared against the golden files.
+
+use jsl_codegen::config::Config;
+use jsl_codegen::{input, Generator};
+use similar::TextDiff;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const SCHEMA_DIRS: &[&str] = &["examples", "tests/schemas"];
+const GOLDEN_DIR: &str = "tests/golden";
+
+fn config() -> Config {
+    let mut config = Config::default();
+    config.typescript.out = Some("typescript".to_owned());
+    config.java.out = Some("java".to_owned());
+    config.java.package = Some("com.example".to_owned());
+    config.go.out = Some("go/models".to_owned());
+    config.rust.out = Some("rust".to_owned());
+    config.python.out = Some("python".to_owned());
+    config.kotlin.out = Some("kotlin".to_owned());
+    config.kotlin.package = Some("com.example".to_owned());
+    config.csharp.out = Some("csharp".to_owned());
+    config.csharp.package = Some("Example".to_owned());
+    config.swift.out = Some("swift".to_owned());
+    config.dart.out = Some("dart".to_owned());
+    config.protobuf.out = Some("protobuf".to_owned());
+    config.protobuf.package = Some("example".to_owned());
+    config
+}
+
+// Each case is named, and has the schema files to generate code from together.
+fn cases() -> Vec<(String, Vec<PathBuf>)> {
+    let mut cases = Vec::new();
+    for dir in SCHEMA_DIRS {
+        for path in input::find(&[dir]).unwrap() {
+            let name = path.file_stem().unwrap().to_string_lossy().into_owned();
+            cases.push((name, vec![path]));
+        }
+
+        let mut subdirs: Vec<_> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.is_dir())
+            .collect();
+        subdirs.sort();
+
+        for subdir in subdirs {
+            let name = subdir.file_name().unwrap().to_string_lossy().into_owned();
+            let paths = input::find(&[subdir.to_str().unwrap()]).unwrap();
+            cases.push((name, paths));
+        }
+    }
+
+    cases
+}
+
+fn generate(paths: &[PathBuf]) -> BTreeMap<PathBuf, String> {
+    let inputs = input::load(paths).unwrap();
+    Generator::from_config(&config())
+        .unwrap()
+        .generate(&inputs)
+        .unwrap()
+}
+
+// Read every file under a directory, keyed by their path relative to it.
+fn read_dir(root: &Path, dir: &Path, out: &mut BTreeMap<PathBuf, String>) {
+    for entry in fs::read_dir(dir).unwrap() {
+        let path = entry.unwrap().path();
+        if path.is_dir() {
+            read_dir(root, &path, out);
+        } else {
+            let contents = fs::read_to_string(&path).unwrap();
+            out.insert(path.strip_prefix(root).unwrap().to_owned(), contents);
+        }
+    }
+}
+
+#[test]
+fn output_is_deterministic() {
+    for (name, paths) in cases() {
+        let first = generate(&paths);
+        for _ in 0..10 {
+            assert!(
+                first == generate(&paths),
+                "output for {} changed between runs",
+                name
+            );
+        }
+    }
+}
+
+#[test]
+fn output_matches_golden_files() {
+    let update = env::var_os("UPDATE_GOLDEN").is_some();
+
+    for (name, paths) in cases() {
+        let golden_dir = Path::new(GOLDEN_DIR).join(&name);
+        let actual = generate(&paths);
+
+        if update {
+            compile(&name, &actual);
+            if golden_dir.exists() {
+                fs::remove_dir_all(&golden_dir).unwrap();
+            }
+
+            for (path, contents) in &actual {
+                let path = golden_dir.join(path);
+                fs::create_dir_all(path.parent().unwrap()).unwrap();
+                fs::write(path, contents).unwrap();
+            }
+
+            continue;
+        }
+
+        let mut expected = BTreeMap::new();
+        if golden_dir.exists() {
+            read_dir(&golden_dir, &golden_dir, &mut expected);
+        }
+
+        assert_eq!(
+            expected.keys().collect::<Vec<_>>(),
+            actual.keys().collect::<Vec<_>>(),
+            "files generated for {} differ from {}",
+            name,
+            golden_dir.display()
+        );
+
+        for (path, contents) in &actual {
+            let expected = &expected[path];
+            if expected != contents {
+                let diff = TextDiff::from_lines(expected, contents);
+                panic!(
+                    "{} differs from the expected output:\n{}",
+                    golden_dir.join(path).display(),
+                    diff.unified_diff()
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn output_compiles() {
+    if env::var_os("JSL_CODEGEN_COMPILE").is_none() {
+        return;
+    }
+
+    for (name, paths) in cases() {
+        compile(&name, &generate(&paths));
+    }
+}
+
+// Check that the output for a case compiles, for each target whose compiler is
+// installed.
+fn compile(name: &str, output: &BTreeMap<PathBuf, String>) {
+    let dir = env::temp_dir().join(format!("jsl-codegen-{}-{}", std::process::id(), name));
+    if dir.exists() {
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    for (path, contents) in output {
+        let path = dir.join(path);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    compile_typescript(name, &dir.join("typescript"));
+    compile_go(name, &dir.join("go"));
+    compile_java(name, &dir.join("java"));
+    compile_rust(name, &dir.join("rust"));
+    compile_python(name, &dir.join("python"));
+    compile_protobuf(name, &dir.join("protobuf"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+fn compile_typescript(name: &str, dir: &Path) {
+    if !installed("tsc", "--version") {
+        eprintln!("tsc not found, not compiling TypeScript for {}", name);
+        return;
+    }
+
+    let mut command = Command::new("tsc");
+    command.args(["--noEmit", "--strict", "--target", "es2017"]);
+    command.args(files(dir, "ts"));
+    run(name, &mut command);
+}
+
+fn compile_go(name: &str, dir: &Path) {
+    if !installed("go", "version") {
+        eprintln!("go not found, not compiling Go for {}", name);
+        return;
+    }
+
+    fs::write(dir.join("go.mod"), "module example.com/golden\n\ngo 1.12\n").unwrap();
+
+    let mut command = Command::new("go");
+    command.args(["build", "./..."]).current_dir(dir);
+    run(name, &mut command);
+}
+
+fn compile_java(name: &str, dir: &Path) {
+    if !installed("javac", "-version") {
+        eprintln!("javac not found, not compiling Java for {}", name);
+        return;
+    }
+
+    let classpath = match env::var("JSL_CODEGEN_JAVA_CLASSPATH") {
+        Ok(classpath) => classpath,
+        Err(_) => {
+            eprintln!(
+                "JSL_CODEGEN_JAVA_CLASSPATH not set, not compiling Java for {}",
+                name
+            );
+            return;
+        }
+    };
+
+    let mut command = Command::new("javac");
+    command
+        .args(["-encoding", "UTF-8"])
+        .arg("-classpath")
+        .arg(classpath)
+        .arg("-d")
+        .arg(dir.join("classes"));
+    command.args(files(&dir.join("com/example"), "java"));
+    run(name, &mut command);
+}
+
+fn compile_rust(name: &str, dir: &Path) {
+    if !installed("cargo", "--version") {
+        eprintln!("cargo not found, not compiling Rust for {}", name);
+        return;
+    }
+
+    // The output for each input is a module, and they're siblings of one
+    // another.
+    let mut lib = String::new();
+    for path in files(dir, "rs") {
+        let module = path.file_stem().unwrap().to_str().unwrap();
+        lib.push_str(&format!("pub mod {};\n", module));
+    }
+
+    fs::create_dir_all(dir.join("src")).unwrap();
+    for path in files(dir, "rs") {
+        fs::rename(&path, dir.join("src").join(path.file_name().unwrap())).unwrap();
+    }
+
+    fs::write(dir.join("src/lib.rs"), lib).unwrap();
+    fs::write(
+        dir.join("Cargo.toml"),
+        "[package]\nname = \"golden\"\nversion = \"0.0.0\"\nedition = \"2018\"\n\n\
+         [dependencies]\n\
+         chrono = { version = \"0.4\", features = [\"serde\"] }\n\
+         serde = { version = \"1.0\", features = [\"derive\"] }\n\
+         serde_json = \"1.0\"\n",
+    )
+    .unwrap();
+
+    // Every case has the same dependencies, so they share a target directory
+    // to keep from building those over and over.
+    let mut command = Command::new("cargo");
+    command
+        .args(["check", "--quiet"])
+        .env(
+            "CARGO_TARGET_DIR",
+            env::temp_dir().join("jsl-codegen-golden-rust"),
+        )
+        .current_dir(dir);
+    run(name, &mut command);
+}
+
+fn compile_python(name: &str, dir: &Path) {
+    if !installed("python3", "--version") {
+        eprintln!("python3 not found, not checking Python for {}", name);
+        return;
+    }
+
+    // Modules import one another relative to the package they're in.
+    fs::write(dir.join("__init__.py"), "").unwrap();
+    let modules: Vec<_> = files(dir, "py")
+        .iter()
+        .map(|path| path.file_stem().unwrap().to_str().unwrap().to_owned())
+        .filter(|module| module != "__init__")
+        .map(|module| format!("python.{}", module))
+        .collect();
+
+    let mut command = Command::new("python3");
+    command
+        .arg("-c")
+        .arg(format!("import {}", modules.join(", ")))
+        .env("PYTHONDONTWRITEBYTECODE", "1")
+        .current_dir(dir.parent().unwrap());
+    run(name, &mut command);
+}
+
+fn compile_protobuf(name: &str, dir: &Path) {
+    if !installed("protoc", "--version") {
+        eprintln!(
+            "protoc not found, not compiling Protocol Buffers for {}",
+            name
+        );
+        return;
+    }
+
+    // Files import one another by their paths relative to the output
+    // directory.
+    let mut command = Command::new("protoc");
+    command
+        .arg("--proto_path=.")
+        .arg(format!(
+            "--descriptor_set_out={}",
+            dir.join("descriptors.pb").display()
+        ))
+        .args(
+            files(dir, "proto")
+                .iter()
+                .map(|path| path.file_name().unwrap()),
+        )
+        .current_dir(dir);
+    run(name, &mut command);
+}
+
+fn installed(program: &str, version_arg: &str) -> bool {
+    Command::new(program).arg(version_arg).output().is_ok()
+}
+
+// The files directly in a directory with a given extension, in order.
+fn files(dir: &Path, extension: &str) -> Vec<PathBuf> {
+    let mut files: Vec<_> = fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().and_then(|ext| ext.to_str()) == Some(extension))
+        .collect();
+    files.sort();
+    files
+}
+
+fn run(name: &str, command: &mut Command) {
+    let output = command.output().unwrap();
+    assert!(
+        output.status.success(),
+        "generated code for {} does not compile: {:?}\n{}{}",
+        name,
+        command,
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
@@ -0,0 +1,61 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Example;
+
+public sealed record CollectionsPoints
+{
+    [JsonPropertyName("x")]
+    public required double X { get; init; }
+
+    [JsonPropertyName("y")]
+    public required double Y { get; init; }
+}
+
+[JsonConverter(typeof(JsonStringEnumConverter<CollectionsStatuses>))]
+public enum CollectionsStatuses
+{
+    [JsonStringEnumMemberName("active")]
+    Active,
+    [JsonStringEnumMemberName("inactive")]
+    Inactive,
+}
+
+public sealed record Collections
+{
+    [JsonPropertyName("anything")]
+    public required List<JsonElement> Anything { get; init; }
+
+    [JsonPropertyName("counts")]
+    public required Dictionary<string, Dictionary<string, double>> Counts { get; init; }
+
+    [JsonPropertyName("labels")]
+    public required Dictionary<string, string> Labels { get; init; }
+
+    [JsonPropertyName("matrix")]
+    public required List<List<double>> Matrix { get; init; }
+
+    [JsonPropertyName("points")]
+    public required List<CollectionsPoints> Points { get; init; }
+
+    [JsonPropertyName("statuses")]
+    public required Dictionary<string, CollectionsStatuses> Statuses { get; init; }
+
+    [JsonPropertyName("strings")]
+    public required List<string> Strings { get; init; }
+
+    [JsonPropertyName("timestamps")]
+    public required List<DateTimeOffset> Timestamps { get; init; }
+
+    [JsonPropertyName("optionalLabels")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Dictionary<string, string>? OptionalLabels { get; init; }
+
+    [JsonPropertyName("optionalStrings")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public List<string>? OptionalStrings { get; init; }
+}
@@ -0,0 +1,97 @@
+class CollectionsPoints {
+  final double x;
+  final double y;
+
+  const CollectionsPoints({
+    required this.x,
+    required this.y,
+  });
+
+  factory CollectionsPoints.fromJson(Map<String, dynamic> json) {
+    return CollectionsPoints(
+      x: (json["x"] as num).toDouble(),
+      y: (json["y"] as num).toDouble(),
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "x": x,
+      "y": y,
+    };
+  }
+}
+
+enum CollectionsStatuses {
+  active("active"),
+  inactive("inactive");
+
+  const CollectionsStatuses(this.value);
+
+  final String value;
+
+  factory CollectionsStatuses.fromJson(String json) {
+    return values.firstWhere(
+      (value) => value.value == json,
+      orElse: () => throw FormatException("unknown CollectionsStatuses value", json),
+    );
+  }
+
+  String toJson() => value;
+}
+
+class Collections {
+  final List<Object?> anything;
+  final Map<String, Map<String, double>> counts;
+  final Map<String, String> labels;
+  final List<List<double>> matrix;
+  final List<CollectionsPoints> points;
+  final Map<String, CollectionsStatuses> statuses;
+  final List<String> strings;
+  final List<DateTime> timestamps;
+  final Map<String, String>? optionalLabels;
+  final List<String>? optionalStrings;
+
+  const Collections({
+    required this.anything,
+    required this.counts,
+    required this.labels,
+    required this.matrix,
+    required this.points,
+    required this.statuses,
+    required this.strings,
+    required this.timestamps,
+    this.optionalLabels,
+    this.optionalStrings,
+  });
+
+  factory Collections.fromJson(Map<String, dynamic> json) {
+    return Collections(
+      anything: json["anything"] as List<Object?>,
+      counts: (json["counts"] as Map<String, dynamic>).map((k0, v0) => MapEntry(k0, (v0 as Map<String, dynamic>).map((k1, v1) => MapEntry(k1, (v1 as num).toDouble())))),
+      labels: (json["labels"] as Map<String, dynamic>).map((k0, v0) => MapEntry(k0, v0 as String)),
+      matrix: (json["matrix"] as List<dynamic>).map((v0) => (v0 as List<dynamic>).map((v1) => (v1 as num).toDouble()).toList()).toList(),
+      points: (json["points"] as List<dynamic>).map((v0) => CollectionsPoints.fromJson(v0 as Map<String, dynamic>)).toList(),
+      statuses: (json["statuses"] as Map<String, dynamic>).map((k0, v0) => MapEntry(k0, CollectionsStatuses.fromJson(v0 as String))),
+      strings: (json["strings"] as List<dynamic>).map((v0) => v0 as String).toList(),
+      timestamps: (json["timestamps"] as List<dynamic>).map((v0) => DateTime.parse(v0 as String)).toList(),
+      optionalLabels: json["optionalLabels"] == null ? null : (json["optionalLabels"] as Map<String, dynamic>).map((k0, v0) => MapEntry(k0, v0 as String)),
+      optionalStrings: json["optionalStrings"] == null ? null : (json["optionalStrings"] as List<dynamic>).map((v0) => v0 as String).toList(),
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "anything": anything,
+      "counts": counts,
+      "labels": labels,
+      "matrix": matrix,
+      "points": points.map((v0) => v0.toJson()).toList(),
+      "statuses": statuses.map((k0, v0) => MapEntry(k0, v0.toJson())),
+      "strings": strings,
+      "timestamps": timestamps.map((v0) => v0.toUtc().toIso8601String()).toList(),
+      if (optionalLabels != null) "optionalLabels": optionalLabels,
+      if (optionalStrings != null) "optionalStrings": optionalStrings,
+    };
+  }
+}
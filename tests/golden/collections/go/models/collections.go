@@ -0,0 +1,255 @@
+package models
+
+import (
+	"encoding/json"
+	"strconv"
+	"time"
+)
+
+type CollectionsPoints struct {
+	X float64 `json:"x"`
+	Y float64 `json:"y"`
+}
+
+func ValidateCollectionsPoints(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateCollectionsPoints(instance, nil, &errors)
+	return errors
+}
+
+func validateCollectionsPoints(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/points/elements/properties"})
+		return
+	}
+	if v0, ok := obj["x"]; ok {
+		if _, ok := v0.(float64); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "x")), SchemaPath: "/properties/points/elements/properties/x/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/points/elements/properties/x"})
+	}
+	if v0, ok := obj["y"]; ok {
+		if _, ok := v0.(float64); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "y")), SchemaPath: "/properties/points/elements/properties/y/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/points/elements/properties/y"})
+	}
+}
+
+func DecodeCollectionsPoints(data []byte) (CollectionsPoints, error) {
+	var out CollectionsPoints
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateCollectionsPoints(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type CollectionsStatuses string
+
+const (
+	CollectionsStatusesActive CollectionsStatuses = "active"
+	CollectionsStatusesInactive CollectionsStatuses = "inactive"
+)
+
+func ValidateCollectionsStatuses(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateCollectionsStatuses(instance, nil, &errors)
+	return errors
+}
+
+func validateCollectionsStatuses(instance interface{}, path []string, errors *[]ValidationError) {
+	switch instance {
+	case "active", "inactive":
+	default:
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/statuses/values/enum"})
+	}
+}
+
+func DecodeCollectionsStatuses(data []byte) (CollectionsStatuses, error) {
+	var out CollectionsStatuses
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateCollectionsStatuses(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type Collections struct {
+	Anything []interface{} `json:"anything"`
+	Counts map[string]map[string]float64 `json:"counts"`
+	Labels map[string]string `json:"labels"`
+	Matrix [][]float64 `json:"matrix"`
+	Points []CollectionsPoints `json:"points"`
+	Statuses map[string]CollectionsStatuses `json:"statuses"`
+	Strings []string `json:"strings"`
+	Timestamps []time.Time `json:"timestamps"`
+	OptionalLabels map[string]string `json:"optionalLabels,omitempty"`
+	OptionalStrings []string `json:"optionalStrings,omitempty"`
+}
+
+func ValidateCollections(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateCollections(instance, nil, &errors)
+	return errors
+}
+
+func validateCollections(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties"})
+		return
+	}
+	if v0, ok := obj["anything"]; ok {
+		if _, ok := v0.([]interface{}); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "anything")), SchemaPath: "/properties/anything/elements"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/anything"})
+	}
+	if v0, ok := obj["counts"]; ok {
+		if o0, ok := v0.(map[string]interface{}); ok {
+			for k0, v1 := range o0 {
+				if o1, ok := v1.(map[string]interface{}); ok {
+					for k1, v2 := range o1 {
+						if _, ok := v2.(float64); !ok {
+							*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "counts", k0, k1)), SchemaPath: "/properties/counts/values/values/type"})
+						}
+					}
+				} else {
+					*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "counts", k0)), SchemaPath: "/properties/counts/values/values"})
+				}
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "counts")), SchemaPath: "/properties/counts/values"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/counts"})
+	}
+	if v0, ok := obj["labels"]; ok {
+		if o0, ok := v0.(map[string]interface{}); ok {
+			for k0, v1 := range o0 {
+				if _, ok := v1.(string); !ok {
+					*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "labels", k0)), SchemaPath: "/properties/labels/values/type"})
+				}
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "labels")), SchemaPath: "/properties/labels/values"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/labels"})
+	}
+	if v0, ok := obj["matrix"]; ok {
+		if a0, ok := v0.([]interface{}); ok {
+			for i0, v1 := range a0 {
+				if a1, ok := v1.([]interface{}); ok {
+					for i1, v2 := range a1 {
+						if _, ok := v2.(float64); !ok {
+							*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "matrix", strconv.Itoa(i0), strconv.Itoa(i1))), SchemaPath: "/properties/matrix/elements/elements/type"})
+						}
+					}
+				} else {
+					*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "matrix", strconv.Itoa(i0))), SchemaPath: "/properties/matrix/elements/elements"})
+				}
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "matrix")), SchemaPath: "/properties/matrix/elements"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/matrix"})
+	}
+	if v0, ok := obj["points"]; ok {
+		if a0, ok := v0.([]interface{}); ok {
+			for i0, v1 := range a0 {
+				validateCollectionsPoints(v1, jslAppend(path, "points", strconv.Itoa(i0)), errors)
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "points")), SchemaPath: "/properties/points/elements"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/points"})
+	}
+	if v0, ok := obj["statuses"]; ok {
+		if o0, ok := v0.(map[string]interface{}); ok {
+			for k0, v1 := range o0 {
+				validateCollectionsStatuses(v1, jslAppend(path, "statuses", k0), errors)
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "statuses")), SchemaPath: "/properties/statuses/values"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/statuses"})
+	}
+	if v0, ok := obj["strings"]; ok {
+		if a0, ok := v0.([]interface{}); ok {
+			for i0, v1 := range a0 {
+				if _, ok := v1.(string); !ok {
+					*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "strings", strconv.Itoa(i0))), SchemaPath: "/properties/strings/elements/type"})
+				}
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "strings")), SchemaPath: "/properties/strings/elements"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/strings"})
+	}
+	if v0, ok := obj["timestamps"]; ok {
+		if a0, ok := v0.([]interface{}); ok {
+			for i0, v1 := range a0 {
+				if !jslIsTimestamp(v1) {
+					*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "timestamps", strconv.Itoa(i0))), SchemaPath: "/properties/timestamps/elements/type"})
+				}
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "timestamps")), SchemaPath: "/properties/timestamps/elements"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/timestamps"})
+	}
+	if v0, ok := obj["optionalLabels"]; ok {
+		if o0, ok := v0.(map[string]interface{}); ok {
+			for k0, v1 := range o0 {
+				if _, ok := v1.(string); !ok {
+					*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "optionalLabels", k0)), SchemaPath: "/optionalProperties/optionalLabels/values/type"})
+				}
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "optionalLabels")), SchemaPath: "/optionalProperties/optionalLabels/values"})
+		}
+	}
+	if v0, ok := obj["optionalStrings"]; ok {
+		if a0, ok := v0.([]interface{}); ok {
+			for i0, v1 := range a0 {
+				if _, ok := v1.(string); !ok {
+					*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "optionalStrings", strconv.Itoa(i0))), SchemaPath: "/optionalProperties/optionalStrings/elements/type"})
+				}
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "optionalStrings")), SchemaPath: "/optionalProperties/optionalStrings/elements"})
+		}
+	}
+}
+
+func DecodeCollections(data []byte) (Collections, error) {
+	var out Collections
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateCollections(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
@@ -0,0 +1,193 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class Collections {
+    public List<Object> anything;
+    public Map<String, Map<String, Double>> counts;
+    public Map<String, String> labels;
+    public List<List<Double>> matrix;
+    public List<CollectionsPoints> points;
+    public Map<String, CollectionsStatuses> statuses;
+    public List<String> strings;
+    public List<Instant> timestamps;
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public Map<String, String> optionalLabels;
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public List<String> optionalStrings;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/properties"));
+            return;
+        }
+        if (instance.has("anything")) {
+            JsonNode v0 = instance.get("anything");
+            if (!v0.isArray()) {
+                errors.add(new ValidationError(ValidationError.append(path, "anything"), "/properties/anything/elements"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/anything"));
+        }
+        if (instance.has("counts")) {
+            JsonNode v0 = instance.get("counts");
+            if (v0.isObject()) {
+                for (Iterator<Map.Entry<String, JsonNode>> it0 = v0.fields(); it0.hasNext();) {
+                    Map.Entry<String, JsonNode> e0 = it0.next();
+                    JsonNode v1 = e0.getValue();
+                    if (v1.isObject()) {
+                        for (Iterator<Map.Entry<String, JsonNode>> it1 = v1.fields(); it1.hasNext();) {
+                            Map.Entry<String, JsonNode> e1 = it1.next();
+                            JsonNode v2 = e1.getValue();
+                            if (!v2.isNumber()) {
+                                errors.add(new ValidationError(ValidationError.append(path, "counts", e0.getKey(), e1.getKey()), "/properties/counts/values/values/type"));
+                            }
+                        }
+                    } else {
+                        errors.add(new ValidationError(ValidationError.append(path, "counts", e0.getKey()), "/properties/counts/values/values"));
+                    }
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "counts"), "/properties/counts/values"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/counts"));
+        }
+        if (instance.has("labels")) {
+            JsonNode v0 = instance.get("labels");
+            if (v0.isObject()) {
+                for (Iterator<Map.Entry<String, JsonNode>> it0 = v0.fields(); it0.hasNext();) {
+                    Map.Entry<String, JsonNode> e0 = it0.next();
+                    JsonNode v1 = e0.getValue();
+                    if (!v1.isTextual()) {
+                        errors.add(new ValidationError(ValidationError.append(path, "labels", e0.getKey()), "/properties/labels/values/type"));
+                    }
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "labels"), "/properties/labels/values"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/labels"));
+        }
+        if (instance.has("matrix")) {
+            JsonNode v0 = instance.get("matrix");
+            if (v0.isArray()) {
+                for (int i0 = 0; i0 < v0.size(); i0++) {
+                    JsonNode v1 = v0.get(i0);
+                    if (v1.isArray()) {
+                        for (int i1 = 0; i1 < v1.size(); i1++) {
+                            JsonNode v2 = v1.get(i1);
+                            if (!v2.isNumber()) {
+                                errors.add(new ValidationError(ValidationError.append(path, "matrix", Integer.toString(i0), Integer.toString(i1)), "/properties/matrix/elements/elements/type"));
+                            }
+                        }
+                    } else {
+                        errors.add(new ValidationError(ValidationError.append(path, "matrix", Integer.toString(i0)), "/properties/matrix/elements/elements"));
+                    }
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "matrix"), "/properties/matrix/elements"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/matrix"));
+        }
+        if (instance.has("points")) {
+            JsonNode v0 = instance.get("points");
+            if (v0.isArray()) {
+                for (int i0 = 0; i0 < v0.size(); i0++) {
+                    JsonNode v1 = v0.get(i0);
+                    CollectionsPoints.validate(v1, ValidationError.append(path, "points", Integer.toString(i0)), errors);
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "points"), "/properties/points/elements"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/points"));
+        }
+        if (instance.has("statuses")) {
+            JsonNode v0 = instance.get("statuses");
+            if (v0.isObject()) {
+                for (Iterator<Map.Entry<String, JsonNode>> it0 = v0.fields(); it0.hasNext();) {
+                    Map.Entry<String, JsonNode> e0 = it0.next();
+                    JsonNode v1 = e0.getValue();
+                    CollectionsStatuses.validate(v1, ValidationError.append(path, "statuses", e0.getKey()), errors);
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "statuses"), "/properties/statuses/values"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/statuses"));
+        }
+        if (instance.has("strings")) {
+            JsonNode v0 = instance.get("strings");
+            if (v0.isArray()) {
+                for (int i0 = 0; i0 < v0.size(); i0++) {
+                    JsonNode v1 = v0.get(i0);
+                    if (!v1.isTextual()) {
+                        errors.add(new ValidationError(ValidationError.append(path, "strings", Integer.toString(i0)), "/properties/strings/elements/type"));
+                    }
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "strings"), "/properties/strings/elements"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/strings"));
+        }
+        if (instance.has("timestamps")) {
+            JsonNode v0 = instance.get("timestamps");
+            if (v0.isArray()) {
+                for (int i0 = 0; i0 < v0.size(); i0++) {
+                    JsonNode v1 = v0.get(i0);
+                    if (!ValidationError.isTimestamp(v1)) {
+                        errors.add(new ValidationError(ValidationError.append(path, "timestamps", Integer.toString(i0)), "/properties/timestamps/elements/type"));
+                    }
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "timestamps"), "/properties/timestamps/elements"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/timestamps"));
+        }
+        if (instance.has("optionalLabels")) {
+            JsonNode v0 = instance.get("optionalLabels");
+            if (v0.isObject()) {
+                for (Iterator<Map.Entry<String, JsonNode>> it0 = v0.fields(); it0.hasNext();) {
+                    Map.Entry<String, JsonNode> e0 = it0.next();
+                    JsonNode v1 = e0.getValue();
+                    if (!v1.isTextual()) {
+                        errors.add(new ValidationError(ValidationError.append(path, "optionalLabels", e0.getKey()), "/optionalProperties/optionalLabels/values/type"));
+                    }
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "optionalLabels"), "/optionalProperties/optionalLabels/values"));
+            }
+        }
+        if (instance.has("optionalStrings")) {
+            JsonNode v0 = instance.get("optionalStrings");
+            if (v0.isArray()) {
+                for (int i0 = 0; i0 < v0.size(); i0++) {
+                    JsonNode v1 = v0.get(i0);
+                    if (!v1.isTextual()) {
+                        errors.add(new ValidationError(ValidationError.append(path, "optionalStrings", Integer.toString(i0)), "/optionalProperties/optionalStrings/elements/type"));
+                    }
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "optionalStrings"), "/optionalProperties/optionalStrings/elements"));
+            }
+        }
+    }
+}
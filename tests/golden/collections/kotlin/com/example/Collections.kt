@@ -0,0 +1,39 @@
+@file:OptIn(ExperimentalSerializationApi::class)
+
+package com.example
+
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.json.JsonElement
+import kotlinx.serialization.json.JsonIgnoreUnknownKeys
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class CollectionsPoints(
+    val x: Double,
+    val y: Double,
+)
+
+@Serializable
+enum class CollectionsStatuses {
+    @SerialName("active")
+    ACTIVE,
+    @SerialName("inactive")
+    INACTIVE,
+}
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class Collections(
+    val anything: List<JsonElement>,
+    val counts: Map<String, Map<String, Double>>,
+    val labels: Map<String, String>,
+    val matrix: List<List<Double>>,
+    val points: List<CollectionsPoints>,
+    val statuses: Map<String, CollectionsStatuses>,
+    val strings: List<String>,
+    val timestamps: List<String>,
+    val optionalLabels: Map<String, String>? = null,
+    val optionalStrings: List<String>? = null,
+)
@@ -0,0 +1,79 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from datetime import datetime
+from enum import Enum
+from typing import Any, Dict, List, Optional
+
+
+def _parse_timestamp(value: str) -> datetime:
+    return datetime.fromisoformat(value.replace("Z", "+00:00"))
+
+
+@dataclass
+class CollectionsPoints:
+    x: float
+    y: float
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> CollectionsPoints:
+        return cls(
+            x=data["x"],
+            y=data["y"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["x"] = self.x
+        data["y"] = self.y
+        return data
+
+
+class CollectionsStatuses(Enum):
+    ACTIVE = "active"
+    INACTIVE = "inactive"
+
+
+@dataclass
+class Collections:
+    anything: List[Any]
+    counts: Dict[str, Dict[str, float]]
+    labels: Dict[str, str]
+    matrix: List[List[float]]
+    points: List[CollectionsPoints]
+    statuses: Dict[str, CollectionsStatuses]
+    strings: List[str]
+    timestamps: List[datetime]
+    optional_labels: Optional[Dict[str, str]] = None
+    optional_strings: Optional[List[str]] = None
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Collections:
+        return cls(
+            anything=data["anything"],
+            counts=data["counts"],
+            labels=data["labels"],
+            matrix=data["matrix"],
+            points=[CollectionsPoints.from_json(v0) for v0 in data["points"]],
+            statuses={k0: CollectionsStatuses(v0) for k0, v0 in data["statuses"].items()},
+            strings=data["strings"],
+            timestamps=[_parse_timestamp(v0) for v0 in data["timestamps"]],
+            optional_labels=data.get("optionalLabels"),
+            optional_strings=data.get("optionalStrings"),
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["anything"] = self.anything
+        data["counts"] = self.counts
+        data["labels"] = self.labels
+        data["matrix"] = self.matrix
+        data["points"] = [v0.to_json() for v0 in self.points]
+        data["statuses"] = {k0: v0.value for k0, v0 in self.statuses.items()}
+        data["strings"] = self.strings
+        data["timestamps"] = [v0.isoformat() for v0 in self.timestamps]
+        if self.optional_labels is not None:
+            data["optionalLabels"] = self.optional_labels
+        if self.optional_strings is not None:
+            data["optionalStrings"] = self.optional_strings
+        return data
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CollectionsPoints {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum CollectionsStatuses {
+    #[serde(rename = "active")]
+    Active,
+    #[serde(rename = "inactive")]
+    Inactive,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Collections {
+    pub anything: Vec<serde_json::Value>,
+    pub counts: std::collections::HashMap<String, std::collections::HashMap<String, f64>>,
+    pub labels: std::collections::HashMap<String, String>,
+    pub matrix: Vec<Vec<f64>>,
+    pub points: Vec<CollectionsPoints>,
+    pub statuses: std::collections::HashMap<String, CollectionsStatuses>,
+    pub strings: Vec<String>,
+    pub timestamps: Vec<chrono::DateTime<chrono::Utc>>,
+    #[serde(rename = "optionalLabels")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional_labels: Option<std::collections::HashMap<String, String>>,
+    #[serde(rename = "optionalStrings")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional_strings: Option<Vec<String>>,
+}
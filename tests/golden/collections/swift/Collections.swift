@@ -0,0 +1,24 @@
+import Foundation
+
+struct CollectionsPoints: Codable, Equatable {
+    var x: Double
+    var y: Double
+}
+
+enum CollectionsStatuses: String, Codable, Equatable {
+    case active
+    case inactive
+}
+
+struct Collections: Codable, Equatable {
+    var anything: [JSONValue]
+    var counts: [String: [String: Double]]
+    var labels: [String: String]
+    var matrix: [[Double]]
+    var points: [CollectionsPoints]
+    var statuses: [String: CollectionsStatuses]
+    var strings: [String]
+    var timestamps: [String]
+    var optionalLabels: [String: String]?
+    var optionalStrings: [String]?
+}
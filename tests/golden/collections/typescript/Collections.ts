@@ -0,0 +1,222 @@
+export interface ValidationError {
+  instancePath: string;
+  schemaPath: string;
+}
+function jslPointer(path: string[]): string {
+  return path.map((token) => "/" + token.replace(/~/g, "~0").replace(/\//g, "~1")).join("");
+}
+function jslIsObject(instance: unknown): instance is { [key: string]: unknown } {
+  return typeof instance === "object" && instance !== null && !Array.isArray(instance);
+}
+function jslHas(instance: { [key: string]: unknown }, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(instance, key);
+}
+function jslIsTimestamp(instance: unknown): boolean {
+  return typeof instance === "string" && /^\d{4}-\d{2}-\d{2}[Tt]\d{2}:\d{2}:\d{2}(\.\d+)?([Zz]|[+-]\d{2}:\d{2})$/.test(instance) && !isNaN(Date.parse(instance));
+}
+export interface CollectionsPoints {
+  x: number;
+  y: number;
+  [key: string]: unknown;
+}
+export function validateCollectionsPoints(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateCollectionsPointsAt(instance, [], errors);
+  return errors;
+}
+export function validateCollectionsPointsAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/points/elements/properties" });
+    return;
+  }
+  if (jslHas(instance, "x")) {
+    if (typeof instance["x"] !== "number") {
+      errors.push({ instancePath: jslPointer([...path, "x"]), schemaPath: "/properties/points/elements/properties/x/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/points/elements/properties/x" });
+  }
+  if (jslHas(instance, "y")) {
+    if (typeof instance["y"] !== "number") {
+      errors.push({ instancePath: jslPointer([...path, "y"]), schemaPath: "/properties/points/elements/properties/y/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/points/elements/properties/y" });
+  }
+}
+export type CollectionsStatuses = "active" | "inactive";
+export function validateCollectionsStatuses(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateCollectionsStatusesAt(instance, [], errors);
+  return errors;
+}
+export function validateCollectionsStatusesAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (instance !== "active" && instance !== "inactive") {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/statuses/values/enum" });
+  }
+}
+export interface Collections {
+  anything: any[];
+  counts: { [name: string]: { [name: string]: number } };
+  labels: { [name: string]: string };
+  matrix: number[][];
+  points: CollectionsPoints[];
+  statuses: { [name: string]: CollectionsStatuses };
+  strings: string[];
+  timestamps: string[];
+  optionalLabels?: { [name: string]: string };
+  optionalStrings?: string[];
+  [key: string]: unknown;
+}
+export function validateCollections(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateCollectionsAt(instance, [], errors);
+  return errors;
+}
+export function validateCollectionsAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties" });
+    return;
+  }
+  if (jslHas(instance, "anything")) {
+    const a0: unknown = instance["anything"];
+    if (Array.isArray(a0)) {
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "anything"]), schemaPath: "/properties/anything/elements" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/anything" });
+  }
+  if (jslHas(instance, "counts")) {
+    const o0: unknown = instance["counts"];
+    if (jslIsObject(o0)) {
+      Object.keys(o0).forEach((k0: string) => {
+        const o1: unknown = o0[k0];
+        if (jslIsObject(o1)) {
+          Object.keys(o1).forEach((k1: string) => {
+            if (typeof o1[k1] !== "number") {
+              errors.push({ instancePath: jslPointer([...path, "counts", k0, k1]), schemaPath: "/properties/counts/values/values/type" });
+            }
+          });
+        } else {
+          errors.push({ instancePath: jslPointer([...path, "counts", k0]), schemaPath: "/properties/counts/values/values" });
+        }
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "counts"]), schemaPath: "/properties/counts/values" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/counts" });
+  }
+  if (jslHas(instance, "labels")) {
+    const o0: unknown = instance["labels"];
+    if (jslIsObject(o0)) {
+      Object.keys(o0).forEach((k0: string) => {
+        if (typeof o0[k0] !== "string") {
+          errors.push({ instancePath: jslPointer([...path, "labels", k0]), schemaPath: "/properties/labels/values/type" });
+        }
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "labels"]), schemaPath: "/properties/labels/values" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/labels" });
+  }
+  if (jslHas(instance, "matrix")) {
+    const a0: unknown = instance["matrix"];
+    if (Array.isArray(a0)) {
+      a0.forEach((v0: unknown, i0: number) => {
+        const a1: unknown = v0;
+        if (Array.isArray(a1)) {
+          a1.forEach((v1: unknown, i1: number) => {
+            if (typeof v1 !== "number") {
+              errors.push({ instancePath: jslPointer([...path, "matrix", String(i0), String(i1)]), schemaPath: "/properties/matrix/elements/elements/type" });
+            }
+          });
+        } else {
+          errors.push({ instancePath: jslPointer([...path, "matrix", String(i0)]), schemaPath: "/properties/matrix/elements/elements" });
+        }
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "matrix"]), schemaPath: "/properties/matrix/elements" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/matrix" });
+  }
+  if (jslHas(instance, "points")) {
+    const a0: unknown = instance["points"];
+    if (Array.isArray(a0)) {
+      a0.forEach((v0: unknown, i0: number) => {
+        validateCollectionsPointsAt(v0, [...path, "points", String(i0)], errors);
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "points"]), schemaPath: "/properties/points/elements" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/points" });
+  }
+  if (jslHas(instance, "statuses")) {
+    const o0: unknown = instance["statuses"];
+    if (jslIsObject(o0)) {
+      Object.keys(o0).forEach((k0: string) => {
+        validateCollectionsStatusesAt(o0[k0], [...path, "statuses", k0], errors);
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "statuses"]), schemaPath: "/properties/statuses/values" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/statuses" });
+  }
+  if (jslHas(instance, "strings")) {
+    const a0: unknown = instance["strings"];
+    if (Array.isArray(a0)) {
+      a0.forEach((v0: unknown, i0: number) => {
+        if (typeof v0 !== "string") {
+          errors.push({ instancePath: jslPointer([...path, "strings", String(i0)]), schemaPath: "/properties/strings/elements/type" });
+        }
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "strings"]), schemaPath: "/properties/strings/elements" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/strings" });
+  }
+  if (jslHas(instance, "timestamps")) {
+    const a0: unknown = instance["timestamps"];
+    if (Array.isArray(a0)) {
+      a0.forEach((v0: unknown, i0: number) => {
+        if (!jslIsTimestamp(v0)) {
+          errors.push({ instancePath: jslPointer([...path, "timestamps", String(i0)]), schemaPath: "/properties/timestamps/elements/type" });
+        }
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "timestamps"]), schemaPath: "/properties/timestamps/elements" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/timestamps" });
+  }
+  if (jslHas(instance, "optionalLabels")) {
+    const o0: unknown = instance["optionalLabels"];
+    if (jslIsObject(o0)) {
+      Object.keys(o0).forEach((k0: string) => {
+        if (typeof o0[k0] !== "string") {
+          errors.push({ instancePath: jslPointer([...path, "optionalLabels", k0]), schemaPath: "/optionalProperties/optionalLabels/values/type" });
+        }
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "optionalLabels"]), schemaPath: "/optionalProperties/optionalLabels/values" });
+    }
+  }
+  if (jslHas(instance, "optionalStrings")) {
+    const a0: unknown = instance["optionalStrings"];
+    if (Array.isArray(a0)) {
+      a0.forEach((v0: unknown, i0: number) => {
+        if (typeof v0 !== "string") {
+          errors.push({ instancePath: jslPointer([...path, "optionalStrings", String(i0)]), schemaPath: "/optionalProperties/optionalStrings/elements/type" });
+        }
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "optionalStrings"]), schemaPath: "/optionalProperties/optionalStrings/elements" });
+    }
+  }
+}
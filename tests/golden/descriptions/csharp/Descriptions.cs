@@ -0,0 +1,97 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Example;
+
+/// <summary>
+/// One line of an order.
+/// Each line has a single product.
+/// </summary>
+public sealed record LineItem
+{
+    /// <summary>
+    /// The price of one unit
+    /// </summary>
+    [JsonPropertyName("price")]
+    public required double Price { get; init; }
+
+    /// <summary>
+    /// The product's stock keeping unit
+    /// </summary>
+    [JsonPropertyName("sku")]
+    public required string Sku { get; init; }
+
+    /// <summary>
+    ///   Indented
+    ///
+    ///   with a blank line
+    /// </summary>
+    [JsonPropertyName("note")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Note { get; init; }
+}
+
+/// <summary>
+/// How the order was paid for
+/// </summary>
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "method")]
+[JsonDerivedType(typeof(DescriptionsPaymentCard), "card")]
+[JsonDerivedType(typeof(DescriptionsPaymentCash), "cash")]
+public abstract record DescriptionsPayment;
+
+/// <summary>
+/// Paid by card
+/// </summary>
+public sealed record DescriptionsPaymentCard : DescriptionsPayment
+{
+    /// <summary>
+    /// The last four digits
+    /// </summary>
+    [JsonPropertyName("last4")]
+    public required string Last4 { get; init; }
+}
+
+/// <summary>
+/// Paid in cash
+/// </summary>
+public sealed record DescriptionsPaymentCash : DescriptionsPayment;
+
+/// <summary>
+/// Where the order is at
+/// </summary>
+[JsonConverter(typeof(JsonStringEnumConverter<DescriptionsStatus>))]
+public enum DescriptionsStatus
+{
+    [JsonStringEnumMemberName("pending")]
+    Pending,
+    [JsonStringEnumMemberName("shipped")]
+    Shipped,
+}
+
+/// <summary>
+/// An order placed in the store
+/// </summary>
+public sealed record Descriptions
+{
+    /// <summary>
+    /// What was ordered
+    /// </summary>
+    [JsonPropertyName("items")]
+    public required List<LineItem> Items { get; init; }
+
+    /// <summary>
+    /// How the order was paid for
+    /// </summary>
+    [JsonPropertyName("payment")]
+    public required DescriptionsPayment Payment { get; init; }
+
+    /// <summary>
+    /// Where the order is at
+    /// </summary>
+    [JsonPropertyName("status")]
+    public required DescriptionsStatus Status { get; init; }
+}
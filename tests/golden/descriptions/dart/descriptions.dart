@@ -0,0 +1,143 @@
+/// One line of an order.
+/// Each line has a single product.
+class LineItem {
+  /// The price of one unit
+  final Money price;
+  /// The product's stock keeping unit
+  final String sku;
+  ///   Indented
+  ///
+  ///   with a blank line
+  final String? note;
+
+  const LineItem({
+    required this.price,
+    required this.sku,
+    this.note,
+  });
+
+  factory LineItem.fromJson(Map<String, dynamic> json) {
+    return LineItem(
+      price: (json["price"] as num).toDouble(),
+      sku: json["sku"] as String,
+      note: json["note"] as String?,
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "price": price,
+      "sku": sku,
+      if (note != null) "note": note,
+    };
+  }
+}
+
+/// An amount of money, in cents
+typedef Money = double;
+
+/// How the order was paid for
+sealed class DescriptionsPayment {
+  const DescriptionsPayment();
+
+  factory DescriptionsPayment.fromJson(Map<String, dynamic> json) {
+    return switch (json["method"]) {
+      "card" => DescriptionsPaymentCard.fromJson(json),
+      "cash" => DescriptionsPaymentCash.fromJson(json),
+      _ => throw FormatException("unknown DescriptionsPayment tag", json["method"]),
+    };
+  }
+
+  Map<String, dynamic> toJson();
+}
+
+/// Paid by card
+class DescriptionsPaymentCard extends DescriptionsPayment {
+  /// The last four digits
+  final String last4;
+
+  const DescriptionsPaymentCard({
+    required this.last4,
+  });
+
+  factory DescriptionsPaymentCard.fromJson(Map<String, dynamic> json) {
+    return DescriptionsPaymentCard(
+      last4: json["last4"] as String,
+    );
+  }
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      "method": "card",
+      "last4": last4,
+    };
+  }
+}
+
+/// Paid in cash
+class DescriptionsPaymentCash extends DescriptionsPayment {
+  const DescriptionsPaymentCash();
+
+  factory DescriptionsPaymentCash.fromJson(Map<String, dynamic> json) {
+    return const DescriptionsPaymentCash();
+  }
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      "method": "cash",
+    };
+  }
+}
+
+/// Where the order is at
+enum DescriptionsStatus {
+  pending("pending"),
+  shipped("shipped");
+
+  const DescriptionsStatus(this.value);
+
+  final String value;
+
+  factory DescriptionsStatus.fromJson(String json) {
+    return values.firstWhere(
+      (value) => value.value == json,
+      orElse: () => throw FormatException("unknown DescriptionsStatus value", json),
+    );
+  }
+
+  String toJson() => value;
+}
+
+/// An order placed in the store
+class Descriptions {
+  /// What was ordered
+  final List<LineItem> items;
+  /// How the order was paid for
+  final DescriptionsPayment payment;
+  /// Where the order is at
+  final DescriptionsStatus status;
+
+  const Descriptions({
+    required this.items,
+    required this.payment,
+    required this.status,
+  });
+
+  factory Descriptions.fromJson(Map<String, dynamic> json) {
+    return Descriptions(
+      items: (json["items"] as List<dynamic>).map((v0) => LineItem.fromJson(v0 as Map<String, dynamic>)).toList(),
+      payment: DescriptionsPayment.fromJson(json["payment"] as Map<String, dynamic>),
+      status: DescriptionsStatus.fromJson(json["status"] as String),
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "items": items.map((v0) => v0.toJson()).toList(),
+      "payment": payment.toJson(),
+      "status": status.toJson(),
+    };
+  }
+}
@@ -0,0 +1,294 @@
+package models
+
+import (
+	"encoding/json"
+	"strconv"
+)
+
+// One line of an order.
+// Each line has a single product.
+type LineItem struct {
+	// The price of one unit
+	Price Money `json:"price"`
+	// The product's stock keeping unit
+	Sku string `json:"sku"`
+	//   Indented
+	//
+	//   with a blank line
+	Note *string `json:"note,omitempty"`
+}
+
+func ValidateLineItem(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateLineItem(instance, nil, &errors)
+	return errors
+}
+
+func validateLineItem(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/lineItem/properties"})
+		return
+	}
+	if v0, ok := obj["price"]; ok {
+		validateMoney(v0, jslAppend(path, "price"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/lineItem/properties/price"})
+	}
+	if v0, ok := obj["sku"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "sku")), SchemaPath: "/definitions/lineItem/properties/sku/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/lineItem/properties/sku"})
+	}
+	if v0, ok := obj["note"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "note")), SchemaPath: "/definitions/lineItem/optionalProperties/note/type"})
+		}
+	}
+}
+
+func DecodeLineItem(data []byte) (LineItem, error) {
+	var out LineItem
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateLineItem(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+// An amount of money, in cents
+type Money float64
+
+func ValidateMoney(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateMoney(instance, nil, &errors)
+	return errors
+}
+
+func validateMoney(instance interface{}, path []string, errors *[]ValidationError) {
+	if _, ok := instance.(float64); !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/money/type"})
+	}
+}
+
+func DecodeMoney(data []byte) (Money, error) {
+	var out Money
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateMoney(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+// Paid by card
+type DescriptionsPaymentCard struct {
+	// The last four digits
+	Last4 string `json:"last4"`
+}
+
+// Paid in cash
+type DescriptionsPaymentCash struct {
+}
+
+// How the order was paid for
+type DescriptionsPayment struct {
+	Method string `json:"method"`
+	Val interface{} `json:"-"`
+}
+
+func (s *DescriptionsPayment) UnmarshalJSON(buf []byte) error {
+	var x struct{ Tag string `json:"method"` }
+	if err := json.Unmarshal(buf, &x); err != nil {
+		return err
+	}
+	switch x.Tag {
+	case "card":
+		var data DescriptionsPaymentCard
+		if err := json.Unmarshal(buf, &data); err != nil {
+			return err
+		}
+		s.Val = data
+	case "cash":
+		var data DescriptionsPaymentCash
+		if err := json.Unmarshal(buf, &data); err != nil {
+			return err
+		}
+		s.Val = data
+	}
+	s.Method = x.Tag
+	return nil
+}
+
+func (s DescriptionsPayment) MarshalJSON() ([]byte, error) {
+	switch val := s.Val.(type) {
+	case DescriptionsPaymentCard:
+		var data struct{
+			Tag string `json:"method"`
+			DescriptionsPaymentCard
+		}
+		data.DescriptionsPaymentCard = val
+		data.Tag = s.Method
+		return json.Marshal(data)
+	case DescriptionsPaymentCash:
+		var data struct{
+			Tag string `json:"method"`
+			DescriptionsPaymentCash
+		}
+		data.DescriptionsPaymentCash = val
+		data.Tag = s.Method
+		return json.Marshal(data)
+	}
+	panic("invalid discriminator tag")
+}
+
+func ValidateDescriptionsPayment(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateDescriptionsPayment(instance, nil, &errors)
+	return errors
+}
+
+func validateDescriptionsPayment(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/payment/discriminator"})
+		return
+	}
+	tag, ok := obj["method"]
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/payment/discriminator/tag"})
+		return
+	}
+	switch tag {
+	case "card":
+		if v0, ok := obj["last4"]; ok {
+			if _, ok := v0.(string); !ok {
+				*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "last4")), SchemaPath: "/properties/payment/discriminator/mapping/card/properties/last4/type"})
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/payment/discriminator/mapping/card/properties/last4"})
+		}
+	case "cash":
+	default:
+		if _, ok := tag.(string); ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "method")), SchemaPath: "/properties/payment/discriminator/mapping"})
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "method")), SchemaPath: "/properties/payment/discriminator/tag"})
+		}
+	}
+}
+
+func DecodeDescriptionsPayment(data []byte) (DescriptionsPayment, error) {
+	var out DescriptionsPayment
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateDescriptionsPayment(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+// Where the order is at
+type DescriptionsStatus string
+
+const (
+	DescriptionsStatusPending DescriptionsStatus = "pending"
+	DescriptionsStatusShipped DescriptionsStatus = "shipped"
+)
+
+func ValidateDescriptionsStatus(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateDescriptionsStatus(instance, nil, &errors)
+	return errors
+}
+
+func validateDescriptionsStatus(instance interface{}, path []string, errors *[]ValidationError) {
+	switch instance {
+	case "pending", "shipped":
+	default:
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/status/enum"})
+	}
+}
+
+func DecodeDescriptionsStatus(data []byte) (DescriptionsStatus, error) {
+	var out DescriptionsStatus
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateDescriptionsStatus(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+// An order placed in the store
+type Descriptions struct {
+	// What was ordered
+	Items []LineItem `json:"items"`
+	// How the order was paid for
+	Payment DescriptionsPayment `json:"payment"`
+	// Where the order is at
+	Status DescriptionsStatus `json:"status"`
+}
+
+func ValidateDescriptions(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateDescriptions(instance, nil, &errors)
+	return errors
+}
+
+func validateDescriptions(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties"})
+		return
+	}
+	if v0, ok := obj["items"]; ok {
+		if a0, ok := v0.([]interface{}); ok {
+			for i0, v1 := range a0 {
+				validateLineItem(v1, jslAppend(path, "items", strconv.Itoa(i0)), errors)
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "items")), SchemaPath: "/properties/items/elements"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/items"})
+	}
+	if v0, ok := obj["payment"]; ok {
+		validateDescriptionsPayment(v0, jslAppend(path, "payment"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/payment"})
+	}
+	if v0, ok := obj["status"]; ok {
+		validateDescriptionsStatus(v0, jslAppend(path, "status"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/status"})
+	}
+}
+
+func DecodeDescriptions(data []byte) (Descriptions, error) {
+	var out Descriptions
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateDescriptions(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
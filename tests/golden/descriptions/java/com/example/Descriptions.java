@@ -0,0 +1,66 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * An order placed in the store
+ */
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class Descriptions {
+    /**
+     * What was ordered
+     */
+    public List<LineItem> items;
+    /**
+     * How the order was paid for
+     */
+    public DescriptionsPayment payment;
+    /**
+     * Where the order is at
+     */
+    public DescriptionsStatus status;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/properties"));
+            return;
+        }
+        if (instance.has("items")) {
+            JsonNode v0 = instance.get("items");
+            if (v0.isArray()) {
+                for (int i0 = 0; i0 < v0.size(); i0++) {
+                    JsonNode v1 = v0.get(i0);
+                    LineItem.validate(v1, ValidationError.append(path, "items", Integer.toString(i0)), errors);
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "items"), "/properties/items/elements"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/items"));
+        }
+        if (instance.has("payment")) {
+            JsonNode v0 = instance.get("payment");
+            DescriptionsPayment.validate(v0, ValidationError.append(path, "payment"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/payment"));
+        }
+        if (instance.has("status")) {
+            JsonNode v0 = instance.get("status");
+            DescriptionsStatus.validate(v0, ValidationError.append(path, "status"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/status"));
+        }
+    }
+}
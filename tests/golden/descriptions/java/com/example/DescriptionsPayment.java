@@ -0,0 +1,57 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonSubTypes;
+import com.fasterxml.jackson.annotation.JsonTypeInfo;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * How the order was paid for
+ */
+@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "method")
+@JsonSubTypes({
+    @JsonSubTypes.Type(value = DescriptionsPaymentCard.class, name = "card"),
+    @JsonSubTypes.Type(value = DescriptionsPaymentCash.class, name = "cash"),
+})
+public abstract class DescriptionsPayment {
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/properties/payment/discriminator"));
+            return;
+        }
+        if (!instance.has("method")) {
+            errors.add(new ValidationError(path, "/properties/payment/discriminator/tag"));
+            return;
+        }
+        if (!instance.get("method").isTextual()) {
+            errors.add(new ValidationError(ValidationError.append(path, "method"), "/properties/payment/discriminator/tag"));
+            return;
+        }
+
+        switch (instance.get("method").asText()) {
+        case "card":
+            if (instance.has("last4")) {
+                JsonNode v0 = instance.get("last4");
+                if (!v0.isTextual()) {
+                    errors.add(new ValidationError(ValidationError.append(path, "last4"), "/properties/payment/discriminator/mapping/card/properties/last4/type"));
+                }
+            } else {
+                errors.add(new ValidationError(path, "/properties/payment/discriminator/mapping/card/properties/last4"));
+            }
+            break;
+        case "cash":
+            break;
+        default:
+            errors.add(new ValidationError(ValidationError.append(path, "method"), "/properties/payment/discriminator/mapping"));
+        }
+    }
+}
@@ -0,0 +1,17 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import java.time.Instant;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * Paid by card
+ */
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class DescriptionsPaymentCard extends DescriptionsPayment {
+    /**
+     * The last four digits
+     */
+    public String last4;
+}
@@ -0,0 +1,13 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import java.time.Instant;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * Paid in cash
+ */
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class DescriptionsPaymentCash extends DescriptionsPayment {
+}
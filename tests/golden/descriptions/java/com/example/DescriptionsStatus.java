@@ -0,0 +1,34 @@
+package com.example;
+
+import com.fasterxml.jackson.databind.JsonNode;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * Where the order is at
+ */
+public enum DescriptionsStatus {
+   pending,
+   shipped,
+   ;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (instance.isTextual()) {
+            switch (instance.asText()) {
+            case "pending":
+            case "shipped":
+                return;
+            }
+        }
+
+        errors.add(new ValidationError(path, "/properties/status/enum"));
+    }
+}
@@ -0,0 +1,68 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * One line of an order.
+ * Each line has a single product.
+ */
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class LineItem {
+    /**
+     * The price of one unit
+     */
+    public double price;
+    /**
+     * The product's stock keeping unit
+     */
+    public String sku;
+    /**
+     *   Indented
+     *
+     *   with a blank line
+     */
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public String note;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/definitions/lineItem/properties"));
+            return;
+        }
+        if (instance.has("price")) {
+            JsonNode v0 = instance.get("price");
+            if (!v0.isNumber()) {
+                errors.add(new ValidationError(ValidationError.append(path, "price"), "/definitions/money/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/definitions/lineItem/properties/price"));
+        }
+        if (instance.has("sku")) {
+            JsonNode v0 = instance.get("sku");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "sku"), "/definitions/lineItem/properties/sku/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/definitions/lineItem/properties/sku"));
+        }
+        if (instance.has("note")) {
+            JsonNode v0 = instance.get("note");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "note"), "/definitions/lineItem/optionalProperties/note/type"));
+            }
+        }
+    }
+}
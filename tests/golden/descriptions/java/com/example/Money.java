@@ -0,0 +1,26 @@
+package com.example;
+
+import com.fasterxml.jackson.databind.JsonNode;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * An amount of money, in cents
+ */
+public final class Money {
+    private Money() {}
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isNumber()) {
+            errors.add(new ValidationError(path, "/definitions/money/type"));
+        }
+    }
+}
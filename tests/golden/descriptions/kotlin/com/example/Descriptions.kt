@@ -0,0 +1,96 @@
+@file:OptIn(ExperimentalSerializationApi::class)
+
+package com.example
+
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.json.JsonClassDiscriminator
+import kotlinx.serialization.json.JsonIgnoreUnknownKeys
+
+/**
+ * One line of an order.
+ * Each line has a single product.
+ */
+@Serializable
+@JsonIgnoreUnknownKeys
+data class LineItem(
+    /**
+     * The price of one unit
+     */
+    val price: Money,
+    /**
+     * The product's stock keeping unit
+     */
+    val sku: String,
+    /**
+     *   Indented
+     *
+     *   with a blank line
+     */
+    val note: String? = null,
+)
+
+/**
+ * An amount of money, in cents
+ */
+typealias Money = Double
+
+/**
+ * How the order was paid for
+ */
+@Serializable
+@JsonClassDiscriminator("method")
+sealed class DescriptionsPayment
+
+/**
+ * Paid by card
+ */
+@Serializable
+@SerialName("card")
+@JsonIgnoreUnknownKeys
+data class DescriptionsPaymentCard(
+    /**
+     * The last four digits
+     */
+    val last4: String,
+) : DescriptionsPayment()
+
+/**
+ * Paid in cash
+ */
+@Serializable
+@SerialName("cash")
+@JsonIgnoreUnknownKeys
+class DescriptionsPaymentCash : DescriptionsPayment()
+
+/**
+ * Where the order is at
+ */
+@Serializable
+enum class DescriptionsStatus {
+    @SerialName("pending")
+    PENDING,
+    @SerialName("shipped")
+    SHIPPED,
+}
+
+/**
+ * An order placed in the store
+ */
+@Serializable
+@JsonIgnoreUnknownKeys
+data class Descriptions(
+    /**
+     * What was ordered
+     */
+    val items: List<LineItem>,
+    /**
+     * How the order was paid for
+     */
+    val payment: DescriptionsPayment,
+    /**
+     * Where the order is at
+     */
+    val status: DescriptionsStatus,
+)
@@ -0,0 +1,122 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from enum import Enum
+from typing import Any, Dict, List, Optional
+
+
+@dataclass
+class LineItem:
+    """
+    One line of an order.
+    Each line has a single product.
+    """
+    # The price of one unit
+    price: Money
+    # The product's stock keeping unit
+    sku: str
+    #   Indented
+    #
+    #   with a blank line
+    note: Optional[str] = None
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> LineItem:
+        return cls(
+            price=data["price"],
+            sku=data["sku"],
+            note=data.get("note"),
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["price"] = self.price
+        data["sku"] = self.sku
+        if self.note is not None:
+            data["note"] = self.note
+        return data
+
+
+# An amount of money, in cents
+Money = float
+
+
+@dataclass
+class DescriptionsPayment:
+    """How the order was paid for"""
+
+    @staticmethod
+    def from_json(data: Dict[str, Any]) -> DescriptionsPayment:
+        variants = {
+            "card": DescriptionsPaymentCard,
+            "cash": DescriptionsPaymentCash,
+        }
+
+        return variants[data["method"]].from_json(data)
+
+    def to_json(self) -> Dict[str, Any]:
+        raise NotImplementedError
+
+
+@dataclass
+class DescriptionsPaymentCard(DescriptionsPayment):
+    """Paid by card"""
+    # The last four digits
+    last_4: str
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> DescriptionsPaymentCard:
+        return cls(
+            last_4=data["last4"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {"method": "card"}
+        data["last4"] = self.last_4
+        return data
+
+
+@dataclass
+class DescriptionsPaymentCash(DescriptionsPayment):
+    """Paid in cash"""
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> DescriptionsPaymentCash:
+        return cls(
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {"method": "cash"}
+        return data
+
+
+class DescriptionsStatus(Enum):
+    """Where the order is at"""
+    PENDING = "pending"
+    SHIPPED = "shipped"
+
+
+@dataclass
+class Descriptions:
+    """An order placed in the store"""
+    # What was ordered
+    items: List[LineItem]
+    # How the order was paid for
+    payment: DescriptionsPayment
+    # Where the order is at
+    status: DescriptionsStatus
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Descriptions:
+        return cls(
+            items=[LineItem.from_json(v0) for v0 in data["items"]],
+            payment=DescriptionsPayment.from_json(data["payment"]),
+            status=DescriptionsStatus(data["status"]),
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["items"] = [v0.to_json() for v0 in self.items]
+        data["payment"] = self.payment.to_json()
+        data["status"] = self.status.value
+        return data
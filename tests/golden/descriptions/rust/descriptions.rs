@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+/// One line of an order.
+/// Each line has a single product.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LineItem {
+    /// The price of one unit
+    pub price: Money,
+    /// The product's stock keeping unit
+    pub sku: String,
+    ///   Indented
+    ///
+    ///   with a blank line
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub note: Option<String>,
+}
+
+/// An amount of money, in cents
+pub type Money = f64;
+
+/// Paid by card
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DescriptionsPaymentCard {
+    /// The last four digits
+    #[serde(rename = "last4")]
+    pub last_4: String,
+}
+
+/// Paid in cash
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DescriptionsPaymentCash {
+}
+
+/// How the order was paid for
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "method")]
+pub enum DescriptionsPayment {
+    #[serde(rename = "card")]
+    Card(DescriptionsPaymentCard),
+    #[serde(rename = "cash")]
+    Cash(DescriptionsPaymentCash),
+}
+
+/// Where the order is at
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum DescriptionsStatus {
+    #[serde(rename = "pending")]
+    Pending,
+    #[serde(rename = "shipped")]
+    Shipped,
+}
+
+/// An order placed in the store
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Descriptions {
+    /// What was ordered
+    pub items: Vec<LineItem>,
+    /// How the order was paid for
+    pub payment: DescriptionsPayment,
+    /// Where the order is at
+    pub status: DescriptionsStatus,
+}
@@ -0,0 +1,78 @@
+import Foundation
+
+/// One line of an order.
+/// Each line has a single product.
+struct LineItem: Codable, Equatable {
+    /// The price of one unit
+    var price: Money
+    /// The product's stock keeping unit
+    var sku: String
+    ///   Indented
+    ///
+    ///   with a blank line
+    var note: String?
+}
+
+/// An amount of money, in cents
+typealias Money = Double
+
+/// Paid by card
+struct DescriptionsPaymentCard: Codable, Equatable {
+    /// The last four digits
+    var last4: String
+}
+
+/// Paid in cash
+struct DescriptionsPaymentCash: Codable, Equatable {
+}
+
+/// How the order was paid for
+enum DescriptionsPayment: Codable, Equatable {
+    case card(DescriptionsPaymentCard)
+    case cash(DescriptionsPaymentCash)
+
+    enum CodingKeys: String, CodingKey {
+        case tag = "method"
+    }
+
+    init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .tag)
+        switch tag {
+        case "card":
+            self = .card(try DescriptionsPaymentCard(from: decoder))
+        case "cash":
+            self = .cash(try DescriptionsPaymentCash(from: decoder))
+        default:
+            throw DecodingError.dataCorruptedError(forKey: .tag, in: container, debugDescription: "Unknown tag: \(tag)")
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case .card(let value):
+            try container.encode("card", forKey: .tag)
+            try value.encode(to: encoder)
+        case .cash(let value):
+            try container.encode("cash", forKey: .tag)
+            try value.encode(to: encoder)
+        }
+    }
+}
+
+/// Where the order is at
+enum DescriptionsStatus: String, Codable, Equatable {
+    case pending
+    case shipped
+}
+
+/// An order placed in the store
+struct Descriptions: Codable, Equatable {
+    /// What was ordered
+    var items: [LineItem]
+    /// How the order was paid for
+    var payment: DescriptionsPayment
+    /// Where the order is at
+    var status: DescriptionsStatus
+}
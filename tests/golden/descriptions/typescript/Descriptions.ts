@@ -0,0 +1,197 @@
+export interface ValidationError {
+  instancePath: string;
+  schemaPath: string;
+}
+function jslPointer(path: string[]): string {
+  return path.map((token) => "/" + token.replace(/~/g, "~0").replace(/\//g, "~1")).join("");
+}
+function jslIsObject(instance: unknown): instance is { [key: string]: unknown } {
+  return typeof instance === "object" && instance !== null && !Array.isArray(instance);
+}
+function jslHas(instance: { [key: string]: unknown }, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(instance, key);
+}
+/**
+ * One line of an order.
+ * Each line has a single product.
+ */
+export interface LineItem {
+  /**
+   * The price of one unit
+   */
+  price: Money;
+  /**
+   * The product's stock keeping unit
+   */
+  sku: string;
+  /**
+   *   Indented
+   *
+   *   with a blank line
+   */
+  note?: string;
+  [key: string]: unknown;
+}
+export function validateLineItem(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateLineItemAt(instance, [], errors);
+  return errors;
+}
+export function validateLineItemAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/lineItem/properties" });
+    return;
+  }
+  if (jslHas(instance, "price")) {
+    validateMoneyAt(instance["price"], [...path, "price"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/lineItem/properties/price" });
+  }
+  if (jslHas(instance, "sku")) {
+    if (typeof instance["sku"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "sku"]), schemaPath: "/definitions/lineItem/properties/sku/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/lineItem/properties/sku" });
+  }
+  if (jslHas(instance, "note")) {
+    if (typeof instance["note"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "note"]), schemaPath: "/definitions/lineItem/optionalProperties/note/type" });
+    }
+  }
+}
+/**
+ * An amount of money, in cents
+ */
+export type Money = number;
+export function validateMoney(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateMoneyAt(instance, [], errors);
+  return errors;
+}
+export function validateMoneyAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (typeof instance !== "number") {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/money/type" });
+  }
+}
+/**
+ * Paid by card
+ */
+export interface DescriptionsPaymentCard {
+  method: "card";
+  /**
+   * The last four digits
+   */
+  last4: string;
+  [key: string]: unknown;
+}
+/**
+ * Paid in cash
+ */
+export interface DescriptionsPaymentCash {
+  method: "cash";
+  [key: string]: unknown;
+}
+/**
+ * How the order was paid for
+ */
+export type DescriptionsPayment = DescriptionsPaymentCard | DescriptionsPaymentCash;
+export function validateDescriptionsPayment(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateDescriptionsPaymentAt(instance, [], errors);
+  return errors;
+}
+export function validateDescriptionsPaymentAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/payment/discriminator" });
+    return;
+  }
+  if (!jslHas(instance, "method")) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/payment/discriminator/tag" });
+    return;
+  }
+  switch (instance["method"]) {
+    case "card":
+      if (jslHas(instance, "last4")) {
+        if (typeof instance["last4"] !== "string") {
+          errors.push({ instancePath: jslPointer([...path, "last4"]), schemaPath: "/properties/payment/discriminator/mapping/card/properties/last4/type" });
+        }
+      } else {
+        errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/payment/discriminator/mapping/card/properties/last4" });
+      }
+      break;
+    case "cash":
+      break;
+    default:
+      if (typeof instance["method"] === "string") {
+        errors.push({ instancePath: jslPointer([...path, "method"]), schemaPath: "/properties/payment/discriminator/mapping" });
+      } else {
+        errors.push({ instancePath: jslPointer([...path, "method"]), schemaPath: "/properties/payment/discriminator/tag" });
+      }
+  }
+}
+/**
+ * Where the order is at
+ */
+export type DescriptionsStatus = "pending" | "shipped";
+export function validateDescriptionsStatus(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateDescriptionsStatusAt(instance, [], errors);
+  return errors;
+}
+export function validateDescriptionsStatusAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (instance !== "pending" && instance !== "shipped") {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/status/enum" });
+  }
+}
+/**
+ * An order placed in the store
+ */
+export interface Descriptions {
+  /**
+   * What was ordered
+   */
+  items: LineItem[];
+  /**
+   * How the order was paid for
+   */
+  payment: DescriptionsPayment;
+  /**
+   * Where the order is at
+   */
+  status: DescriptionsStatus;
+  [key: string]: unknown;
+}
+export function validateDescriptions(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateDescriptionsAt(instance, [], errors);
+  return errors;
+}
+export function validateDescriptionsAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties" });
+    return;
+  }
+  if (jslHas(instance, "items")) {
+    const a0: unknown = instance["items"];
+    if (Array.isArray(a0)) {
+      a0.forEach((v0: unknown, i0: number) => {
+        validateLineItemAt(v0, [...path, "items", String(i0)], errors);
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "items"]), schemaPath: "/properties/items/elements" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/items" });
+  }
+  if (jslHas(instance, "payment")) {
+    validateDescriptionsPaymentAt(instance["payment"], [...path, "payment"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/payment" });
+  }
+  if (jslHas(instance, "status")) {
+    validateDescriptionsStatusAt(instance["status"], [...path, "status"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/status" });
+  }
+}
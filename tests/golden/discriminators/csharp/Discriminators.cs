@@ -0,0 +1,90 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Example;
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "type")]
+[JsonDerivedType(typeof(ShapeGroupLayoutGrid), "grid")]
+[JsonDerivedType(typeof(ShapeGroupLayoutStack), "stack")]
+public abstract record ShapeGroupLayout;
+
+public sealed record ShapeGroupLayoutGrid : ShapeGroupLayout
+{
+    [JsonPropertyName("columns")]
+    public required double Columns { get; init; }
+}
+
+public sealed record ShapeGroupLayoutStack : ShapeGroupLayout;
+
+[JsonConverter(typeof(JsonStringEnumConverter<ShapeSquareColor>))]
+public enum ShapeSquareColor
+{
+    [JsonStringEnumMemberName("blue")]
+    Blue,
+    [JsonStringEnumMemberName("green")]
+    Green,
+    [JsonStringEnumMemberName("red")]
+    Red,
+}
+
+/// <summary>
+/// A shape that can be drawn
+/// </summary>
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "kind")]
+[JsonDerivedType(typeof(ShapeCircle), "circle")]
+[JsonDerivedType(typeof(ShapeGroup), "group")]
+[JsonDerivedType(typeof(ShapeSquare), "square")]
+public abstract record Shape;
+
+/// <summary>
+/// A circle around a point
+/// </summary>
+public sealed record ShapeCircle : Shape
+{
+    [JsonPropertyName("radius")]
+    public required double Radius { get; init; }
+}
+
+public sealed record ShapeGroup : Shape
+{
+    [JsonPropertyName("layout")]
+    public required ShapeGroupLayout Layout { get; init; }
+
+    [JsonPropertyName("shapes")]
+    public required List<Shape> Shapes { get; init; }
+}
+
+public sealed record ShapeSquare : Shape
+{
+    [JsonPropertyName("side")]
+    public required double Side { get; init; }
+
+    [JsonPropertyName("color")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public ShapeSquareColor? Color { get; init; }
+}
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "event")]
+[JsonDerivedType(typeof(DiscriminatorsCleared), "cleared")]
+[JsonDerivedType(typeof(DiscriminatorsDrawn), "drawn")]
+public abstract record Discriminators;
+
+public sealed record DiscriminatorsCleared : Discriminators
+{
+    [JsonPropertyName("reason")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Reason { get; init; }
+}
+
+public sealed record DiscriminatorsDrawn : Discriminators
+{
+    [JsonPropertyName("at")]
+    public required DateTimeOffset At { get; init; }
+
+    [JsonPropertyName("shape")]
+    public required Shape Shape { get; init; }
+}
@@ -0,0 +1,222 @@
+sealed class ShapeGroupLayout {
+  const ShapeGroupLayout();
+
+  factory ShapeGroupLayout.fromJson(Map<String, dynamic> json) {
+    return switch (json["type"]) {
+      "grid" => ShapeGroupLayoutGrid.fromJson(json),
+      "stack" => ShapeGroupLayoutStack.fromJson(json),
+      _ => throw FormatException("unknown ShapeGroupLayout tag", json["type"]),
+    };
+  }
+
+  Map<String, dynamic> toJson();
+}
+
+class ShapeGroupLayoutGrid extends ShapeGroupLayout {
+  final double columns;
+
+  const ShapeGroupLayoutGrid({
+    required this.columns,
+  });
+
+  factory ShapeGroupLayoutGrid.fromJson(Map<String, dynamic> json) {
+    return ShapeGroupLayoutGrid(
+      columns: (json["columns"] as num).toDouble(),
+    );
+  }
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      "type": "grid",
+      "columns": columns,
+    };
+  }
+}
+
+class ShapeGroupLayoutStack extends ShapeGroupLayout {
+  const ShapeGroupLayoutStack();
+
+  factory ShapeGroupLayoutStack.fromJson(Map<String, dynamic> json) {
+    return const ShapeGroupLayoutStack();
+  }
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      "type": "stack",
+    };
+  }
+}
+
+enum ShapeSquareColor {
+  blue("blue"),
+  green("green"),
+  red("red");
+
+  const ShapeSquareColor(this.value);
+
+  final String value;
+
+  factory ShapeSquareColor.fromJson(String json) {
+    return values.firstWhere(
+      (value) => value.value == json,
+      orElse: () => throw FormatException("unknown ShapeSquareColor value", json),
+    );
+  }
+
+  String toJson() => value;
+}
+
+/// A shape that can be drawn
+sealed class Shape {
+  const Shape();
+
+  factory Shape.fromJson(Map<String, dynamic> json) {
+    return switch (json["kind"]) {
+      "circle" => ShapeCircle.fromJson(json),
+      "group" => ShapeGroup.fromJson(json),
+      "square" => ShapeSquare.fromJson(json),
+      _ => throw FormatException("unknown Shape tag", json["kind"]),
+    };
+  }
+
+  Map<String, dynamic> toJson();
+}
+
+/// A circle around a point
+class ShapeCircle extends Shape {
+  final double radius;
+
+  const ShapeCircle({
+    required this.radius,
+  });
+
+  factory ShapeCircle.fromJson(Map<String, dynamic> json) {
+    return ShapeCircle(
+      radius: (json["radius"] as num).toDouble(),
+    );
+  }
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      "kind": "circle",
+      "radius": radius,
+    };
+  }
+}
+
+class ShapeGroup extends Shape {
+  final ShapeGroupLayout layout;
+  final List<Shape> shapes;
+
+  const ShapeGroup({
+    required this.layout,
+    required this.shapes,
+  });
+
+  factory ShapeGroup.fromJson(Map<String, dynamic> json) {
+    return ShapeGroup(
+      layout: ShapeGroupLayout.fromJson(json["layout"] as Map<String, dynamic>),
+      shapes: (json["shapes"] as List<dynamic>).map((v0) => Shape.fromJson(v0 as Map<String, dynamic>)).toList(),
+    );
+  }
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      "kind": "group",
+      "layout": layout.toJson(),
+      "shapes": shapes.map((v0) => v0.toJson()).toList(),
+    };
+  }
+}
+
+class ShapeSquare extends Shape {
+  final double side;
+  final ShapeSquareColor? color;
+
+  const ShapeSquare({
+    required this.side,
+    this.color,
+  });
+
+  factory ShapeSquare.fromJson(Map<String, dynamic> json) {
+    return ShapeSquare(
+      side: (json["side"] as num).toDouble(),
+      color: json["color"] == null ? null : ShapeSquareColor.fromJson(json["color"] as String),
+    );
+  }
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      "kind": "square",
+      "side": side,
+      if (color != null) "color": color!.toJson(),
+    };
+  }
+}
+
+sealed class Discriminators {
+  const Discriminators();
+
+  factory Discriminators.fromJson(Map<String, dynamic> json) {
+    return switch (json["event"]) {
+      "cleared" => DiscriminatorsCleared.fromJson(json),
+      "drawn" => DiscriminatorsDrawn.fromJson(json),
+      _ => throw FormatException("unknown Discriminators tag", json["event"]),
+    };
+  }
+
+  Map<String, dynamic> toJson();
+}
+
+class DiscriminatorsCleared extends Discriminators {
+  final String? reason;
+
+  const DiscriminatorsCleared({
+    this.reason,
+  });
+
+  factory DiscriminatorsCleared.fromJson(Map<String, dynamic> json) {
+    return DiscriminatorsCleared(
+      reason: json["reason"] as String?,
+    );
+  }
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      "event": "cleared",
+      if (reason != null) "reason": reason,
+    };
+  }
+}
+
+class DiscriminatorsDrawn extends Discriminators {
+  final DateTime at;
+  final Shape shape;
+
+  const DiscriminatorsDrawn({
+    required this.at,
+    required this.shape,
+  });
+
+  factory DiscriminatorsDrawn.fromJson(Map<String, dynamic> json) {
+    return DiscriminatorsDrawn(
+      at: DateTime.parse(json["at"] as String),
+      shape: Shape.fromJson(json["shape"] as Map<String, dynamic>),
+    );
+  }
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      "event": "drawn",
+      "at": at.toUtc().toIso8601String(),
+      "shape": shape.toJson(),
+    };
+  }
+}
@@ -0,0 +1,422 @@
+package models
+
+import (
+	"encoding/json"
+	"strconv"
+	"time"
+)
+
+type ShapeGroupLayoutGrid struct {
+	Columns float64 `json:"columns"`
+}
+
+type ShapeGroupLayoutStack struct {
+}
+
+type ShapeGroupLayout struct {
+	Type string `json:"type"`
+	Val interface{} `json:"-"`
+}
+
+func (s *ShapeGroupLayout) UnmarshalJSON(buf []byte) error {
+	var x struct{ Tag string `json:"type"` }
+	if err := json.Unmarshal(buf, &x); err != nil {
+		return err
+	}
+	switch x.Tag {
+	case "grid":
+		var data ShapeGroupLayoutGrid
+		if err := json.Unmarshal(buf, &data); err != nil {
+			return err
+		}
+		s.Val = data
+	case "stack":
+		var data ShapeGroupLayoutStack
+		if err := json.Unmarshal(buf, &data); err != nil {
+			return err
+		}
+		s.Val = data
+	}
+	s.Type = x.Tag
+	return nil
+}
+
+func (s ShapeGroupLayout) MarshalJSON() ([]byte, error) {
+	switch val := s.Val.(type) {
+	case ShapeGroupLayoutGrid:
+		var data struct{
+			Tag string `json:"type"`
+			ShapeGroupLayoutGrid
+		}
+		data.ShapeGroupLayoutGrid = val
+		data.Tag = s.Type
+		return json.Marshal(data)
+	case ShapeGroupLayoutStack:
+		var data struct{
+			Tag string `json:"type"`
+			ShapeGroupLayoutStack
+		}
+		data.ShapeGroupLayoutStack = val
+		data.Tag = s.Type
+		return json.Marshal(data)
+	}
+	panic("invalid discriminator tag")
+}
+
+func ValidateShapeGroupLayout(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateShapeGroupLayout(instance, nil, &errors)
+	return errors
+}
+
+func validateShapeGroupLayout(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/shape/discriminator/mapping/group/properties/layout/discriminator"})
+		return
+	}
+	tag, ok := obj["type"]
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/shape/discriminator/mapping/group/properties/layout/discriminator/tag"})
+		return
+	}
+	switch tag {
+	case "grid":
+		if v0, ok := obj["columns"]; ok {
+			if _, ok := v0.(float64); !ok {
+				*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "columns")), SchemaPath: "/definitions/shape/discriminator/mapping/group/properties/layout/discriminator/mapping/grid/properties/columns/type"})
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/shape/discriminator/mapping/group/properties/layout/discriminator/mapping/grid/properties/columns"})
+		}
+	case "stack":
+	default:
+		if _, ok := tag.(string); ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "type")), SchemaPath: "/definitions/shape/discriminator/mapping/group/properties/layout/discriminator/mapping"})
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "type")), SchemaPath: "/definitions/shape/discriminator/mapping/group/properties/layout/discriminator/tag"})
+		}
+	}
+}
+
+func DecodeShapeGroupLayout(data []byte) (ShapeGroupLayout, error) {
+	var out ShapeGroupLayout
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateShapeGroupLayout(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type ShapeSquareColor string
+
+const (
+	ShapeSquareColorBlue ShapeSquareColor = "blue"
+	ShapeSquareColorGreen ShapeSquareColor = "green"
+	ShapeSquareColorRed ShapeSquareColor = "red"
+)
+
+func ValidateShapeSquareColor(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateShapeSquareColor(instance, nil, &errors)
+	return errors
+}
+
+func validateShapeSquareColor(instance interface{}, path []string, errors *[]ValidationError) {
+	switch instance {
+	case "blue", "green", "red":
+	default:
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/shape/discriminator/mapping/square/optionalProperties/color/enum"})
+	}
+}
+
+func DecodeShapeSquareColor(data []byte) (ShapeSquareColor, error) {
+	var out ShapeSquareColor
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateShapeSquareColor(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+// A circle around a point
+type ShapeCircle struct {
+	Radius float64 `json:"radius"`
+}
+
+type ShapeGroup struct {
+	Layout ShapeGroupLayout `json:"layout"`
+	Shapes []Shape `json:"shapes"`
+}
+
+type ShapeSquare struct {
+	Side float64 `json:"side"`
+	Color *ShapeSquareColor `json:"color,omitempty"`
+}
+
+// A shape that can be drawn
+type Shape struct {
+	Kind string `json:"kind"`
+	Val interface{} `json:"-"`
+}
+
+func (s *Shape) UnmarshalJSON(buf []byte) error {
+	var x struct{ Tag string `json:"kind"` }
+	if err := json.Unmarshal(buf, &x); err != nil {
+		return err
+	}
+	switch x.Tag {
+	case "circle":
+		var data ShapeCircle
+		if err := json.Unmarshal(buf, &data); err != nil {
+			return err
+		}
+		s.Val = data
+	case "group":
+		var data ShapeGroup
+		if err := json.Unmarshal(buf, &data); err != nil {
+			return err
+		}
+		s.Val = data
+	case "square":
+		var data ShapeSquare
+		if err := json.Unmarshal(buf, &data); err != nil {
+			return err
+		}
+		s.Val = data
+	}
+	s.Kind = x.Tag
+	return nil
+}
+
+func (s Shape) MarshalJSON() ([]byte, error) {
+	switch val := s.Val.(type) {
+	case ShapeCircle:
+		var data struct{
+			Tag string `json:"kind"`
+			ShapeCircle
+		}
+		data.ShapeCircle = val
+		data.Tag = s.Kind
+		return json.Marshal(data)
+	case ShapeGroup:
+		var data struct{
+			Tag string `json:"kind"`
+			ShapeGroup
+		}
+		data.ShapeGroup = val
+		data.Tag = s.Kind
+		return json.Marshal(data)
+	case ShapeSquare:
+		var data struct{
+			Tag string `json:"kind"`
+			ShapeSquare
+		}
+		data.ShapeSquare = val
+		data.Tag = s.Kind
+		return json.Marshal(data)
+	}
+	panic("invalid discriminator tag")
+}
+
+func ValidateShape(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateShape(instance, nil, &errors)
+	return errors
+}
+
+func validateShape(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/shape/discriminator"})
+		return
+	}
+	tag, ok := obj["kind"]
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/shape/discriminator/tag"})
+		return
+	}
+	switch tag {
+	case "circle":
+		if v0, ok := obj["radius"]; ok {
+			if _, ok := v0.(float64); !ok {
+				*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "radius")), SchemaPath: "/definitions/shape/discriminator/mapping/circle/properties/radius/type"})
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/shape/discriminator/mapping/circle/properties/radius"})
+		}
+	case "group":
+		if v0, ok := obj["layout"]; ok {
+			validateShapeGroupLayout(v0, jslAppend(path, "layout"), errors)
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/shape/discriminator/mapping/group/properties/layout"})
+		}
+		if v0, ok := obj["shapes"]; ok {
+			if a0, ok := v0.([]interface{}); ok {
+				for i0, v1 := range a0 {
+					validateShape(v1, jslAppend(path, "shapes", strconv.Itoa(i0)), errors)
+				}
+			} else {
+				*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "shapes")), SchemaPath: "/definitions/shape/discriminator/mapping/group/properties/shapes/elements"})
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/shape/discriminator/mapping/group/properties/shapes"})
+		}
+	case "square":
+		if v0, ok := obj["side"]; ok {
+			if _, ok := v0.(float64); !ok {
+				*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "side")), SchemaPath: "/definitions/shape/discriminator/mapping/square/properties/side/type"})
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/shape/discriminator/mapping/square/properties/side"})
+		}
+		if v0, ok := obj["color"]; ok {
+			validateShapeSquareColor(v0, jslAppend(path, "color"), errors)
+		}
+	default:
+		if _, ok := tag.(string); ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "kind")), SchemaPath: "/definitions/shape/discriminator/mapping"})
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "kind")), SchemaPath: "/definitions/shape/discriminator/tag"})
+		}
+	}
+}
+
+func DecodeShape(data []byte) (Shape, error) {
+	var out Shape
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateShape(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type DiscriminatorsCleared struct {
+	Reason *string `json:"reason,omitempty"`
+}
+
+type DiscriminatorsDrawn struct {
+	At time.Time `json:"at"`
+	Shape Shape `json:"shape"`
+}
+
+type Discriminators struct {
+	Event string `json:"event"`
+	Val interface{} `json:"-"`
+}
+
+func (s *Discriminators) UnmarshalJSON(buf []byte) error {
+	var x struct{ Tag string `json:"event"` }
+	if err := json.Unmarshal(buf, &x); err != nil {
+		return err
+	}
+	switch x.Tag {
+	case "cleared":
+		var data DiscriminatorsCleared
+		if err := json.Unmarshal(buf, &data); err != nil {
+			return err
+		}
+		s.Val = data
+	case "drawn":
+		var data DiscriminatorsDrawn
+		if err := json.Unmarshal(buf, &data); err != nil {
+			return err
+		}
+		s.Val = data
+	}
+	s.Event = x.Tag
+	return nil
+}
+
+func (s Discriminators) MarshalJSON() ([]byte, error) {
+	switch val := s.Val.(type) {
+	case DiscriminatorsCleared:
+		var data struct{
+			Tag string `json:"event"`
+			DiscriminatorsCleared
+		}
+		data.DiscriminatorsCleared = val
+		data.Tag = s.Event
+		return json.Marshal(data)
+	case DiscriminatorsDrawn:
+		var data struct{
+			Tag string `json:"event"`
+			DiscriminatorsDrawn
+		}
+		data.DiscriminatorsDrawn = val
+		data.Tag = s.Event
+		return json.Marshal(data)
+	}
+	panic("invalid discriminator tag")
+}
+
+func ValidateDiscriminators(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateDiscriminators(instance, nil, &errors)
+	return errors
+}
+
+func validateDiscriminators(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/discriminator"})
+		return
+	}
+	tag, ok := obj["event"]
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/discriminator/tag"})
+		return
+	}
+	switch tag {
+	case "cleared":
+		if v0, ok := obj["reason"]; ok {
+			if _, ok := v0.(string); !ok {
+				*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "reason")), SchemaPath: "/discriminator/mapping/cleared/optionalProperties/reason/type"})
+			}
+		}
+	case "drawn":
+		if v0, ok := obj["at"]; ok {
+			if !jslIsTimestamp(v0) {
+				*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "at")), SchemaPath: "/discriminator/mapping/drawn/properties/at/type"})
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/discriminator/mapping/drawn/properties/at"})
+		}
+		if v0, ok := obj["shape"]; ok {
+			validateShape(v0, jslAppend(path, "shape"), errors)
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/discriminator/mapping/drawn/properties/shape"})
+		}
+	default:
+		if _, ok := tag.(string); ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "event")), SchemaPath: "/discriminator/mapping"})
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "event")), SchemaPath: "/discriminator/tag"})
+		}
+	}
+}
+
+func DecodeDiscriminators(data []byte) (Discriminators, error) {
+	var out Discriminators
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateDiscriminators(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
@@ -0,0 +1,66 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonSubTypes;
+import com.fasterxml.jackson.annotation.JsonTypeInfo;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "event")
+@JsonSubTypes({
+    @JsonSubTypes.Type(value = DiscriminatorsCleared.class, name = "cleared"),
+    @JsonSubTypes.Type(value = DiscriminatorsDrawn.class, name = "drawn"),
+})
+public abstract class Discriminators {
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/discriminator"));
+            return;
+        }
+        if (!instance.has("event")) {
+            errors.add(new ValidationError(path, "/discriminator/tag"));
+            return;
+        }
+        if (!instance.get("event").isTextual()) {
+            errors.add(new ValidationError(ValidationError.append(path, "event"), "/discriminator/tag"));
+            return;
+        }
+
+        switch (instance.get("event").asText()) {
+        case "cleared":
+            if (instance.has("reason")) {
+                JsonNode v0 = instance.get("reason");
+                if (!v0.isTextual()) {
+                    errors.add(new ValidationError(ValidationError.append(path, "reason"), "/discriminator/mapping/cleared/optionalProperties/reason/type"));
+                }
+            }
+            break;
+        case "drawn":
+            if (instance.has("at")) {
+                JsonNode v0 = instance.get("at");
+                if (!ValidationError.isTimestamp(v0)) {
+                    errors.add(new ValidationError(ValidationError.append(path, "at"), "/discriminator/mapping/drawn/properties/at/type"));
+                }
+            } else {
+                errors.add(new ValidationError(path, "/discriminator/mapping/drawn/properties/at"));
+            }
+            if (instance.has("shape")) {
+                JsonNode v0 = instance.get("shape");
+                Shape.validate(v0, ValidationError.append(path, "shape"), errors);
+            } else {
+                errors.add(new ValidationError(path, "/discriminator/mapping/drawn/properties/shape"));
+            }
+            break;
+        default:
+            errors.add(new ValidationError(ValidationError.append(path, "event"), "/discriminator/mapping"));
+        }
+    }
+}
@@ -0,0 +1,12 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import java.time.Instant;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class DiscriminatorsDrawn extends Discriminators {
+    public Instant at;
+    public Shape shape;
+}
@@ -0,0 +1,91 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonSubTypes;
+import com.fasterxml.jackson.annotation.JsonTypeInfo;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * A shape that can be drawn
+ */
+@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "kind")
+@JsonSubTypes({
+    @JsonSubTypes.Type(value = ShapeCircle.class, name = "circle"),
+    @JsonSubTypes.Type(value = ShapeGroup.class, name = "group"),
+    @JsonSubTypes.Type(value = ShapeSquare.class, name = "square"),
+})
+public abstract class Shape {
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/definitions/shape/discriminator"));
+            return;
+        }
+        if (!instance.has("kind")) {
+            errors.add(new ValidationError(path, "/definitions/shape/discriminator/tag"));
+            return;
+        }
+        if (!instance.get("kind").isTextual()) {
+            errors.add(new ValidationError(ValidationError.append(path, "kind"), "/definitions/shape/discriminator/tag"));
+            return;
+        }
+
+        switch (instance.get("kind").asText()) {
+        case "circle":
+            if (instance.has("radius")) {
+                JsonNode v0 = instance.get("radius");
+                if (!v0.isNumber()) {
+                    errors.add(new ValidationError(ValidationError.append(path, "radius"), "/definitions/shape/discriminator/mapping/circle/properties/radius/type"));
+                }
+            } else {
+                errors.add(new ValidationError(path, "/definitions/shape/discriminator/mapping/circle/properties/radius"));
+            }
+            break;
+        case "group":
+            if (instance.has("layout")) {
+                JsonNode v0 = instance.get("layout");
+                ShapeGroupLayout.validate(v0, ValidationError.append(path, "layout"), errors);
+            } else {
+                errors.add(new ValidationError(path, "/definitions/shape/discriminator/mapping/group/properties/layout"));
+            }
+            if (instance.has("shapes")) {
+                JsonNode v0 = instance.get("shapes");
+                if (v0.isArray()) {
+                    for (int i0 = 0; i0 < v0.size(); i0++) {
+                        JsonNode v1 = v0.get(i0);
+                        Shape.validate(v1, ValidationError.append(path, "shapes", Integer.toString(i0)), errors);
+                    }
+                } else {
+                    errors.add(new ValidationError(ValidationError.append(path, "shapes"), "/definitions/shape/discriminator/mapping/group/properties/shapes/elements"));
+                }
+            } else {
+                errors.add(new ValidationError(path, "/definitions/shape/discriminator/mapping/group/properties/shapes"));
+            }
+            break;
+        case "square":
+            if (instance.has("side")) {
+                JsonNode v0 = instance.get("side");
+                if (!v0.isNumber()) {
+                    errors.add(new ValidationError(ValidationError.append(path, "side"), "/definitions/shape/discriminator/mapping/square/properties/side/type"));
+                }
+            } else {
+                errors.add(new ValidationError(path, "/definitions/shape/discriminator/mapping/square/properties/side"));
+            }
+            if (instance.has("color")) {
+                JsonNode v0 = instance.get("color");
+                ShapeSquareColor.validate(v0, ValidationError.append(path, "color"), errors);
+            }
+            break;
+        default:
+            errors.add(new ValidationError(ValidationError.append(path, "kind"), "/definitions/shape/discriminator/mapping"));
+        }
+    }
+}
@@ -0,0 +1,14 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import java.time.Instant;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * A circle around a point
+ */
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class ShapeCircle extends Shape {
+    public double radius;
+}
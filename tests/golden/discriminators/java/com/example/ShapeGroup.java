@@ -0,0 +1,12 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import java.time.Instant;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class ShapeGroup extends Shape {
+    public ShapeGroupLayout layout;
+    public List<Shape> shapes;
+}
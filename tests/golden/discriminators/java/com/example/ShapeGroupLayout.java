@@ -0,0 +1,54 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonSubTypes;
+import com.fasterxml.jackson.annotation.JsonTypeInfo;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type")
+@JsonSubTypes({
+    @JsonSubTypes.Type(value = ShapeGroupLayoutGrid.class, name = "grid"),
+    @JsonSubTypes.Type(value = ShapeGroupLayoutStack.class, name = "stack"),
+})
+public abstract class ShapeGroupLayout {
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/definitions/shape/discriminator/mapping/group/properties/layout/discriminator"));
+            return;
+        }
+        if (!instance.has("type")) {
+            errors.add(new ValidationError(path, "/definitions/shape/discriminator/mapping/group/properties/layout/discriminator/tag"));
+            return;
+        }
+        if (!instance.get("type").isTextual()) {
+            errors.add(new ValidationError(ValidationError.append(path, "type"), "/definitions/shape/discriminator/mapping/group/properties/layout/discriminator/tag"));
+            return;
+        }
+
+        switch (instance.get("type").asText()) {
+        case "grid":
+            if (instance.has("columns")) {
+                JsonNode v0 = instance.get("columns");
+                if (!v0.isNumber()) {
+                    errors.add(new ValidationError(ValidationError.append(path, "columns"), "/definitions/shape/discriminator/mapping/group/properties/layout/discriminator/mapping/grid/properties/columns/type"));
+                }
+            } else {
+                errors.add(new ValidationError(path, "/definitions/shape/discriminator/mapping/group/properties/layout/discriminator/mapping/grid/properties/columns"));
+            }
+            break;
+        case "stack":
+            break;
+        default:
+            errors.add(new ValidationError(ValidationError.append(path, "type"), "/definitions/shape/discriminator/mapping/group/properties/layout/discriminator/mapping"));
+        }
+    }
+}
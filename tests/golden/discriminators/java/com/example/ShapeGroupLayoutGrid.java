@@ -0,0 +1,11 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import java.time.Instant;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class ShapeGroupLayoutGrid extends ShapeGroupLayout {
+    public double columns;
+}
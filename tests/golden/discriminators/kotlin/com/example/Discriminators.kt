@@ -0,0 +1,87 @@
+@file:OptIn(ExperimentalSerializationApi::class)
+
+package com.example
+
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.json.JsonClassDiscriminator
+import kotlinx.serialization.json.JsonIgnoreUnknownKeys
+
+@Serializable
+@JsonClassDiscriminator("type")
+sealed class ShapeGroupLayout
+
+@Serializable
+@SerialName("grid")
+@JsonIgnoreUnknownKeys
+data class ShapeGroupLayoutGrid(
+    val columns: Double,
+) : ShapeGroupLayout()
+
+@Serializable
+@SerialName("stack")
+@JsonIgnoreUnknownKeys
+class ShapeGroupLayoutStack : ShapeGroupLayout()
+
+@Serializable
+enum class ShapeSquareColor {
+    @SerialName("blue")
+    BLUE,
+    @SerialName("green")
+    GREEN,
+    @SerialName("red")
+    RED,
+}
+
+/**
+ * A shape that can be drawn
+ */
+@Serializable
+@JsonClassDiscriminator("kind")
+sealed class Shape
+
+/**
+ * A circle around a point
+ */
+@Serializable
+@SerialName("circle")
+@JsonIgnoreUnknownKeys
+data class ShapeCircle(
+    val radius: Double,
+) : Shape()
+
+@Serializable
+@SerialName("group")
+@JsonIgnoreUnknownKeys
+data class ShapeGroup(
+    val layout: ShapeGroupLayout,
+    val shapes: List<Shape>,
+) : Shape()
+
+@Serializable
+@SerialName("square")
+@JsonIgnoreUnknownKeys
+data class ShapeSquare(
+    val side: Double,
+    val color: ShapeSquareColor? = null,
+) : Shape()
+
+@Serializable
+@JsonClassDiscriminator("event")
+sealed class Discriminators
+
+@Serializable
+@SerialName("cleared")
+@JsonIgnoreUnknownKeys
+data class DiscriminatorsCleared(
+    val reason: String? = null,
+) : Discriminators()
+
+@Serializable
+@SerialName("drawn")
+@JsonIgnoreUnknownKeys
+data class DiscriminatorsDrawn(
+    val at: String,
+    val shape: Shape,
+) : Discriminators()
@@ -0,0 +1,184 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from datetime import datetime
+from enum import Enum
+from typing import Any, Dict, List, Optional
+
+
+def _parse_timestamp(value: str) -> datetime:
+    return datetime.fromisoformat(value.replace("Z", "+00:00"))
+
+
+@dataclass
+class ShapeGroupLayout:
+    @staticmethod
+    def from_json(data: Dict[str, Any]) -> ShapeGroupLayout:
+        variants = {
+            "grid": ShapeGroupLayoutGrid,
+            "stack": ShapeGroupLayoutStack,
+        }
+
+        return variants[data["type"]].from_json(data)
+
+    def to_json(self) -> Dict[str, Any]:
+        raise NotImplementedError
+
+
+@dataclass
+class ShapeGroupLayoutGrid(ShapeGroupLayout):
+    columns: float
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> ShapeGroupLayoutGrid:
+        return cls(
+            columns=data["columns"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {"type": "grid"}
+        data["columns"] = self.columns
+        return data
+
+
+@dataclass
+class ShapeGroupLayoutStack(ShapeGroupLayout):
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> ShapeGroupLayoutStack:
+        return cls(
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {"type": "stack"}
+        return data
+
+
+class ShapeSquareColor(Enum):
+    BLUE = "blue"
+    GREEN = "green"
+    RED = "red"
+
+
+@dataclass
+class Shape:
+    """A shape that can be drawn"""
+
+    @staticmethod
+    def from_json(data: Dict[str, Any]) -> Shape:
+        variants = {
+            "circle": ShapeCircle,
+            "group": ShapeGroup,
+            "square": ShapeSquare,
+        }
+
+        return variants[data["kind"]].from_json(data)
+
+    def to_json(self) -> Dict[str, Any]:
+        raise NotImplementedError
+
+
+@dataclass
+class ShapeCircle(Shape):
+    """A circle around a point"""
+    radius: float
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> ShapeCircle:
+        return cls(
+            radius=data["radius"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {"kind": "circle"}
+        data["radius"] = self.radius
+        return data
+
+
+@dataclass
+class ShapeGroup(Shape):
+    layout: ShapeGroupLayout
+    shapes: List[Shape]
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> ShapeGroup:
+        return cls(
+            layout=ShapeGroupLayout.from_json(data["layout"]),
+            shapes=[Shape.from_json(v0) for v0 in data["shapes"]],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {"kind": "group"}
+        data["layout"] = self.layout.to_json()
+        data["shapes"] = [v0.to_json() for v0 in self.shapes]
+        return data
+
+
+@dataclass
+class ShapeSquare(Shape):
+    side: float
+    color: Optional[ShapeSquareColor] = None
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> ShapeSquare:
+        return cls(
+            side=data["side"],
+            color=ShapeSquareColor(data["color"]) if "color" in data else None,
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {"kind": "square"}
+        data["side"] = self.side
+        if self.color is not None:
+            data["color"] = self.color.value
+        return data
+
+
+@dataclass
+class Discriminators:
+    @staticmethod
+    def from_json(data: Dict[str, Any]) -> Discriminators:
+        variants = {
+            "cleared": DiscriminatorsCleared,
+            "drawn": DiscriminatorsDrawn,
+        }
+
+        return variants[data["event"]].from_json(data)
+
+    def to_json(self) -> Dict[str, Any]:
+        raise NotImplementedError
+
+
+@dataclass
+class DiscriminatorsCleared(Discriminators):
+    reason: Optional[str] = None
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> DiscriminatorsCleared:
+        return cls(
+            reason=data.get("reason"),
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {"event": "cleared"}
+        if self.reason is not None:
+            data["reason"] = self.reason
+        return data
+
+
+@dataclass
+class DiscriminatorsDrawn(Discriminators):
+    at: datetime
+    shape: Shape
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> DiscriminatorsDrawn:
+        return cls(
+            at=_parse_timestamp(data["at"]),
+            shape=Shape.from_json(data["shape"]),
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {"event": "drawn"}
+        data["at"] = self.at.isoformat()
+        data["shape"] = self.shape.to_json()
+        return data
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShapeGroupLayoutGrid {
+    pub columns: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShapeGroupLayoutStack {
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum ShapeGroupLayout {
+    #[serde(rename = "grid")]
+    Grid(ShapeGroupLayoutGrid),
+    #[serde(rename = "stack")]
+    Stack(ShapeGroupLayoutStack),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ShapeSquareColor {
+    #[serde(rename = "blue")]
+    Blue,
+    #[serde(rename = "green")]
+    Green,
+    #[serde(rename = "red")]
+    Red,
+}
+
+/// A circle around a point
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShapeCircle {
+    pub radius: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShapeGroup {
+    pub layout: ShapeGroupLayout,
+    pub shapes: Vec<Shape>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShapeSquare {
+    pub side: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub color: Option<ShapeSquareColor>,
+}
+
+/// A shape that can be drawn
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+pub enum Shape {
+    #[serde(rename = "circle")]
+    Circle(ShapeCircle),
+    #[serde(rename = "group")]
+    Group(ShapeGroup),
+    #[serde(rename = "square")]
+    Square(ShapeSquare),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiscriminatorsCleared {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DiscriminatorsDrawn {
+    pub at: chrono::DateTime<chrono::Utc>,
+    pub shape: Shape,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "event")]
+pub enum Discriminators {
+    #[serde(rename = "cleared")]
+    Cleared(DiscriminatorsCleared),
+    #[serde(rename = "drawn")]
+    Drawn(DiscriminatorsDrawn),
+}
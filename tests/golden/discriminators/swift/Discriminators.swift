@@ -0,0 +1,147 @@
+import Foundation
+
+struct ShapeGroupLayoutGrid: Codable, Equatable {
+    var columns: Double
+}
+
+struct ShapeGroupLayoutStack: Codable, Equatable {
+}
+
+enum ShapeGroupLayout: Codable, Equatable {
+    case grid(ShapeGroupLayoutGrid)
+    case stack(ShapeGroupLayoutStack)
+
+    enum CodingKeys: String, CodingKey {
+        case tag = "type"
+    }
+
+    init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .tag)
+        switch tag {
+        case "grid":
+            self = .grid(try ShapeGroupLayoutGrid(from: decoder))
+        case "stack":
+            self = .stack(try ShapeGroupLayoutStack(from: decoder))
+        default:
+            throw DecodingError.dataCorruptedError(forKey: .tag, in: container, debugDescription: "Unknown tag: \(tag)")
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case .grid(let value):
+            try container.encode("grid", forKey: .tag)
+            try value.encode(to: encoder)
+        case .stack(let value):
+            try container.encode("stack", forKey: .tag)
+            try value.encode(to: encoder)
+        }
+    }
+}
+
+enum ShapeSquareColor: String, Codable, Equatable {
+    case blue
+    case green
+    case red
+}
+
+/// A circle around a point
+struct ShapeCircle: Codable, Equatable {
+    var radius: Double
+}
+
+struct ShapeGroup: Codable, Equatable {
+    var layout: ShapeGroupLayout
+    var shapes: [Shape]
+}
+
+struct ShapeSquare: Codable, Equatable {
+    var side: Double
+    var color: ShapeSquareColor?
+}
+
+/// A shape that can be drawn
+enum Shape: Codable, Equatable {
+    case circle(ShapeCircle)
+    case group(ShapeGroup)
+    case square(ShapeSquare)
+
+    enum CodingKeys: String, CodingKey {
+        case tag = "kind"
+    }
+
+    init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .tag)
+        switch tag {
+        case "circle":
+            self = .circle(try ShapeCircle(from: decoder))
+        case "group":
+            self = .group(try ShapeGroup(from: decoder))
+        case "square":
+            self = .square(try ShapeSquare(from: decoder))
+        default:
+            throw DecodingError.dataCorruptedError(forKey: .tag, in: container, debugDescription: "Unknown tag: \(tag)")
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case .circle(let value):
+            try container.encode("circle", forKey: .tag)
+            try value.encode(to: encoder)
+        case .group(let value):
+            try container.encode("group", forKey: .tag)
+            try value.encode(to: encoder)
+        case .square(let value):
+            try container.encode("square", forKey: .tag)
+            try value.encode(to: encoder)
+        }
+    }
+}
+
+struct DiscriminatorsCleared: Codable, Equatable {
+    var reason: String?
+}
+
+struct DiscriminatorsDrawn: Codable, Equatable {
+    var at: String
+    var shape: Shape
+}
+
+enum Discriminators: Codable, Equatable {
+    case cleared(DiscriminatorsCleared)
+    case drawn(DiscriminatorsDrawn)
+
+    enum CodingKeys: String, CodingKey {
+        case tag = "event"
+    }
+
+    init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .tag)
+        switch tag {
+        case "cleared":
+            self = .cleared(try DiscriminatorsCleared(from: decoder))
+        case "drawn":
+            self = .drawn(try DiscriminatorsDrawn(from: decoder))
+        default:
+            throw DecodingError.dataCorruptedError(forKey: .tag, in: container, debugDescription: "Unknown tag: \(tag)")
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case .cleared(let value):
+            try container.encode("cleared", forKey: .tag)
+            try value.encode(to: encoder)
+        case .drawn(let value):
+            try container.encode("drawn", forKey: .tag)
+            try value.encode(to: encoder)
+        }
+    }
+}
@@ -0,0 +1,75 @@
+import Foundation
+
+/// Any JSON value.
+enum JSONValue: Codable, Equatable {
+    case null
+    case bool(Bool)
+    case number(Double)
+    case string(String)
+    case array([JSONValue])
+    case object([String: JSONValue])
+
+    init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if container.decodeNil() {
+            self = .null
+        } else if let value = try? container.decode(Bool.self) {
+            self = .bool(value)
+        } else if let value = try? container.decode(Double.self) {
+            self = .number(value)
+        } else if let value = try? container.decode(String.self) {
+            self = .string(value)
+        } else if let value = try? container.decode([JSONValue].self) {
+            self = .array(value)
+        } else {
+            self = .object(try container.decode([String: JSONValue].self))
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case .null:
+            try container.encodeNil()
+        case .bool(let value):
+            try container.encode(value)
+        case .number(let value):
+            try container.encode(value)
+        case .string(let value):
+            try container.encode(value)
+        case .array(let value):
+            try container.encode(value)
+        case .object(let value):
+            try container.encode(value)
+        }
+    }
+}
+
+/// A value stored out of line, so that types can contain themselves.
+indirect enum Indirect<Value: Codable & Equatable>: Codable, Equatable {
+    case wrapped(Value)
+
+    init(_ value: Value) {
+        self = .wrapped(value)
+    }
+
+    var value: Value {
+        get {
+            switch self {
+            case .wrapped(let value):
+                return value
+            }
+        }
+        set {
+            self = .wrapped(newValue)
+        }
+    }
+
+    init(from decoder: Decoder) throws {
+        self = .wrapped(try Value(from: decoder))
+    }
+
+    func encode(to encoder: Encoder) throws {
+        try value.encode(to: encoder)
+    }
+}
@@ -0,0 +1,214 @@
+export interface ValidationError {
+  instancePath: string;
+  schemaPath: string;
+}
+function jslPointer(path: string[]): string {
+  return path.map((token) => "/" + token.replace(/~/g, "~0").replace(/\//g, "~1")).join("");
+}
+function jslIsObject(instance: unknown): instance is { [key: string]: unknown } {
+  return typeof instance === "object" && instance !== null && !Array.isArray(instance);
+}
+function jslHas(instance: { [key: string]: unknown }, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(instance, key);
+}
+function jslIsTimestamp(instance: unknown): boolean {
+  return typeof instance === "string" && /^\d{4}-\d{2}-\d{2}[Tt]\d{2}:\d{2}:\d{2}(\.\d+)?([Zz]|[+-]\d{2}:\d{2})$/.test(instance) && !isNaN(Date.parse(instance));
+}
+export interface ShapeGroupLayoutGrid {
+  type: "grid";
+  columns: number;
+  [key: string]: unknown;
+}
+export interface ShapeGroupLayoutStack {
+  type: "stack";
+  [key: string]: unknown;
+}
+export type ShapeGroupLayout = ShapeGroupLayoutGrid | ShapeGroupLayoutStack;
+export function validateShapeGroupLayout(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateShapeGroupLayoutAt(instance, [], errors);
+  return errors;
+}
+export function validateShapeGroupLayoutAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/shape/discriminator/mapping/group/properties/layout/discriminator" });
+    return;
+  }
+  if (!jslHas(instance, "type")) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/shape/discriminator/mapping/group/properties/layout/discriminator/tag" });
+    return;
+  }
+  switch (instance["type"]) {
+    case "grid":
+      if (jslHas(instance, "columns")) {
+        if (typeof instance["columns"] !== "number") {
+          errors.push({ instancePath: jslPointer([...path, "columns"]), schemaPath: "/definitions/shape/discriminator/mapping/group/properties/layout/discriminator/mapping/grid/properties/columns/type" });
+        }
+      } else {
+        errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/shape/discriminator/mapping/group/properties/layout/discriminator/mapping/grid/properties/columns" });
+      }
+      break;
+    case "stack":
+      break;
+    default:
+      if (typeof instance["type"] === "string") {
+        errors.push({ instancePath: jslPointer([...path, "type"]), schemaPath: "/definitions/shape/discriminator/mapping/group/properties/layout/discriminator/mapping" });
+      } else {
+        errors.push({ instancePath: jslPointer([...path, "type"]), schemaPath: "/definitions/shape/discriminator/mapping/group/properties/layout/discriminator/tag" });
+      }
+  }
+}
+export type ShapeSquareColor = "blue" | "green" | "red";
+export function validateShapeSquareColor(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateShapeSquareColorAt(instance, [], errors);
+  return errors;
+}
+export function validateShapeSquareColorAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (instance !== "blue" && instance !== "green" && instance !== "red") {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/shape/discriminator/mapping/square/optionalProperties/color/enum" });
+  }
+}
+/**
+ * A circle around a point
+ */
+export interface ShapeCircle {
+  kind: "circle";
+  radius: number;
+  [key: string]: unknown;
+}
+export interface ShapeGroup {
+  kind: "group";
+  layout: ShapeGroupLayout;
+  shapes: Shape[];
+  [key: string]: unknown;
+}
+export interface ShapeSquare {
+  kind: "square";
+  side: number;
+  color?: ShapeSquareColor;
+  [key: string]: unknown;
+}
+/**
+ * A shape that can be drawn
+ */
+export type Shape = ShapeCircle | ShapeGroup | ShapeSquare;
+export function validateShape(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateShapeAt(instance, [], errors);
+  return errors;
+}
+export function validateShapeAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/shape/discriminator" });
+    return;
+  }
+  if (!jslHas(instance, "kind")) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/shape/discriminator/tag" });
+    return;
+  }
+  switch (instance["kind"]) {
+    case "circle":
+      if (jslHas(instance, "radius")) {
+        if (typeof instance["radius"] !== "number") {
+          errors.push({ instancePath: jslPointer([...path, "radius"]), schemaPath: "/definitions/shape/discriminator/mapping/circle/properties/radius/type" });
+        }
+      } else {
+        errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/shape/discriminator/mapping/circle/properties/radius" });
+      }
+      break;
+    case "group":
+      if (jslHas(instance, "layout")) {
+        validateShapeGroupLayoutAt(instance["layout"], [...path, "layout"], errors);
+      } else {
+        errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/shape/discriminator/mapping/group/properties/layout" });
+      }
+      if (jslHas(instance, "shapes")) {
+        const a0: unknown = instance["shapes"];
+        if (Array.isArray(a0)) {
+          a0.forEach((v0: unknown, i0: number) => {
+            validateShapeAt(v0, [...path, "shapes", String(i0)], errors);
+          });
+        } else {
+          errors.push({ instancePath: jslPointer([...path, "shapes"]), schemaPath: "/definitions/shape/discriminator/mapping/group/properties/shapes/elements" });
+        }
+      } else {
+        errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/shape/discriminator/mapping/group/properties/shapes" });
+      }
+      break;
+    case "square":
+      if (jslHas(instance, "side")) {
+        if (typeof instance["side"] !== "number") {
+          errors.push({ instancePath: jslPointer([...path, "side"]), schemaPath: "/definitions/shape/discriminator/mapping/square/properties/side/type" });
+        }
+      } else {
+        errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/shape/discriminator/mapping/square/properties/side" });
+      }
+      if (jslHas(instance, "color")) {
+        validateShapeSquareColorAt(instance["color"], [...path, "color"], errors);
+      }
+      break;
+    default:
+      if (typeof instance["kind"] === "string") {
+        errors.push({ instancePath: jslPointer([...path, "kind"]), schemaPath: "/definitions/shape/discriminator/mapping" });
+      } else {
+        errors.push({ instancePath: jslPointer([...path, "kind"]), schemaPath: "/definitions/shape/discriminator/tag" });
+      }
+  }
+}
+export interface DiscriminatorsCleared {
+  event: "cleared";
+  reason?: string;
+  [key: string]: unknown;
+}
+export interface DiscriminatorsDrawn {
+  event: "drawn";
+  at: string;
+  shape: Shape;
+  [key: string]: unknown;
+}
+export type Discriminators = DiscriminatorsCleared | DiscriminatorsDrawn;
+export function validateDiscriminators(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateDiscriminatorsAt(instance, [], errors);
+  return errors;
+}
+export function validateDiscriminatorsAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/discriminator" });
+    return;
+  }
+  if (!jslHas(instance, "event")) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/discriminator/tag" });
+    return;
+  }
+  switch (instance["event"]) {
+    case "cleared":
+      if (jslHas(instance, "reason")) {
+        if (typeof instance["reason"] !== "string") {
+          errors.push({ instancePath: jslPointer([...path, "reason"]), schemaPath: "/discriminator/mapping/cleared/optionalProperties/reason/type" });
+        }
+      }
+      break;
+    case "drawn":
+      if (jslHas(instance, "at")) {
+        if (!jslIsTimestamp(instance["at"])) {
+          errors.push({ instancePath: jslPointer([...path, "at"]), schemaPath: "/discriminator/mapping/drawn/properties/at/type" });
+        }
+      } else {
+        errors.push({ instancePath: jslPointer(path), schemaPath: "/discriminator/mapping/drawn/properties/at" });
+      }
+      if (jslHas(instance, "shape")) {
+        validateShapeAt(instance["shape"], [...path, "shape"], errors);
+      } else {
+        errors.push({ instancePath: jslPointer(path), schemaPath: "/discriminator/mapping/drawn/properties/shape" });
+      }
+      break;
+    default:
+      if (typeof instance["event"] === "string") {
+        errors.push({ instancePath: jslPointer([...path, "event"]), schemaPath: "/discriminator/mapping" });
+      } else {
+        errors.push({ instancePath: jslPointer([...path, "event"]), schemaPath: "/discriminator/tag" });
+      }
+  }
+}
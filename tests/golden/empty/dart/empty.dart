@@ -0,0 +1 @@
+typedef Empty = Object?;
@@ -0,0 +1,29 @@
+package models
+
+import (
+	"encoding/json"
+)
+
+type Empty interface{}
+
+func ValidateEmpty(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateEmpty(instance, nil, &errors)
+	return errors
+}
+
+func validateEmpty(instance interface{}, path []string, errors *[]ValidationError) {
+}
+
+func DecodeEmpty(data []byte) (Empty, error) {
+	var out Empty
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateEmpty(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
@@ -0,0 +1,5 @@
+package com.example
+
+import kotlinx.serialization.json.JsonElement
+
+typealias Empty = JsonElement
@@ -0,0 +1,2 @@
+
+pub type Empty = serde_json::Value;
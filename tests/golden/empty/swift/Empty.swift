@@ -0,0 +1,3 @@
+import Foundation
+
+typealias Empty = JSONValue
@@ -0,0 +1,21 @@
+export interface ValidationError {
+  instancePath: string;
+  schemaPath: string;
+}
+function jslPointer(path: string[]): string {
+  return path.map((token) => "/" + token.replace(/~/g, "~0").replace(/\//g, "~1")).join("");
+}
+function jslIsObject(instance: unknown): instance is { [key: string]: unknown } {
+  return typeof instance === "object" && instance !== null && !Array.isArray(instance);
+}
+function jslHas(instance: { [key: string]: unknown }, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(instance, key);
+}
+export type Empty = any;
+export function validateEmpty(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateEmptyAt(instance, [], errors);
+  return errors;
+}
+export function validateEmptyAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+}
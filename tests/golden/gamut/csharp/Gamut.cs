@@ -0,0 +1,135 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Example;
+
+/// <summary>
+/// this is a description of a definition
+/// </summary>
+public sealed record A
+{
+    [JsonPropertyName("a")]
+    public required string A_ { get; init; }
+
+    [JsonPropertyName("b")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? B { get; init; }
+}
+
+[JsonConverter(typeof(JsonStringEnumConverter<GamutE>))]
+public enum GamutE
+{
+    [JsonStringEnumMemberName("bar")]
+    Bar,
+    [JsonStringEnumMemberName("baz")]
+    Baz,
+    [JsonStringEnumMemberName("foo")]
+    Foo,
+}
+
+/// <summary>
+/// this is a description at the level of an object
+/// </summary>
+public sealed record GamutF
+{
+    [JsonPropertyName("a")]
+    public required string A { get; init; }
+
+    [JsonPropertyName("b")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? B { get; init; }
+}
+
+public sealed record GamutG
+{
+    [JsonPropertyName("a")]
+    public required string A { get; init; }
+
+    [JsonPropertyName("b")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? B { get; init; }
+}
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "tag")]
+[JsonDerivedType(typeof(GamutHBar), "bar")]
+[JsonDerivedType(typeof(GamutHFoo), "foo")]
+public abstract record GamutH;
+
+public sealed record GamutHBar : GamutH
+{
+    [JsonPropertyName("a")]
+    public required double A { get; init; }
+
+    [JsonPropertyName("b")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public double? B { get; init; }
+}
+
+public sealed record GamutHFoo : GamutH
+{
+    [JsonPropertyName("a")]
+    public required string A { get; init; }
+
+    [JsonPropertyName("b")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? B { get; init; }
+}
+
+/// <summary>
+/// this is a description of props within props
+/// </summary>
+public sealed record GamutK
+{
+    [JsonPropertyName("a")]
+    public required string A { get; init; }
+}
+
+public sealed record Gamut
+{
+    /// <summary>
+    /// foo
+    /// bar
+    /// </summary>
+    [JsonPropertyName("a")]
+    public required bool A { get; init; }
+
+    [JsonPropertyName("b")]
+    public required double B { get; init; }
+
+    [JsonPropertyName("c")]
+    public required string C { get; init; }
+
+    [JsonPropertyName("d")]
+    public required DateTimeOffset D { get; init; }
+
+    [JsonPropertyName("e")]
+    public required GamutE E { get; init; }
+
+    [JsonPropertyName("f")]
+    public required List<GamutF> F { get; init; }
+
+    [JsonPropertyName("g")]
+    public required Dictionary<string, GamutG> G { get; init; }
+
+    [JsonPropertyName("h")]
+    public required GamutH H { get; init; }
+
+    [JsonPropertyName("i")]
+    public required A I { get; init; }
+
+    [JsonPropertyName("j")]
+    public required List<string> J { get; init; }
+
+    /// <summary>
+    /// this is a description of props within props
+    /// </summary>
+    [JsonPropertyName("k")]
+    public required GamutK K { get; init; }
+
+    [JsonPropertyName("l")]
+    public required JsonElement L { get; init; }
+}
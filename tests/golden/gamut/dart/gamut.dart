@@ -0,0 +1,249 @@
+/// this is a description of a definition
+class A {
+  final String a;
+  final String? b;
+
+  const A({
+    required this.a,
+    this.b,
+  });
+
+  factory A.fromJson(Map<String, dynamic> json) {
+    return A(
+      a: json["a"] as String,
+      b: json["b"] as String?,
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "a": a,
+      if (b != null) "b": b,
+    };
+  }
+}
+
+/// this is a description of an elements definition
+typedef B = List<String>;
+
+enum GamutE {
+  bar("bar"),
+  baz("baz"),
+  foo("foo");
+
+  const GamutE(this.value);
+
+  final String value;
+
+  factory GamutE.fromJson(String json) {
+    return values.firstWhere(
+      (value) => value.value == json,
+      orElse: () => throw FormatException("unknown GamutE value", json),
+    );
+  }
+
+  String toJson() => value;
+}
+
+/// this is a description at the level of an object
+class GamutF {
+  final String a;
+  final String? b;
+
+  const GamutF({
+    required this.a,
+    this.b,
+  });
+
+  factory GamutF.fromJson(Map<String, dynamic> json) {
+    return GamutF(
+      a: json["a"] as String,
+      b: json["b"] as String?,
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "a": a,
+      if (b != null) "b": b,
+    };
+  }
+}
+
+class GamutG {
+  final String a;
+  final String? b;
+
+  const GamutG({
+    required this.a,
+    this.b,
+  });
+
+  factory GamutG.fromJson(Map<String, dynamic> json) {
+    return GamutG(
+      a: json["a"] as String,
+      b: json["b"] as String?,
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "a": a,
+      if (b != null) "b": b,
+    };
+  }
+}
+
+sealed class GamutH {
+  const GamutH();
+
+  factory GamutH.fromJson(Map<String, dynamic> json) {
+    return switch (json["tag"]) {
+      "bar" => GamutHBar.fromJson(json),
+      "foo" => GamutHFoo.fromJson(json),
+      _ => throw FormatException("unknown GamutH tag", json["tag"]),
+    };
+  }
+
+  Map<String, dynamic> toJson();
+}
+
+class GamutHBar extends GamutH {
+  final double a;
+  final double? b;
+
+  const GamutHBar({
+    required this.a,
+    this.b,
+  });
+
+  factory GamutHBar.fromJson(Map<String, dynamic> json) {
+    return GamutHBar(
+      a: (json["a"] as num).toDouble(),
+      b: json["b"] == null ? null : (json["b"] as num).toDouble(),
+    );
+  }
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      "tag": "bar",
+      "a": a,
+      if (b != null) "b": b,
+    };
+  }
+}
+
+class GamutHFoo extends GamutH {
+  final String a;
+  final String? b;
+
+  const GamutHFoo({
+    required this.a,
+    this.b,
+  });
+
+  factory GamutHFoo.fromJson(Map<String, dynamic> json) {
+    return GamutHFoo(
+      a: json["a"] as String,
+      b: json["b"] as String?,
+    );
+  }
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      "tag": "foo",
+      "a": a,
+      if (b != null) "b": b,
+    };
+  }
+}
+
+/// this is a description of props within props
+class GamutK {
+  final String a;
+
+  const GamutK({
+    required this.a,
+  });
+
+  factory GamutK.fromJson(Map<String, dynamic> json) {
+    return GamutK(
+      a: json["a"] as String,
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "a": a,
+    };
+  }
+}
+
+class Gamut {
+  /// foo
+  /// bar
+  final bool a;
+  final double b;
+  final String c;
+  final DateTime d;
+  final GamutE e;
+  final List<GamutF> f;
+  final Map<String, GamutG> g;
+  final GamutH h;
+  final A i;
+  final B j;
+  /// this is a description of props within props
+  final GamutK k;
+  final Object? l;
+
+  const Gamut({
+    required this.a,
+    required this.b,
+    required this.c,
+    required this.d,
+    required this.e,
+    required this.f,
+    required this.g,
+    required this.h,
+    required this.i,
+    required this.j,
+    required this.k,
+    required this.l,
+  });
+
+  factory Gamut.fromJson(Map<String, dynamic> json) {
+    return Gamut(
+      a: json["a"] as bool,
+      b: (json["b"] as num).toDouble(),
+      c: json["c"] as String,
+      d: DateTime.parse(json["d"] as String),
+      e: GamutE.fromJson(json["e"] as String),
+      f: (json["f"] as List<dynamic>).map((v0) => GamutF.fromJson(v0 as Map<String, dynamic>)).toList(),
+      g: (json["g"] as Map<String, dynamic>).map((k0, v0) => MapEntry(k0, GamutG.fromJson(v0 as Map<String, dynamic>))),
+      h: GamutH.fromJson(json["h"] as Map<String, dynamic>),
+      i: A.fromJson(json["i"] as Map<String, dynamic>),
+      j: (json["j"] as List<dynamic>).map((v0) => v0 as String).toList(),
+      k: GamutK.fromJson(json["k"] as Map<String, dynamic>),
+      l: json["l"],
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "a": a,
+      "b": b,
+      "c": c,
+      "d": d.toUtc().toIso8601String(),
+      "e": e.toJson(),
+      "f": f.map((v0) => v0.toJson()).toList(),
+      "g": g.map((k0, v0) => MapEntry(k0, v0.toJson())),
+      "h": h.toJson(),
+      "i": i.toJson(),
+      "j": j,
+      "k": k.toJson(),
+      "l": l,
+    };
+  }
+}
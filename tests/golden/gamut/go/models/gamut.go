@@ -0,0 +1,498 @@
+package models
+
+import (
+	"encoding/json"
+	"strconv"
+	"time"
+)
+
+// this is a description of a definition
+type A struct {
+	A string `json:"a"`
+	B *string `json:"b,omitempty"`
+}
+
+func ValidateA(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateA(instance, nil, &errors)
+	return errors
+}
+
+func validateA(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/a/properties"})
+		return
+	}
+	if v0, ok := obj["a"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "a")), SchemaPath: "/definitions/a/properties/a/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/a/properties/a"})
+	}
+	if v0, ok := obj["b"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "b")), SchemaPath: "/definitions/a/optionalProperties/b/type"})
+		}
+	}
+}
+
+func DecodeA(data []byte) (A, error) {
+	var out A
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateA(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+// this is a description of an elements definition
+type B []string
+
+func ValidateB(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateB(instance, nil, &errors)
+	return errors
+}
+
+func validateB(instance interface{}, path []string, errors *[]ValidationError) {
+	if a0, ok := instance.([]interface{}); ok {
+		for i0, v1 := range a0 {
+			if _, ok := v1.(string); !ok {
+				*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, strconv.Itoa(i0))), SchemaPath: "/definitions/b/elements/type"})
+			}
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/b/elements"})
+	}
+}
+
+func DecodeB(data []byte) (B, error) {
+	var out B
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateB(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type GamutE string
+
+const (
+	GamutEBar GamutE = "bar"
+	GamutEBaz GamutE = "baz"
+	GamutEFoo GamutE = "foo"
+)
+
+func ValidateGamutE(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateGamutE(instance, nil, &errors)
+	return errors
+}
+
+func validateGamutE(instance interface{}, path []string, errors *[]ValidationError) {
+	switch instance {
+	case "bar", "baz", "foo":
+	default:
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/e/enum"})
+	}
+}
+
+func DecodeGamutE(data []byte) (GamutE, error) {
+	var out GamutE
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateGamutE(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+// this is a description at the level of an object
+type GamutF struct {
+	A string `json:"a"`
+	B *string `json:"b,omitempty"`
+}
+
+func ValidateGamutF(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateGamutF(instance, nil, &errors)
+	return errors
+}
+
+func validateGamutF(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/f/elements/properties"})
+		return
+	}
+	if v0, ok := obj["a"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "a")), SchemaPath: "/properties/f/elements/properties/a/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/f/elements/properties/a"})
+	}
+	if v0, ok := obj["b"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "b")), SchemaPath: "/properties/f/elements/optionalProperties/b/type"})
+		}
+	}
+}
+
+func DecodeGamutF(data []byte) (GamutF, error) {
+	var out GamutF
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateGamutF(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type GamutG struct {
+	A string `json:"a"`
+	B *string `json:"b,omitempty"`
+}
+
+func ValidateGamutG(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateGamutG(instance, nil, &errors)
+	return errors
+}
+
+func validateGamutG(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/g/values/properties"})
+		return
+	}
+	if v0, ok := obj["a"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "a")), SchemaPath: "/properties/g/values/properties/a/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/g/values/properties/a"})
+	}
+	if v0, ok := obj["b"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "b")), SchemaPath: "/properties/g/values/optionalProperties/b/type"})
+		}
+	}
+}
+
+func DecodeGamutG(data []byte) (GamutG, error) {
+	var out GamutG
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateGamutG(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type GamutHBar struct {
+	A float64 `json:"a"`
+	B *float64 `json:"b,omitempty"`
+}
+
+type GamutHFoo struct {
+	A string `json:"a"`
+	B *string `json:"b,omitempty"`
+}
+
+type GamutH struct {
+	Tag string `json:"tag"`
+	Val interface{} `json:"-"`
+}
+
+func (s *GamutH) UnmarshalJSON(buf []byte) error {
+	var x struct{ Tag string `json:"tag"` }
+	if err := json.Unmarshal(buf, &x); err != nil {
+		return err
+	}
+	switch x.Tag {
+	case "bar":
+		var data GamutHBar
+		if err := json.Unmarshal(buf, &data); err != nil {
+			return err
+		}
+		s.Val = data
+	case "foo":
+		var data GamutHFoo
+		if err := json.Unmarshal(buf, &data); err != nil {
+			return err
+		}
+		s.Val = data
+	}
+	s.Tag = x.Tag
+	return nil
+}
+
+func (s GamutH) MarshalJSON() ([]byte, error) {
+	switch val := s.Val.(type) {
+	case GamutHBar:
+		var data struct{
+			Tag string `json:"tag"`
+			GamutHBar
+		}
+		data.GamutHBar = val
+		data.Tag = s.Tag
+		return json.Marshal(data)
+	case GamutHFoo:
+		var data struct{
+			Tag string `json:"tag"`
+			GamutHFoo
+		}
+		data.GamutHFoo = val
+		data.Tag = s.Tag
+		return json.Marshal(data)
+	}
+	panic("invalid discriminator tag")
+}
+
+func ValidateGamutH(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateGamutH(instance, nil, &errors)
+	return errors
+}
+
+func validateGamutH(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/h/discriminator"})
+		return
+	}
+	tag, ok := obj["tag"]
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/h/discriminator/tag"})
+		return
+	}
+	switch tag {
+	case "bar":
+		if v0, ok := obj["a"]; ok {
+			if _, ok := v0.(float64); !ok {
+				*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "a")), SchemaPath: "/properties/h/discriminator/mapping/bar/properties/a/type"})
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/h/discriminator/mapping/bar/properties/a"})
+		}
+		if v0, ok := obj["b"]; ok {
+			if _, ok := v0.(float64); !ok {
+				*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "b")), SchemaPath: "/properties/h/discriminator/mapping/bar/optionalProperties/b/type"})
+			}
+		}
+	case "foo":
+		if v0, ok := obj["a"]; ok {
+			if _, ok := v0.(string); !ok {
+				*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "a")), SchemaPath: "/properties/h/discriminator/mapping/foo/properties/a/type"})
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/h/discriminator/mapping/foo/properties/a"})
+		}
+		if v0, ok := obj["b"]; ok {
+			if _, ok := v0.(string); !ok {
+				*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "b")), SchemaPath: "/properties/h/discriminator/mapping/foo/optionalProperties/b/type"})
+			}
+		}
+	default:
+		if _, ok := tag.(string); ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "tag")), SchemaPath: "/properties/h/discriminator/mapping"})
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "tag")), SchemaPath: "/properties/h/discriminator/tag"})
+		}
+	}
+}
+
+func DecodeGamutH(data []byte) (GamutH, error) {
+	var out GamutH
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateGamutH(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+// this is a description of props within props
+type GamutK struct {
+	A string `json:"a"`
+}
+
+func ValidateGamutK(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateGamutK(instance, nil, &errors)
+	return errors
+}
+
+func validateGamutK(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/k/properties"})
+		return
+	}
+	if v0, ok := obj["a"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "a")), SchemaPath: "/properties/k/properties/a/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/k/properties/a"})
+	}
+}
+
+func DecodeGamutK(data []byte) (GamutK, error) {
+	var out GamutK
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateGamutK(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type Gamut struct {
+	// foo
+	// bar
+	A bool `json:"a"`
+	B float64 `json:"b"`
+	C string `json:"c"`
+	D time.Time `json:"d"`
+	E GamutE `json:"e"`
+	F []GamutF `json:"f"`
+	G map[string]GamutG `json:"g"`
+	H GamutH `json:"h"`
+	I A `json:"i"`
+	J B `json:"j"`
+	// this is a description of props within props
+	K GamutK `json:"k"`
+	L interface{} `json:"l"`
+}
+
+func ValidateGamut(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateGamut(instance, nil, &errors)
+	return errors
+}
+
+func validateGamut(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties"})
+		return
+	}
+	if v0, ok := obj["a"]; ok {
+		if _, ok := v0.(bool); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "a")), SchemaPath: "/properties/a/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/a"})
+	}
+	if v0, ok := obj["b"]; ok {
+		if _, ok := v0.(float64); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "b")), SchemaPath: "/properties/b/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/b"})
+	}
+	if v0, ok := obj["c"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "c")), SchemaPath: "/properties/c/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/c"})
+	}
+	if v0, ok := obj["d"]; ok {
+		if !jslIsTimestamp(v0) {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "d")), SchemaPath: "/properties/d/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/d"})
+	}
+	if v0, ok := obj["e"]; ok {
+		validateGamutE(v0, jslAppend(path, "e"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/e"})
+	}
+	if v0, ok := obj["f"]; ok {
+		if a0, ok := v0.([]interface{}); ok {
+			for i0, v1 := range a0 {
+				validateGamutF(v1, jslAppend(path, "f", strconv.Itoa(i0)), errors)
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "f")), SchemaPath: "/properties/f/elements"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/f"})
+	}
+	if v0, ok := obj["g"]; ok {
+		if o0, ok := v0.(map[string]interface{}); ok {
+			for k0, v1 := range o0 {
+				validateGamutG(v1, jslAppend(path, "g", k0), errors)
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "g")), SchemaPath: "/properties/g/values"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/g"})
+	}
+	if v0, ok := obj["h"]; ok {
+		validateGamutH(v0, jslAppend(path, "h"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/h"})
+	}
+	if v0, ok := obj["i"]; ok {
+		validateA(v0, jslAppend(path, "i"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/i"})
+	}
+	if v0, ok := obj["j"]; ok {
+		validateB(v0, jslAppend(path, "j"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/j"})
+	}
+	if v0, ok := obj["k"]; ok {
+		validateGamutK(v0, jslAppend(path, "k"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/k"})
+	}
+	if _, ok := obj["l"]; !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/l"})
+	}
+}
+
+func DecodeGamut(data []byte) (Gamut, error) {
+	var out Gamut
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateGamut(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
@@ -0,0 +1,47 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * this is a description of a definition
+ */
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class A {
+    public String a;
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public String b;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/definitions/a/properties"));
+            return;
+        }
+        if (instance.has("a")) {
+            JsonNode v0 = instance.get("a");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "a"), "/definitions/a/properties/a/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/definitions/a/properties/a"));
+        }
+        if (instance.has("b")) {
+            JsonNode v0 = instance.get("b");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "b"), "/definitions/a/optionalProperties/b/type"));
+            }
+        }
+    }
+}
@@ -0,0 +1,146 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class Gamut {
+    /**
+     * foo
+     * bar
+     */
+    public boolean a;
+    public double b;
+    public String c;
+    public Instant d;
+    public GamutE e;
+    public List<GamutF> f;
+    public Map<String, GamutG> g;
+    public GamutH h;
+    public A i;
+    public List<String> j;
+    /**
+     * this is a description of props within props
+     */
+    public GamutK k;
+    public Object l;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/properties"));
+            return;
+        }
+        if (instance.has("a")) {
+            JsonNode v0 = instance.get("a");
+            if (!v0.isBoolean()) {
+                errors.add(new ValidationError(ValidationError.append(path, "a"), "/properties/a/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/a"));
+        }
+        if (instance.has("b")) {
+            JsonNode v0 = instance.get("b");
+            if (!v0.isNumber()) {
+                errors.add(new ValidationError(ValidationError.append(path, "b"), "/properties/b/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/b"));
+        }
+        if (instance.has("c")) {
+            JsonNode v0 = instance.get("c");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "c"), "/properties/c/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/c"));
+        }
+        if (instance.has("d")) {
+            JsonNode v0 = instance.get("d");
+            if (!ValidationError.isTimestamp(v0)) {
+                errors.add(new ValidationError(ValidationError.append(path, "d"), "/properties/d/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/d"));
+        }
+        if (instance.has("e")) {
+            JsonNode v0 = instance.get("e");
+            GamutE.validate(v0, ValidationError.append(path, "e"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/e"));
+        }
+        if (instance.has("f")) {
+            JsonNode v0 = instance.get("f");
+            if (v0.isArray()) {
+                for (int i0 = 0; i0 < v0.size(); i0++) {
+                    JsonNode v1 = v0.get(i0);
+                    GamutF.validate(v1, ValidationError.append(path, "f", Integer.toString(i0)), errors);
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "f"), "/properties/f/elements"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/f"));
+        }
+        if (instance.has("g")) {
+            JsonNode v0 = instance.get("g");
+            if (v0.isObject()) {
+                for (Iterator<Map.Entry<String, JsonNode>> it0 = v0.fields(); it0.hasNext();) {
+                    Map.Entry<String, JsonNode> e0 = it0.next();
+                    JsonNode v1 = e0.getValue();
+                    GamutG.validate(v1, ValidationError.append(path, "g", e0.getKey()), errors);
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "g"), "/properties/g/values"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/g"));
+        }
+        if (instance.has("h")) {
+            JsonNode v0 = instance.get("h");
+            GamutH.validate(v0, ValidationError.append(path, "h"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/h"));
+        }
+        if (instance.has("i")) {
+            JsonNode v0 = instance.get("i");
+            A.validate(v0, ValidationError.append(path, "i"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/i"));
+        }
+        if (instance.has("j")) {
+            JsonNode v0 = instance.get("j");
+            if (v0.isArray()) {
+                for (int i0 = 0; i0 < v0.size(); i0++) {
+                    JsonNode v1 = v0.get(i0);
+                    if (!v1.isTextual()) {
+                        errors.add(new ValidationError(ValidationError.append(path, "j", Integer.toString(i0)), "/definitions/b/elements/type"));
+                    }
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "j"), "/definitions/b/elements"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/j"));
+        }
+        if (instance.has("k")) {
+            JsonNode v0 = instance.get("k");
+            GamutK.validate(v0, ValidationError.append(path, "k"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/k"));
+        }
+        if (!instance.has("l")) {
+            errors.add(new ValidationError(path, "/properties/l"));
+        }
+    }
+}
@@ -0,0 +1,33 @@
+package com.example;
+
+import com.fasterxml.jackson.databind.JsonNode;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+public enum GamutE {
+   bar,
+   baz,
+   foo,
+   ;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (instance.isTextual()) {
+            switch (instance.asText()) {
+            case "bar":
+            case "baz":
+            case "foo":
+                return;
+            }
+        }
+
+        errors.add(new ValidationError(path, "/properties/e/enum"));
+    }
+}
@@ -0,0 +1,44 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class GamutG {
+    public String a;
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public String b;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/properties/g/values/properties"));
+            return;
+        }
+        if (instance.has("a")) {
+            JsonNode v0 = instance.get("a");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "a"), "/properties/g/values/properties/a/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/g/values/properties/a"));
+        }
+        if (instance.has("b")) {
+            JsonNode v0 = instance.get("b");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "b"), "/properties/g/values/optionalProperties/b/type"));
+            }
+        }
+    }
+}
@@ -0,0 +1,74 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonSubTypes;
+import com.fasterxml.jackson.annotation.JsonTypeInfo;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "tag")
+@JsonSubTypes({
+    @JsonSubTypes.Type(value = GamutHBar.class, name = "bar"),
+    @JsonSubTypes.Type(value = GamutHFoo.class, name = "foo"),
+})
+public abstract class GamutH {
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/properties/h/discriminator"));
+            return;
+        }
+        if (!instance.has("tag")) {
+            errors.add(new ValidationError(path, "/properties/h/discriminator/tag"));
+            return;
+        }
+        if (!instance.get("tag").isTextual()) {
+            errors.add(new ValidationError(ValidationError.append(path, "tag"), "/properties/h/discriminator/tag"));
+            return;
+        }
+
+        switch (instance.get("tag").asText()) {
+        case "bar":
+            if (instance.has("a")) {
+                JsonNode v0 = instance.get("a");
+                if (!v0.isNumber()) {
+                    errors.add(new ValidationError(ValidationError.append(path, "a"), "/properties/h/discriminator/mapping/bar/properties/a/type"));
+                }
+            } else {
+                errors.add(new ValidationError(path, "/properties/h/discriminator/mapping/bar/properties/a"));
+            }
+            if (instance.has("b")) {
+                JsonNode v0 = instance.get("b");
+                if (!v0.isNumber()) {
+                    errors.add(new ValidationError(ValidationError.append(path, "b"), "/properties/h/discriminator/mapping/bar/optionalProperties/b/type"));
+                }
+            }
+            break;
+        case "foo":
+            if (instance.has("a")) {
+                JsonNode v0 = instance.get("a");
+                if (!v0.isTextual()) {
+                    errors.add(new ValidationError(ValidationError.append(path, "a"), "/properties/h/discriminator/mapping/foo/properties/a/type"));
+                }
+            } else {
+                errors.add(new ValidationError(path, "/properties/h/discriminator/mapping/foo/properties/a"));
+            }
+            if (instance.has("b")) {
+                JsonNode v0 = instance.get("b");
+                if (!v0.isTextual()) {
+                    errors.add(new ValidationError(ValidationError.append(path, "b"), "/properties/h/discriminator/mapping/foo/optionalProperties/b/type"));
+                }
+            }
+            break;
+        default:
+            errors.add(new ValidationError(ValidationError.append(path, "tag"), "/properties/h/discriminator/mapping"));
+        }
+    }
+}
@@ -0,0 +1,14 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import java.time.Instant;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class GamutHBar extends GamutH {
+    public double a;
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public Double b;
+}
@@ -0,0 +1,14 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import java.time.Instant;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class GamutHFoo extends GamutH {
+    public String a;
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public String b;
+}
@@ -0,0 +1,105 @@
+@file:OptIn(ExperimentalSerializationApi::class)
+
+package com.example
+
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.json.JsonClassDiscriminator
+import kotlinx.serialization.json.JsonElement
+import kotlinx.serialization.json.JsonIgnoreUnknownKeys
+
+/**
+ * this is a description of a definition
+ */
+@Serializable
+@JsonIgnoreUnknownKeys
+data class A(
+    val a: String,
+    val b: String? = null,
+)
+
+/**
+ * this is a description of an elements definition
+ */
+typealias B = List<String>
+
+@Serializable
+enum class GamutE {
+    @SerialName("bar")
+    BAR,
+    @SerialName("baz")
+    BAZ,
+    @SerialName("foo")
+    FOO,
+}
+
+/**
+ * this is a description at the level of an object
+ */
+@Serializable
+@JsonIgnoreUnknownKeys
+data class GamutF(
+    val a: String,
+    val b: String? = null,
+)
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class GamutG(
+    val a: String,
+    val b: String? = null,
+)
+
+@Serializable
+@JsonClassDiscriminator("tag")
+sealed class GamutH
+
+@Serializable
+@SerialName("bar")
+@JsonIgnoreUnknownKeys
+data class GamutHBar(
+    val a: Double,
+    val b: Double? = null,
+) : GamutH()
+
+@Serializable
+@SerialName("foo")
+@JsonIgnoreUnknownKeys
+data class GamutHFoo(
+    val a: String,
+    val b: String? = null,
+) : GamutH()
+
+/**
+ * this is a description of props within props
+ */
+@Serializable
+@JsonIgnoreUnknownKeys
+data class GamutK(
+    val a: String,
+)
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class Gamut(
+    /**
+     * foo
+     * bar
+     */
+    val a: Boolean,
+    val b: Double,
+    val c: String,
+    val d: String,
+    val e: GamutE,
+    val f: List<GamutF>,
+    val g: Map<String, GamutG>,
+    val h: GamutH,
+    val i: A,
+    val j: B,
+    /**
+     * this is a description of props within props
+     */
+    val k: GamutK,
+    val l: JsonElement,
+)
@@ -0,0 +1,206 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from datetime import datetime
+from enum import Enum
+from typing import Any, Dict, List, Optional
+
+
+def _parse_timestamp(value: str) -> datetime:
+    return datetime.fromisoformat(value.replace("Z", "+00:00"))
+
+
+@dataclass
+class A:
+    """this is a description of a definition"""
+    a: str
+    b: Optional[str] = None
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> A:
+        return cls(
+            a=data["a"],
+            b=data.get("b"),
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["a"] = self.a
+        if self.b is not None:
+            data["b"] = self.b
+        return data
+
+
+# this is a description of an elements definition
+B = List[str]
+
+
+class GamutE(Enum):
+    BAR = "bar"
+    BAZ = "baz"
+    FOO = "foo"
+
+
+@dataclass
+class GamutF:
+    """this is a description at the level of an object"""
+    a: str
+    b: Optional[str] = None
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> GamutF:
+        return cls(
+            a=data["a"],
+            b=data.get("b"),
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["a"] = self.a
+        if self.b is not None:
+            data["b"] = self.b
+        return data
+
+
+@dataclass
+class GamutG:
+    a: str
+    b: Optional[str] = None
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> GamutG:
+        return cls(
+            a=data["a"],
+            b=data.get("b"),
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["a"] = self.a
+        if self.b is not None:
+            data["b"] = self.b
+        return data
+
+
+@dataclass
+class GamutH:
+    @staticmethod
+    def from_json(data: Dict[str, Any]) -> GamutH:
+        variants = {
+            "bar": GamutHBar,
+            "foo": GamutHFoo,
+        }
+
+        return variants[data["tag"]].from_json(data)
+
+    def to_json(self) -> Dict[str, Any]:
+        raise NotImplementedError
+
+
+@dataclass
+class GamutHBar(GamutH):
+    a: float
+    b: Optional[float] = None
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> GamutHBar:
+        return cls(
+            a=data["a"],
+            b=data.get("b"),
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {"tag": "bar"}
+        data["a"] = self.a
+        if self.b is not None:
+            data["b"] = self.b
+        return data
+
+
+@dataclass
+class GamutHFoo(GamutH):
+    a: str
+    b: Optional[str] = None
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> GamutHFoo:
+        return cls(
+            a=data["a"],
+            b=data.get("b"),
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {"tag": "foo"}
+        data["a"] = self.a
+        if self.b is not None:
+            data["b"] = self.b
+        return data
+
+
+@dataclass
+class GamutK:
+    """this is a description of props within props"""
+    a: str
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> GamutK:
+        return cls(
+            a=data["a"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["a"] = self.a
+        return data
+
+
+@dataclass
+class Gamut:
+    # foo
+    # bar
+    a: bool
+    b: float
+    c: str
+    d: datetime
+    e: GamutE
+    f: List[GamutF]
+    g: Dict[str, GamutG]
+    h: GamutH
+    i: A
+    j: B
+    # this is a description of props within props
+    k: GamutK
+    l: Any
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Gamut:
+        return cls(
+            a=data["a"],
+            b=data["b"],
+            c=data["c"],
+            d=_parse_timestamp(data["d"]),
+            e=GamutE(data["e"]),
+            f=[GamutF.from_json(v0) for v0 in data["f"]],
+            g={k0: GamutG.from_json(v0) for k0, v0 in data["g"].items()},
+            h=GamutH.from_json(data["h"]),
+            i=A.from_json(data["i"]),
+            j=data["j"],
+            k=GamutK.from_json(data["k"]),
+            l=data["l"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["a"] = self.a
+        data["b"] = self.b
+        data["c"] = self.c
+        data["d"] = self.d.isoformat()
+        data["e"] = self.e.value
+        data["f"] = [v0.to_json() for v0 in self.f]
+        data["g"] = {k0: v0.to_json() for k0, v0 in self.g.items()}
+        data["h"] = self.h.to_json()
+        data["i"] = self.i.to_json()
+        data["j"] = self.j
+        data["k"] = self.k.to_json()
+        data["l"] = self.l
+        return data
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+/// this is a description of a definition
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct A {
+    pub a: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub b: Option<String>,
+}
+
+/// this is a description of an elements definition
+pub type B = Vec<String>;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum GamutE {
+    #[serde(rename = "bar")]
+    Bar,
+    #[serde(rename = "baz")]
+    Baz,
+    #[serde(rename = "foo")]
+    Foo,
+}
+
+/// this is a description at the level of an object
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GamutF {
+    pub a: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub b: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GamutG {
+    pub a: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub b: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GamutHBar {
+    pub a: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub b: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GamutHFoo {
+    pub a: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub b: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "tag")]
+pub enum GamutH {
+    #[serde(rename = "bar")]
+    Bar(GamutHBar),
+    #[serde(rename = "foo")]
+    Foo(GamutHFoo),
+}
+
+/// this is a description of props within props
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GamutK {
+    pub a: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Gamut {
+    /// foo
+    /// bar
+    pub a: bool,
+    pub b: f64,
+    pub c: String,
+    pub d: chrono::DateTime<chrono::Utc>,
+    pub e: GamutE,
+    pub f: Vec<GamutF>,
+    pub g: std::collections::HashMap<String, GamutG>,
+    pub h: GamutH,
+    pub i: A,
+    pub j: B,
+    /// this is a description of props within props
+    pub k: GamutK,
+    pub l: serde_json::Value,
+}
@@ -0,0 +1,94 @@
+import Foundation
+
+/// this is a description of a definition
+struct A: Codable, Equatable {
+    var a: String
+    var b: String?
+}
+
+/// this is a description of an elements definition
+typealias B = [String]
+
+enum GamutE: String, Codable, Equatable {
+    case bar
+    case baz
+    case foo
+}
+
+/// this is a description at the level of an object
+struct GamutF: Codable, Equatable {
+    var a: String
+    var b: String?
+}
+
+struct GamutG: Codable, Equatable {
+    var a: String
+    var b: String?
+}
+
+struct GamutHBar: Codable, Equatable {
+    var a: Double
+    var b: Double?
+}
+
+struct GamutHFoo: Codable, Equatable {
+    var a: String
+    var b: String?
+}
+
+enum GamutH: Codable, Equatable {
+    case bar(GamutHBar)
+    case foo(GamutHFoo)
+
+    enum CodingKeys: String, CodingKey {
+        case tag = "tag"
+    }
+
+    init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .tag)
+        switch tag {
+        case "bar":
+            self = .bar(try GamutHBar(from: decoder))
+        case "foo":
+            self = .foo(try GamutHFoo(from: decoder))
+        default:
+            throw DecodingError.dataCorruptedError(forKey: .tag, in: container, debugDescription: "Unknown tag: \(tag)")
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case .bar(let value):
+            try container.encode("bar", forKey: .tag)
+            try value.encode(to: encoder)
+        case .foo(let value):
+            try container.encode("foo", forKey: .tag)
+            try value.encode(to: encoder)
+        }
+    }
+}
+
+/// this is a description of props within props
+struct GamutK: Codable, Equatable {
+    var a: String
+}
+
+struct Gamut: Codable, Equatable {
+    /// foo
+    /// bar
+    var a: Bool
+    var b: Double
+    var c: String
+    var d: String
+    var e: GamutE
+    var f: [GamutF]
+    var g: [String: GamutG]
+    var h: GamutH
+    var i: A
+    var j: B
+    /// this is a description of props within props
+    var k: GamutK
+    var l: JSONValue
+}
@@ -0,0 +1,340 @@
+export interface ValidationError {
+  instancePath: string;
+  schemaPath: string;
+}
+function jslPointer(path: string[]): string {
+  return path.map((token) => "/" + token.replace(/~/g, "~0").replace(/\//g, "~1")).join("");
+}
+function jslIsObject(instance: unknown): instance is { [key: string]: unknown } {
+  return typeof instance === "object" && instance !== null && !Array.isArray(instance);
+}
+function jslHas(instance: { [key: string]: unknown }, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(instance, key);
+}
+function jslIsTimestamp(instance: unknown): boolean {
+  return typeof instance === "string" && /^\d{4}-\d{2}-\d{2}[Tt]\d{2}:\d{2}:\d{2}(\.\d+)?([Zz]|[+-]\d{2}:\d{2})$/.test(instance) && !isNaN(Date.parse(instance));
+}
+/**
+ * this is a description of a definition
+ */
+export interface A {
+  a: string;
+  b?: string;
+  [key: string]: unknown;
+}
+export function validateA(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateAAt(instance, [], errors);
+  return errors;
+}
+export function validateAAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/a/properties" });
+    return;
+  }
+  if (jslHas(instance, "a")) {
+    if (typeof instance["a"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "a"]), schemaPath: "/definitions/a/properties/a/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/a/properties/a" });
+  }
+  if (jslHas(instance, "b")) {
+    if (typeof instance["b"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "b"]), schemaPath: "/definitions/a/optionalProperties/b/type" });
+    }
+  }
+}
+/**
+ * this is a description of an elements definition
+ */
+export type B = string[];
+export function validateB(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateBAt(instance, [], errors);
+  return errors;
+}
+export function validateBAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  const a0: unknown = instance;
+  if (Array.isArray(a0)) {
+    a0.forEach((v0: unknown, i0: number) => {
+      if (typeof v0 !== "string") {
+        errors.push({ instancePath: jslPointer([...path, String(i0)]), schemaPath: "/definitions/b/elements/type" });
+      }
+    });
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/b/elements" });
+  }
+}
+export type GamutE = "bar" | "baz" | "foo";
+export function validateGamutE(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateGamutEAt(instance, [], errors);
+  return errors;
+}
+export function validateGamutEAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (instance !== "bar" && instance !== "baz" && instance !== "foo") {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/e/enum" });
+  }
+}
+/**
+ * this is a description at the level of an object
+ */
+export interface GamutF {
+  a: string;
+  b?: string;
+  [key: string]: unknown;
+}
+export function validateGamutF(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateGamutFAt(instance, [], errors);
+  return errors;
+}
+export function validateGamutFAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/f/elements/properties" });
+    return;
+  }
+  if (jslHas(instance, "a")) {
+    if (typeof instance["a"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "a"]), schemaPath: "/properties/f/elements/properties/a/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/f/elements/properties/a" });
+  }
+  if (jslHas(instance, "b")) {
+    if (typeof instance["b"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "b"]), schemaPath: "/properties/f/elements/optionalProperties/b/type" });
+    }
+  }
+}
+export interface GamutG {
+  a: string;
+  b?: string;
+  [key: string]: unknown;
+}
+export function validateGamutG(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateGamutGAt(instance, [], errors);
+  return errors;
+}
+export function validateGamutGAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/g/values/properties" });
+    return;
+  }
+  if (jslHas(instance, "a")) {
+    if (typeof instance["a"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "a"]), schemaPath: "/properties/g/values/properties/a/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/g/values/properties/a" });
+  }
+  if (jslHas(instance, "b")) {
+    if (typeof instance["b"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "b"]), schemaPath: "/properties/g/values/optionalProperties/b/type" });
+    }
+  }
+}
+export interface GamutHBar {
+  tag: "bar";
+  a: number;
+  b?: number;
+  [key: string]: unknown;
+}
+export interface GamutHFoo {
+  tag: "foo";
+  a: string;
+  b?: string;
+  [key: string]: unknown;
+}
+export type GamutH = GamutHBar | GamutHFoo;
+export function validateGamutH(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateGamutHAt(instance, [], errors);
+  return errors;
+}
+export function validateGamutHAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/h/discriminator" });
+    return;
+  }
+  if (!jslHas(instance, "tag")) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/h/discriminator/tag" });
+    return;
+  }
+  switch (instance["tag"]) {
+    case "bar":
+      if (jslHas(instance, "a")) {
+        if (typeof instance["a"] !== "number") {
+          errors.push({ instancePath: jslPointer([...path, "a"]), schemaPath: "/properties/h/discriminator/mapping/bar/properties/a/type" });
+        }
+      } else {
+        errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/h/discriminator/mapping/bar/properties/a" });
+      }
+      if (jslHas(instance, "b")) {
+        if (typeof instance["b"] !== "number") {
+          errors.push({ instancePath: jslPointer([...path, "b"]), schemaPath: "/properties/h/discriminator/mapping/bar/optionalProperties/b/type" });
+        }
+      }
+      break;
+    case "foo":
+      if (jslHas(instance, "a")) {
+        if (typeof instance["a"] !== "string") {
+          errors.push({ instancePath: jslPointer([...path, "a"]), schemaPath: "/properties/h/discriminator/mapping/foo/properties/a/type" });
+        }
+      } else {
+        errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/h/discriminator/mapping/foo/properties/a" });
+      }
+      if (jslHas(instance, "b")) {
+        if (typeof instance["b"] !== "string") {
+          errors.push({ instancePath: jslPointer([...path, "b"]), schemaPath: "/properties/h/discriminator/mapping/foo/optionalProperties/b/type" });
+        }
+      }
+      break;
+    default:
+      if (typeof instance["tag"] === "string") {
+        errors.push({ instancePath: jslPointer([...path, "tag"]), schemaPath: "/properties/h/discriminator/mapping" });
+      } else {
+        errors.push({ instancePath: jslPointer([...path, "tag"]), schemaPath: "/properties/h/discriminator/tag" });
+      }
+  }
+}
+/**
+ * this is a description of props within props
+ */
+export interface GamutK {
+  a: string;
+  [key: string]: unknown;
+}
+export function validateGamutK(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateGamutKAt(instance, [], errors);
+  return errors;
+}
+export function validateGamutKAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/k/properties" });
+    return;
+  }
+  if (jslHas(instance, "a")) {
+    if (typeof instance["a"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "a"]), schemaPath: "/properties/k/properties/a/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/k/properties/a" });
+  }
+}
+export interface Gamut {
+  /**
+   * foo
+   * bar
+   */
+  a: boolean;
+  b: number;
+  c: string;
+  d: string;
+  e: GamutE;
+  f: GamutF[];
+  g: { [name: string]: GamutG };
+  h: GamutH;
+  i: A;
+  j: B;
+  /**
+   * this is a description of props within props
+   */
+  k: GamutK;
+  l: any;
+  [key: string]: unknown;
+}
+export function validateGamut(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateGamutAt(instance, [], errors);
+  return errors;
+}
+export function validateGamutAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties" });
+    return;
+  }
+  if (jslHas(instance, "a")) {
+    if (typeof instance["a"] !== "boolean") {
+      errors.push({ instancePath: jslPointer([...path, "a"]), schemaPath: "/properties/a/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/a" });
+  }
+  if (jslHas(instance, "b")) {
+    if (typeof instance["b"] !== "number") {
+      errors.push({ instancePath: jslPointer([...path, "b"]), schemaPath: "/properties/b/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/b" });
+  }
+  if (jslHas(instance, "c")) {
+    if (typeof instance["c"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "c"]), schemaPath: "/properties/c/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/c" });
+  }
+  if (jslHas(instance, "d")) {
+    if (!jslIsTimestamp(instance["d"])) {
+      errors.push({ instancePath: jslPointer([...path, "d"]), schemaPath: "/properties/d/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/d" });
+  }
+  if (jslHas(instance, "e")) {
+    validateGamutEAt(instance["e"], [...path, "e"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/e" });
+  }
+  if (jslHas(instance, "f")) {
+    const a0: unknown = instance["f"];
+    if (Array.isArray(a0)) {
+      a0.forEach((v0: unknown, i0: number) => {
+        validateGamutFAt(v0, [...path, "f", String(i0)], errors);
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "f"]), schemaPath: "/properties/f/elements" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/f" });
+  }
+  if (jslHas(instance, "g")) {
+    const o0: unknown = instance["g"];
+    if (jslIsObject(o0)) {
+      Object.keys(o0).forEach((k0: string) => {
+        validateGamutGAt(o0[k0], [...path, "g", k0], errors);
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "g"]), schemaPath: "/properties/g/values" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/g" });
+  }
+  if (jslHas(instance, "h")) {
+    validateGamutHAt(instance["h"], [...path, "h"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/h" });
+  }
+  if (jslHas(instance, "i")) {
+    validateAAt(instance["i"], [...path, "i"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/i" });
+  }
+  if (jslHas(instance, "j")) {
+    validateBAt(instance["j"], [...path, "j"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/j" });
+  }
+  if (jslHas(instance, "k")) {
+    validateGamutKAt(instance["k"], [...path, "k"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/k" });
+  }
+  if (!jslHas(instance, "l")) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/l" });
+  }
+}
@@ -0,0 +1,99 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Example;
+
+public sealed record X3D
+{
+    [JsonPropertyName("x")]
+    public required double X { get; init; }
+}
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "val")]
+[JsonDerivedType(typeof(BoxEmpty), "empty")]
+public abstract record Box;
+
+public sealed record BoxEmpty : Box;
+
+[JsonConverter(typeof(JsonStringEnumConverter<Self>))]
+public enum Self
+{
+    [JsonStringEnumMemberName("")]
+    _,
+    [JsonStringEnumMemberName("2fa")]
+    _2Fa,
+    None,
+    [JsonStringEnumMemberName("class")]
+    Class,
+    [JsonStringEnumMemberName("foo-bar")]
+    FooBar,
+    [JsonStringEnumMemberName("self")]
+    Self_,
+}
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "kind-of")]
+[JsonDerivedType(typeof(Shape2D), "2d")]
+[JsonDerivedType(typeof(ShapeSelf), "self")]
+public abstract record Shape;
+
+public sealed record Shape2D : Shape
+{
+    [JsonPropertyName("val")]
+    public required double Val { get; init; }
+}
+
+public sealed record ShapeSelf : Shape;
+
+public sealed record ValidationError
+{
+    [JsonPropertyName("message")]
+    public required string Message { get; init; }
+}
+
+public sealed record Identifiers
+{
+    [JsonPropertyName("$ref")]
+    public required X3D Ref { get; init; }
+
+    [JsonPropertyName("2fa")]
+    public required bool _2Fa { get; init; }
+
+    [JsonPropertyName("None")]
+    public required double None { get; init; }
+
+    [JsonPropertyName("box")]
+    public required Box Box { get; init; }
+
+    [JsonPropertyName("class")]
+    public required string Class { get; init; }
+
+    [JsonPropertyName("error")]
+    public required ValidationError Error { get; init; }
+
+    [JsonPropertyName("my-prop")]
+    public required bool MyProp { get; init; }
+
+    [JsonPropertyName("self")]
+    public required Self Self { get; init; }
+
+    [JsonPropertyName("shape")]
+    public required Shape Shape { get; init; }
+
+    [JsonPropertyName("type")]
+    public required string Type { get; init; }
+
+    [JsonPropertyName("ünïcode")]
+    public required string ünïcode { get; init; }
+
+    [JsonPropertyName("default")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Default { get; init; }
+
+    [JsonPropertyName("with space")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public List<string>? WithSpace { get; init; }
+}
@@ -0,0 +1,210 @@
+class X3D {
+  final double x;
+
+  const X3D({
+    required this.x,
+  });
+
+  factory X3D.fromJson(Map<String, dynamic> json) {
+    return X3D(
+      x: (json["x"] as num).toDouble(),
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "x": x,
+    };
+  }
+}
+
+sealed class Box {
+  const Box();
+
+  factory Box.fromJson(Map<String, dynamic> json) {
+    return switch (json["val"]) {
+      "empty" => BoxEmpty.fromJson(json),
+      _ => throw FormatException("unknown Box tag", json["val"]),
+    };
+  }
+
+  Map<String, dynamic> toJson();
+}
+
+class BoxEmpty extends Box {
+  const BoxEmpty();
+
+  factory BoxEmpty.fromJson(Map<String, dynamic> json) {
+    return const BoxEmpty();
+  }
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      "val": "empty",
+    };
+  }
+}
+
+enum Self {
+  $(""),
+  $2Fa("2fa"),
+  none("None"),
+  class_("class"),
+  fooBar("foo-bar"),
+  self("self");
+
+  const Self(this.value);
+
+  final String value;
+
+  factory Self.fromJson(String json) {
+    return values.firstWhere(
+      (value) => value.value == json,
+      orElse: () => throw FormatException("unknown Self value", json),
+    );
+  }
+
+  String toJson() => value;
+}
+
+sealed class Shape {
+  const Shape();
+
+  factory Shape.fromJson(Map<String, dynamic> json) {
+    return switch (json["kind-of"]) {
+      "2d" => Shape2D.fromJson(json),
+      "self" => ShapeSelf.fromJson(json),
+      _ => throw FormatException("unknown Shape tag", json["kind-of"]),
+    };
+  }
+
+  Map<String, dynamic> toJson();
+}
+
+class Shape2D extends Shape {
+  final double val;
+
+  const Shape2D({
+    required this.val,
+  });
+
+  factory Shape2D.fromJson(Map<String, dynamic> json) {
+    return Shape2D(
+      val: (json["val"] as num).toDouble(),
+    );
+  }
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      "kind-of": "2d",
+      "val": val,
+    };
+  }
+}
+
+class ShapeSelf extends Shape {
+  const ShapeSelf();
+
+  factory ShapeSelf.fromJson(Map<String, dynamic> json) {
+    return const ShapeSelf();
+  }
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      "kind-of": "self",
+    };
+  }
+}
+
+typedef String_ = String;
+
+class ValidationError {
+  final String message;
+
+  const ValidationError({
+    required this.message,
+  });
+
+  factory ValidationError.fromJson(Map<String, dynamic> json) {
+    return ValidationError(
+      message: json["message"] as String,
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "message": message,
+    };
+  }
+}
+
+class Identifiers {
+  final X3D ref;
+  final bool $2Fa;
+  final double none;
+  final Box box;
+  final String class_;
+  final ValidationError error;
+  final bool myProp;
+  final Self self;
+  final Shape shape;
+  final String_ type;
+  final String ünïcode;
+  final String? default_;
+  final List<String>? withSpace;
+
+  const Identifiers({
+    required this.ref,
+    required this.$2Fa,
+    required this.none,
+    required this.box,
+    required this.class_,
+    required this.error,
+    required this.myProp,
+    required this.self,
+    required this.shape,
+    required this.type,
+    required this.ünïcode,
+    this.default_,
+    this.withSpace,
+  });
+
+  factory Identifiers.fromJson(Map<String, dynamic> json) {
+    return Identifiers(
+      ref: X3D.fromJson(json["\$ref"] as Map<String, dynamic>),
+      $2Fa: json["2fa"] as bool,
+      none: (json["None"] as num).toDouble(),
+      box: Box.fromJson(json["box"] as Map<String, dynamic>),
+      class_: json["class"] as String,
+      error: ValidationError.fromJson(json["error"] as Map<String, dynamic>),
+      myProp: json["my-prop"] as bool,
+      self: Self.fromJson(json["self"] as String),
+      shape: Shape.fromJson(json["shape"] as Map<String, dynamic>),
+      type: json["type"] as String,
+      ünïcode: json["ünïcode"] as String,
+      default_: json["default"] as String?,
+      withSpace: json["with space"] == null ? null : (json["with space"] as List<dynamic>).map((v0) => v0 as String).toList(),
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "\$ref": ref.toJson(),
+      "2fa": $2Fa,
+      "None": none,
+      "box": box.toJson(),
+      "class": class_,
+      "error": error.toJson(),
+      "my-prop": myProp,
+      "self": self.toJson(),
+      "shape": shape.toJson(),
+      "type": type,
+      "ünïcode": ünïcode,
+      if (default_ != null) "default": default_,
+      if (withSpace != null) "with space": withSpace,
+    };
+  }
+}
@@ -0,0 +1,457 @@
+package models
+
+import (
+	"encoding/json"
+	"strconv"
+)
+
+type X3D struct {
+	X float64 `json:"x"`
+}
+
+func ValidateX3D(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateX3D(instance, nil, &errors)
+	return errors
+}
+
+func validateX3D(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/3d/properties"})
+		return
+	}
+	if v0, ok := obj["x"]; ok {
+		if _, ok := v0.(float64); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "x")), SchemaPath: "/definitions/3d/properties/x/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/3d/properties/x"})
+	}
+}
+
+func DecodeX3D(data []byte) (X3D, error) {
+	var out X3D
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateX3D(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type BoxEmpty struct {
+}
+
+type Box struct {
+	Val_ string `json:"val"`
+	Val interface{} `json:"-"`
+}
+
+func (s *Box) UnmarshalJSON(buf []byte) error {
+	var x struct{ Tag string `json:"val"` }
+	if err := json.Unmarshal(buf, &x); err != nil {
+		return err
+	}
+	switch x.Tag {
+	case "empty":
+		var data BoxEmpty
+		if err := json.Unmarshal(buf, &data); err != nil {
+			return err
+		}
+		s.Val = data
+	}
+	s.Val_ = x.Tag
+	return nil
+}
+
+func (s Box) MarshalJSON() ([]byte, error) {
+	switch val := s.Val.(type) {
+	case BoxEmpty:
+		var data struct{
+			Tag string `json:"val"`
+			BoxEmpty
+		}
+		data.BoxEmpty = val
+		data.Tag = s.Val_
+		return json.Marshal(data)
+	}
+	panic("invalid discriminator tag")
+}
+
+func ValidateBox(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateBox(instance, nil, &errors)
+	return errors
+}
+
+func validateBox(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/box/discriminator"})
+		return
+	}
+	tag, ok := obj["val"]
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/box/discriminator/tag"})
+		return
+	}
+	switch tag {
+	case "empty":
+	default:
+		if _, ok := tag.(string); ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "val")), SchemaPath: "/definitions/box/discriminator/mapping"})
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "val")), SchemaPath: "/definitions/box/discriminator/tag"})
+		}
+	}
+}
+
+func DecodeBox(data []byte) (Box, error) {
+	var out Box
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateBox(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type Self string
+
+const (
+	SelfX Self = ""
+	SelfX2Fa Self = "2fa"
+	SelfNone Self = "None"
+	SelfClass Self = "class"
+	SelfFooBar Self = "foo-bar"
+	SelfSelf Self = "self"
+)
+
+func ValidateSelf(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateSelf(instance, nil, &errors)
+	return errors
+}
+
+func validateSelf(instance interface{}, path []string, errors *[]ValidationError) {
+	switch instance {
+	case "", "2fa", "None", "class", "foo-bar", "self":
+	default:
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/self/enum"})
+	}
+}
+
+func DecodeSelf(data []byte) (Self, error) {
+	var out Self
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateSelf(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type Shape2D struct {
+	Val float64 `json:"val"`
+}
+
+type ShapeSelf struct {
+}
+
+type Shape struct {
+	KindOf string `json:"kind-of"`
+	Val interface{} `json:"-"`
+}
+
+func (s *Shape) UnmarshalJSON(buf []byte) error {
+	var x struct{ Tag string `json:"kind-of"` }
+	if err := json.Unmarshal(buf, &x); err != nil {
+		return err
+	}
+	switch x.Tag {
+	case "2d":
+		var data Shape2D
+		if err := json.Unmarshal(buf, &data); err != nil {
+			return err
+		}
+		s.Val = data
+	case "self":
+		var data ShapeSelf
+		if err := json.Unmarshal(buf, &data); err != nil {
+			return err
+		}
+		s.Val = data
+	}
+	s.KindOf = x.Tag
+	return nil
+}
+
+func (s Shape) MarshalJSON() ([]byte, error) {
+	switch val := s.Val.(type) {
+	case Shape2D:
+		var data struct{
+			Tag string `json:"kind-of"`
+			Shape2D
+		}
+		data.Shape2D = val
+		data.Tag = s.KindOf
+		return json.Marshal(data)
+	case ShapeSelf:
+		var data struct{
+			Tag string `json:"kind-of"`
+			ShapeSelf
+		}
+		data.ShapeSelf = val
+		data.Tag = s.KindOf
+		return json.Marshal(data)
+	}
+	panic("invalid discriminator tag")
+}
+
+func ValidateShape(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateShape(instance, nil, &errors)
+	return errors
+}
+
+func validateShape(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/shape/discriminator"})
+		return
+	}
+	tag, ok := obj["kind-of"]
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/shape/discriminator/tag"})
+		return
+	}
+	switch tag {
+	case "2d":
+		if v0, ok := obj["val"]; ok {
+			if _, ok := v0.(float64); !ok {
+				*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "val")), SchemaPath: "/definitions/shape/discriminator/mapping/2d/properties/val/type"})
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/shape/discriminator/mapping/2d/properties/val"})
+		}
+	case "self":
+	default:
+		if _, ok := tag.(string); ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "kind-of")), SchemaPath: "/definitions/shape/discriminator/mapping"})
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "kind-of")), SchemaPath: "/definitions/shape/discriminator/tag"})
+		}
+	}
+}
+
+func DecodeShape(data []byte) (Shape, error) {
+	var out Shape
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateShape(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type String string
+
+func ValidateString(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateString(instance, nil, &errors)
+	return errors
+}
+
+func validateString(instance interface{}, path []string, errors *[]ValidationError) {
+	if _, ok := instance.(string); !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/string/type"})
+	}
+}
+
+func DecodeString(data []byte) (String, error) {
+	var out String
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateString(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type ValidationError_ struct {
+	Message string `json:"message"`
+}
+
+func ValidateValidationError_(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateValidationError_(instance, nil, &errors)
+	return errors
+}
+
+func validateValidationError_(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/validationError/properties"})
+		return
+	}
+	if v0, ok := obj["message"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "message")), SchemaPath: "/definitions/validationError/properties/message/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/validationError/properties/message"})
+	}
+}
+
+func DecodeValidationError_(data []byte) (ValidationError_, error) {
+	var out ValidationError_
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateValidationError_(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type Identifiers struct {
+	Ref X3D `json:"$ref"`
+	X2Fa bool `json:"2fa"`
+	None float64 `json:"None"`
+	Box Box `json:"box"`
+	Class string `json:"class"`
+	Error ValidationError_ `json:"error"`
+	MyProp bool `json:"my-prop"`
+	Self Self `json:"self"`
+	Shape Shape `json:"shape"`
+	Type String `json:"type"`
+	Xünïcode string `json:"ünïcode"`
+	Default *string `json:"default,omitempty"`
+	WithSpace []string `json:"with space,omitempty"`
+}
+
+func ValidateIdentifiers(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateIdentifiers(instance, nil, &errors)
+	return errors
+}
+
+func validateIdentifiers(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties"})
+		return
+	}
+	if v0, ok := obj["$ref"]; ok {
+		validateX3D(v0, jslAppend(path, "$ref"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/$ref"})
+	}
+	if v0, ok := obj["2fa"]; ok {
+		if _, ok := v0.(bool); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "2fa")), SchemaPath: "/properties/2fa/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/2fa"})
+	}
+	if v0, ok := obj["None"]; ok {
+		if _, ok := v0.(float64); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "None")), SchemaPath: "/properties/None/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/None"})
+	}
+	if v0, ok := obj["box"]; ok {
+		validateBox(v0, jslAppend(path, "box"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/box"})
+	}
+	if v0, ok := obj["class"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "class")), SchemaPath: "/properties/class/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/class"})
+	}
+	if v0, ok := obj["error"]; ok {
+		validateValidationError_(v0, jslAppend(path, "error"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/error"})
+	}
+	if v0, ok := obj["my-prop"]; ok {
+		if _, ok := v0.(bool); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "my-prop")), SchemaPath: "/properties/my-prop/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/my-prop"})
+	}
+	if v0, ok := obj["self"]; ok {
+		validateSelf(v0, jslAppend(path, "self"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/self"})
+	}
+	if v0, ok := obj["shape"]; ok {
+		validateShape(v0, jslAppend(path, "shape"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/shape"})
+	}
+	if v0, ok := obj["type"]; ok {
+		validateString(v0, jslAppend(path, "type"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/type"})
+	}
+	if v0, ok := obj["ünïcode"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "ünïcode")), SchemaPath: "/properties/ünïcode/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/ünïcode"})
+	}
+	if v0, ok := obj["default"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "default")), SchemaPath: "/optionalProperties/default/type"})
+		}
+	}
+	if v0, ok := obj["with space"]; ok {
+		if a0, ok := v0.([]interface{}); ok {
+			for i0, v1 := range a0 {
+				if _, ok := v1.(string); !ok {
+					*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "with space", strconv.Itoa(i0))), SchemaPath: "/optionalProperties/with space/elements/type"})
+				}
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "with space")), SchemaPath: "/optionalProperties/with space/elements"})
+		}
+	}
+}
+
+func DecodeIdentifiers(data []byte) (Identifiers, error) {
+	var out Identifiers
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateIdentifiers(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
@@ -0,0 +1,43 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonSubTypes;
+import com.fasterxml.jackson.annotation.JsonTypeInfo;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "val")
+@JsonSubTypes({
+    @JsonSubTypes.Type(value = BoxEmpty.class, name = "empty"),
+})
+public abstract class Box {
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/definitions/box/discriminator"));
+            return;
+        }
+        if (!instance.has("val")) {
+            errors.add(new ValidationError(path, "/definitions/box/discriminator/tag"));
+            return;
+        }
+        if (!instance.get("val").isTextual()) {
+            errors.add(new ValidationError(ValidationError.append(path, "val"), "/definitions/box/discriminator/tag"));
+            return;
+        }
+
+        switch (instance.get("val").asText()) {
+        case "empty":
+            break;
+        default:
+            errors.add(new ValidationError(ValidationError.append(path, "val"), "/definitions/box/discriminator/mapping"));
+        }
+    }
+}
@@ -0,0 +1,146 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class Identifiers {
+    @JsonProperty("$ref")
+    public X3D ref;
+    @JsonProperty("2fa")
+    public boolean _2Fa;
+    public double None;
+    public Box box;
+    @JsonProperty("class")
+    public String class_;
+    public ValidationError_ error;
+    @JsonProperty("my-prop")
+    public boolean myProp;
+    public Self self;
+    public Shape shape;
+    public String type;
+    public String ünïcode;
+    @JsonProperty("default")
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public String default_;
+    @JsonProperty("with space")
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public List<String> withSpace;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/properties"));
+            return;
+        }
+        if (instance.has("$ref")) {
+            JsonNode v0 = instance.get("$ref");
+            X3D.validate(v0, ValidationError.append(path, "$ref"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/$ref"));
+        }
+        if (instance.has("2fa")) {
+            JsonNode v0 = instance.get("2fa");
+            if (!v0.isBoolean()) {
+                errors.add(new ValidationError(ValidationError.append(path, "2fa"), "/properties/2fa/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/2fa"));
+        }
+        if (instance.has("None")) {
+            JsonNode v0 = instance.get("None");
+            if (!v0.isNumber()) {
+                errors.add(new ValidationError(ValidationError.append(path, "None"), "/properties/None/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/None"));
+        }
+        if (instance.has("box")) {
+            JsonNode v0 = instance.get("box");
+            Box.validate(v0, ValidationError.append(path, "box"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/box"));
+        }
+        if (instance.has("class")) {
+            JsonNode v0 = instance.get("class");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "class"), "/properties/class/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/class"));
+        }
+        if (instance.has("error")) {
+            JsonNode v0 = instance.get("error");
+            ValidationError_.validate(v0, ValidationError.append(path, "error"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/error"));
+        }
+        if (instance.has("my-prop")) {
+            JsonNode v0 = instance.get("my-prop");
+            if (!v0.isBoolean()) {
+                errors.add(new ValidationError(ValidationError.append(path, "my-prop"), "/properties/my-prop/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/my-prop"));
+        }
+        if (instance.has("self")) {
+            JsonNode v0 = instance.get("self");
+            Self.validate(v0, ValidationError.append(path, "self"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/self"));
+        }
+        if (instance.has("shape")) {
+            JsonNode v0 = instance.get("shape");
+            Shape.validate(v0, ValidationError.append(path, "shape"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/shape"));
+        }
+        if (instance.has("type")) {
+            JsonNode v0 = instance.get("type");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "type"), "/definitions/string/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/type"));
+        }
+        if (instance.has("ünïcode")) {
+            JsonNode v0 = instance.get("ünïcode");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "ünïcode"), "/properties/ünïcode/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/ünïcode"));
+        }
+        if (instance.has("default")) {
+            JsonNode v0 = instance.get("default");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "default"), "/optionalProperties/default/type"));
+            }
+        }
+        if (instance.has("with space")) {
+            JsonNode v0 = instance.get("with space");
+            if (v0.isArray()) {
+                for (int i0 = 0; i0 < v0.size(); i0++) {
+                    JsonNode v1 = v0.get(i0);
+                    if (!v1.isTextual()) {
+                        errors.add(new ValidationError(ValidationError.append(path, "with space", Integer.toString(i0)), "/optionalProperties/with space/elements/type"));
+                    }
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "with space"), "/optionalProperties/with space/elements"));
+            }
+        }
+    }
+}
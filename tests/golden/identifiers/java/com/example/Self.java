@@ -0,0 +1,44 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+public enum Self {
+   @JsonProperty("")
+   __,
+   @JsonProperty("2fa")
+   _2FA,
+   None,
+   @JsonProperty("class")
+   class_,
+   @JsonProperty("foo-bar")
+   FOO_BAR,
+   self,
+   ;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (instance.isTextual()) {
+            switch (instance.asText()) {
+            case "":
+            case "2fa":
+            case "None":
+            case "class":
+            case "foo-bar":
+            case "self":
+                return;
+            }
+        }
+
+        errors.add(new ValidationError(path, "/definitions/self/enum"));
+    }
+}
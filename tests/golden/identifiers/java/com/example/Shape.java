@@ -0,0 +1,54 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonSubTypes;
+import com.fasterxml.jackson.annotation.JsonTypeInfo;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "kind-of")
+@JsonSubTypes({
+    @JsonSubTypes.Type(value = Shape2D.class, name = "2d"),
+    @JsonSubTypes.Type(value = ShapeSelf.class, name = "self"),
+})
+public abstract class Shape {
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/definitions/shape/discriminator"));
+            return;
+        }
+        if (!instance.has("kind-of")) {
+            errors.add(new ValidationError(path, "/definitions/shape/discriminator/tag"));
+            return;
+        }
+        if (!instance.get("kind-of").isTextual()) {
+            errors.add(new ValidationError(ValidationError.append(path, "kind-of"), "/definitions/shape/discriminator/tag"));
+            return;
+        }
+
+        switch (instance.get("kind-of").asText()) {
+        case "2d":
+            if (instance.has("val")) {
+                JsonNode v0 = instance.get("val");
+                if (!v0.isNumber()) {
+                    errors.add(new ValidationError(ValidationError.append(path, "val"), "/definitions/shape/discriminator/mapping/2d/properties/val/type"));
+                }
+            } else {
+                errors.add(new ValidationError(path, "/definitions/shape/discriminator/mapping/2d/properties/val"));
+            }
+            break;
+        case "self":
+            break;
+        default:
+            errors.add(new ValidationError(ValidationError.append(path, "kind-of"), "/definitions/shape/discriminator/mapping"));
+        }
+    }
+}
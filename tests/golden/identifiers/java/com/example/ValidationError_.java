@@ -0,0 +1,35 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class ValidationError_ {
+    public String message;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/definitions/validationError/properties"));
+            return;
+        }
+        if (instance.has("message")) {
+            JsonNode v0 = instance.get("message");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "message"), "/definitions/validationError/properties/message/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/definitions/validationError/properties/message"));
+        }
+    }
+}
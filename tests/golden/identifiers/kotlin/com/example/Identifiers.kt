@@ -0,0 +1,88 @@
+@file:OptIn(ExperimentalSerializationApi::class)
+
+package com.example
+
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.json.JsonClassDiscriminator
+import kotlinx.serialization.json.JsonIgnoreUnknownKeys
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class X3D(
+    val x: Double,
+)
+
+@Serializable
+@JsonClassDiscriminator("val")
+sealed class Box
+
+@Serializable
+@SerialName("empty")
+@JsonIgnoreUnknownKeys
+class BoxEmpty : Box()
+
+@Serializable
+enum class Self {
+    @SerialName("")
+    X,
+    @SerialName("2fa")
+    X2FA,
+    @SerialName("None")
+    NONE,
+    @SerialName("class")
+    CLASS,
+    @SerialName("foo-bar")
+    FOO_BAR,
+    @SerialName("self")
+    SELF,
+}
+
+@Serializable
+@JsonClassDiscriminator("kind-of")
+sealed class Shape
+
+@Serializable
+@SerialName("2d")
+@JsonIgnoreUnknownKeys
+data class Shape2D(
+    @SerialName("val")
+    val val_: Double,
+) : Shape()
+
+@Serializable
+@SerialName("self")
+@JsonIgnoreUnknownKeys
+class ShapeSelf : Shape()
+
+typealias String_ = String
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class ValidationError(
+    val message: String,
+)
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class Identifiers(
+    @SerialName("\$ref")
+    val ref: X3D,
+    @SerialName("2fa")
+    val _2Fa: Boolean,
+    val None: Double,
+    val box: Box,
+    @SerialName("class")
+    val class_: String,
+    val error: ValidationError,
+    @SerialName("my-prop")
+    val myProp: Boolean,
+    val self: Self,
+    val shape: Shape,
+    val type: String_,
+    val ünïcode: String,
+    val default: String? = null,
+    @SerialName("with space")
+    val withSpace: List<String>? = null,
+)
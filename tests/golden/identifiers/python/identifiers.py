@@ -0,0 +1,172 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from enum import Enum
+from typing import Any, Dict, List, Optional
+
+
+@dataclass
+class X3D:
+    x: float
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> X3D:
+        return cls(
+            x=data["x"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["x"] = self.x
+        return data
+
+
+@dataclass
+class Box:
+    @staticmethod
+    def from_json(data: Dict[str, Any]) -> Box:
+        variants = {
+            "empty": BoxEmpty,
+        }
+
+        return variants[data["val"]].from_json(data)
+
+    def to_json(self) -> Dict[str, Any]:
+        raise NotImplementedError
+
+
+@dataclass
+class BoxEmpty(Box):
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> BoxEmpty:
+        return cls(
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {"val": "empty"}
+        return data
+
+
+class Self(Enum):
+    _ = ""
+    _2FA = "2fa"
+    NONE = "None"
+    CLASS = "class"
+    FOO_BAR = "foo-bar"
+    SELF = "self"
+
+
+@dataclass
+class Shape:
+    @staticmethod
+    def from_json(data: Dict[str, Any]) -> Shape:
+        variants = {
+            "2d": Shape2D,
+            "self": ShapeSelf,
+        }
+
+        return variants[data["kind-of"]].from_json(data)
+
+    def to_json(self) -> Dict[str, Any]:
+        raise NotImplementedError
+
+
+@dataclass
+class Shape2D(Shape):
+    val: float
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Shape2D:
+        return cls(
+            val=data["val"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {"kind-of": "2d"}
+        data["val"] = self.val
+        return data
+
+
+@dataclass
+class ShapeSelf(Shape):
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> ShapeSelf:
+        return cls(
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {"kind-of": "self"}
+        return data
+
+
+String = str
+
+
+@dataclass
+class ValidationError:
+    message: str
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> ValidationError:
+        return cls(
+            message=data["message"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["message"] = self.message
+        return data
+
+
+@dataclass
+class Identifiers:
+    ref: X3D
+    _2fa: bool
+    none: float
+    box: Box
+    class_: str
+    error: ValidationError
+    my_prop: bool
+    self_: Self
+    shape: Shape
+    type: String
+    ün_ïcode: str
+    default: Optional[str] = None
+    with_space: Optional[List[str]] = None
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Identifiers:
+        return cls(
+            ref=X3D.from_json(data["$ref"]),
+            _2fa=data["2fa"],
+            none=data["None"],
+            box=Box.from_json(data["box"]),
+            class_=data["class"],
+            error=ValidationError.from_json(data["error"]),
+            my_prop=data["my-prop"],
+            self_=Self(data["self"]),
+            shape=Shape.from_json(data["shape"]),
+            type=data["type"],
+            ün_ïcode=data["ünïcode"],
+            default=data.get("default"),
+            with_space=data.get("with space"),
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["$ref"] = self.ref.to_json()
+        data["2fa"] = self._2fa
+        data["None"] = self.none
+        data["box"] = self.box.to_json()
+        data["class"] = self.class_
+        data["error"] = self.error.to_json()
+        data["my-prop"] = self.my_prop
+        data["self"] = self.self_.value
+        data["shape"] = self.shape.to_json()
+        data["type"] = self.type
+        data["ünïcode"] = self.ün_ïcode
+        if self.default is not None:
+            data["default"] = self.default
+        if self.with_space is not None:
+            data["with space"] = self.with_space
+        return data
@@ -0,0 +1,85 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct X3D {
+    pub x: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BoxEmpty {
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "val")]
+pub enum Box {
+    #[serde(rename = "empty")]
+    Empty(BoxEmpty),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Self_ {
+    #[serde(rename = "")]
+    X,
+    #[serde(rename = "2fa")]
+    X2Fa,
+    None,
+    #[serde(rename = "class")]
+    Class,
+    #[serde(rename = "foo-bar")]
+    FooBar,
+    #[serde(rename = "self")]
+    Self_,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Shape2D {
+    pub val: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ShapeSelf {
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind-of")]
+pub enum Shape {
+    #[serde(rename = "2d")]
+    X2D(Shape2D),
+    #[serde(rename = "self")]
+    Self_(ShapeSelf),
+}
+
+pub type String_ = String;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ValidationError {
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Identifiers {
+    #[serde(rename = "$ref")]
+    pub ref_: X3D,
+    #[serde(rename = "2fa")]
+    pub _2fa: bool,
+    #[serde(rename = "None")]
+    pub none: f64,
+    #[serde(rename = "box")]
+    pub box_: Box,
+    pub class: String,
+    pub error: ValidationError,
+    #[serde(rename = "my-prop")]
+    pub my_prop: bool,
+    #[serde(rename = "self")]
+    pub self_: Self_,
+    pub shape: Shape,
+    #[serde(rename = "type")]
+    pub type_: String_,
+    #[serde(rename = "ünïcode")]
+    pub ün_ïcode: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    #[serde(rename = "with space")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub with_space: Option<Vec<String>>,
+}
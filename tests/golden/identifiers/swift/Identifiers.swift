@@ -0,0 +1,124 @@
+import Foundation
+
+struct X3D: Codable, Equatable {
+    var x: Double
+}
+
+struct BoxEmpty: Codable, Equatable {
+}
+
+enum Box: Codable, Equatable {
+    case empty(BoxEmpty)
+
+    enum CodingKeys: String, CodingKey {
+        case tag = "val"
+    }
+
+    init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .tag)
+        switch tag {
+        case "empty":
+            self = .empty(try BoxEmpty(from: decoder))
+        default:
+            throw DecodingError.dataCorruptedError(forKey: .tag, in: container, debugDescription: "Unknown tag: \(tag)")
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case .empty(let value):
+            try container.encode("empty", forKey: .tag)
+            try value.encode(to: encoder)
+        }
+    }
+}
+
+enum Self_: String, Codable, Equatable {
+    case __ = ""
+    case _2Fa = "2fa"
+    case none = "None"
+    case class_ = "class"
+    case fooBar = "foo-bar"
+    case self_ = "self"
+}
+
+struct Shape2D: Codable, Equatable {
+    var val: Double
+}
+
+struct ShapeSelf: Codable, Equatable {
+}
+
+enum Shape: Codable, Equatable {
+    case _2D(Shape2D)
+    case self_(ShapeSelf)
+
+    enum CodingKeys: String, CodingKey {
+        case tag = "kind-of"
+    }
+
+    init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .tag)
+        switch tag {
+        case "2d":
+            self = ._2D(try Shape2D(from: decoder))
+        case "self":
+            self = .self_(try ShapeSelf(from: decoder))
+        default:
+            throw DecodingError.dataCorruptedError(forKey: .tag, in: container, debugDescription: "Unknown tag: \(tag)")
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case ._2D(let value):
+            try container.encode("2d", forKey: .tag)
+            try value.encode(to: encoder)
+        case .self_(let value):
+            try container.encode("self", forKey: .tag)
+            try value.encode(to: encoder)
+        }
+    }
+}
+
+typealias String_ = String
+
+struct ValidationError: Codable, Equatable {
+    var message: String
+}
+
+struct Identifiers: Codable, Equatable {
+    var ref: X3D
+    var _2Fa: Bool
+    var None: Double
+    var box: Box
+    var class_: String
+    var error: ValidationError
+    var myProp: Bool
+    var self_: Self_
+    var shape: Shape
+    var type: String_
+    var ünïcode: String
+    var default_: String?
+    var withSpace: [String]?
+
+    enum CodingKeys: String, CodingKey {
+        case ref = "$ref"
+        case _2Fa = "2fa"
+        case None
+        case box
+        case class_ = "class"
+        case error
+        case myProp = "my-prop"
+        case self_ = "self"
+        case shape
+        case type
+        case ünïcode
+        case default_ = "default"
+        case withSpace = "with space"
+    }
+}
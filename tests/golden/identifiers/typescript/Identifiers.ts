@@ -0,0 +1,262 @@
+export interface ValidationError {
+  instancePath: string;
+  schemaPath: string;
+}
+function jslPointer(path: string[]): string {
+  return path.map((token) => "/" + token.replace(/~/g, "~0").replace(/\//g, "~1")).join("");
+}
+function jslIsObject(instance: unknown): instance is { [key: string]: unknown } {
+  return typeof instance === "object" && instance !== null && !Array.isArray(instance);
+}
+function jslHas(instance: { [key: string]: unknown }, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(instance, key);
+}
+export interface X3D {
+  x: number;
+  [key: string]: unknown;
+}
+export function validateX3D(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateX3DAt(instance, [], errors);
+  return errors;
+}
+export function validateX3DAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/3d/properties" });
+    return;
+  }
+  if (jslHas(instance, "x")) {
+    if (typeof instance["x"] !== "number") {
+      errors.push({ instancePath: jslPointer([...path, "x"]), schemaPath: "/definitions/3d/properties/x/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/3d/properties/x" });
+  }
+}
+export interface BoxEmpty {
+  val: "empty";
+  [key: string]: unknown;
+}
+export type Box = BoxEmpty;
+export function validateBox(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateBoxAt(instance, [], errors);
+  return errors;
+}
+export function validateBoxAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/box/discriminator" });
+    return;
+  }
+  if (!jslHas(instance, "val")) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/box/discriminator/tag" });
+    return;
+  }
+  switch (instance["val"]) {
+    case "empty":
+      break;
+    default:
+      if (typeof instance["val"] === "string") {
+        errors.push({ instancePath: jslPointer([...path, "val"]), schemaPath: "/definitions/box/discriminator/mapping" });
+      } else {
+        errors.push({ instancePath: jslPointer([...path, "val"]), schemaPath: "/definitions/box/discriminator/tag" });
+      }
+  }
+}
+export type Self = "" | "2fa" | "None" | "class" | "foo-bar" | "self";
+export function validateSelf(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateSelfAt(instance, [], errors);
+  return errors;
+}
+export function validateSelfAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (instance !== "" && instance !== "2fa" && instance !== "None" && instance !== "class" && instance !== "foo-bar" && instance !== "self") {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/self/enum" });
+  }
+}
+export interface Shape2D {
+  "kind-of": "2d";
+  val: number;
+  [key: string]: unknown;
+}
+export interface ShapeSelf {
+  "kind-of": "self";
+  [key: string]: unknown;
+}
+export type Shape = Shape2D | ShapeSelf;
+export function validateShape(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateShapeAt(instance, [], errors);
+  return errors;
+}
+export function validateShapeAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/shape/discriminator" });
+    return;
+  }
+  if (!jslHas(instance, "kind-of")) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/shape/discriminator/tag" });
+    return;
+  }
+  switch (instance["kind-of"]) {
+    case "2d":
+      if (jslHas(instance, "val")) {
+        if (typeof instance["val"] !== "number") {
+          errors.push({ instancePath: jslPointer([...path, "val"]), schemaPath: "/definitions/shape/discriminator/mapping/2d/properties/val/type" });
+        }
+      } else {
+        errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/shape/discriminator/mapping/2d/properties/val" });
+      }
+      break;
+    case "self":
+      break;
+    default:
+      if (typeof instance["kind-of"] === "string") {
+        errors.push({ instancePath: jslPointer([...path, "kind-of"]), schemaPath: "/definitions/shape/discriminator/mapping" });
+      } else {
+        errors.push({ instancePath: jslPointer([...path, "kind-of"]), schemaPath: "/definitions/shape/discriminator/tag" });
+      }
+  }
+}
+export type String = string;
+export function validateString(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateStringAt(instance, [], errors);
+  return errors;
+}
+export function validateStringAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (typeof instance !== "string") {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/string/type" });
+  }
+}
+export interface ValidationError_ {
+  message: string;
+  [key: string]: unknown;
+}
+export function validateValidationError_(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateValidationError_At(instance, [], errors);
+  return errors;
+}
+export function validateValidationError_At(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/validationError/properties" });
+    return;
+  }
+  if (jslHas(instance, "message")) {
+    if (typeof instance["message"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "message"]), schemaPath: "/definitions/validationError/properties/message/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/validationError/properties/message" });
+  }
+}
+export interface Identifiers {
+  $ref: X3D;
+  "2fa": boolean;
+  None: number;
+  box: Box;
+  class: string;
+  error: ValidationError_;
+  "my-prop": boolean;
+  self: Self;
+  shape: Shape;
+  type: String;
+  "ünïcode": string;
+  default?: string;
+  "with space"?: string[];
+  [key: string]: unknown;
+}
+export function validateIdentifiers(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateIdentifiersAt(instance, [], errors);
+  return errors;
+}
+export function validateIdentifiersAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties" });
+    return;
+  }
+  if (jslHas(instance, "$ref")) {
+    validateX3DAt(instance["$ref"], [...path, "$ref"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/$ref" });
+  }
+  if (jslHas(instance, "2fa")) {
+    if (typeof instance["2fa"] !== "boolean") {
+      errors.push({ instancePath: jslPointer([...path, "2fa"]), schemaPath: "/properties/2fa/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/2fa" });
+  }
+  if (jslHas(instance, "None")) {
+    if (typeof instance["None"] !== "number") {
+      errors.push({ instancePath: jslPointer([...path, "None"]), schemaPath: "/properties/None/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/None" });
+  }
+  if (jslHas(instance, "box")) {
+    validateBoxAt(instance["box"], [...path, "box"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/box" });
+  }
+  if (jslHas(instance, "class")) {
+    if (typeof instance["class"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "class"]), schemaPath: "/properties/class/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/class" });
+  }
+  if (jslHas(instance, "error")) {
+    validateValidationError_At(instance["error"], [...path, "error"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/error" });
+  }
+  if (jslHas(instance, "my-prop")) {
+    if (typeof instance["my-prop"] !== "boolean") {
+      errors.push({ instancePath: jslPointer([...path, "my-prop"]), schemaPath: "/properties/my-prop/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/my-prop" });
+  }
+  if (jslHas(instance, "self")) {
+    validateSelfAt(instance["self"], [...path, "self"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/self" });
+  }
+  if (jslHas(instance, "shape")) {
+    validateShapeAt(instance["shape"], [...path, "shape"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/shape" });
+  }
+  if (jslHas(instance, "type")) {
+    validateStringAt(instance["type"], [...path, "type"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/type" });
+  }
+  if (jslHas(instance, "ünïcode")) {
+    if (typeof instance["ünïcode"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "ünïcode"]), schemaPath: "/properties/ünïcode/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/ünïcode" });
+  }
+  if (jslHas(instance, "default")) {
+    if (typeof instance["default"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "default"]), schemaPath: "/optionalProperties/default/type" });
+    }
+  }
+  if (jslHas(instance, "with space")) {
+    const a0: unknown = instance["with space"];
+    if (Array.isArray(a0)) {
+      a0.forEach((v0: unknown, i0: number) => {
+        if (typeof v0 !== "string") {
+          errors.push({ instancePath: jslPointer([...path, "with space", String(i0)]), schemaPath: "/optionalProperties/with space/elements/type" });
+        }
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "with space"]), schemaPath: "/optionalProperties/with space/elements" });
+    }
+  }
+}
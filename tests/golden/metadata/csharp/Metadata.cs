@@ -0,0 +1,53 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Example;
+
+[JsonConverter(typeof(JsonStringEnumConverter<Status>))]
+public enum Status
+{
+    [JsonStringEnumMemberName("done")]
+    Done,
+    [JsonStringEnumMemberName("in-progress")]
+    Active,
+}
+
+public sealed record UserId
+{
+    [JsonPropertyName("value")]
+    public required string Value { get; init; }
+}
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "type")]
+[JsonDerivedType(typeof(SignUp), "signed-up")]
+public abstract record MetadataEvent;
+
+public sealed record SignUp : MetadataEvent;
+
+public sealed record FavoriteNumber
+{
+    [JsonPropertyName("value")]
+    public required double Value { get; init; }
+}
+
+public sealed record Metadata
+{
+    [JsonPropertyName("event")]
+    public required MetadataEvent Event { get; init; }
+
+    [JsonPropertyName("favoriteNumbers")]
+    public required List<FavoriteNumber> FavoriteNumbers { get; init; }
+
+    [JsonPropertyName("id")]
+    public required UserId Id { get; init; }
+
+    [JsonPropertyName("status")]
+    public required Status Status { get; init; }
+
+    [JsonPropertyName("userName")]
+    public required string login { get; init; }
+}
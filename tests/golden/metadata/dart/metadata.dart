@@ -0,0 +1,121 @@
+enum Status {
+  done("done"),
+  Active("in-progress");
+
+  const Status(this.value);
+
+  final String value;
+
+  factory Status.fromJson(String json) {
+    return values.firstWhere(
+      (value) => value.value == json,
+      orElse: () => throw FormatException("unknown Status value", json),
+    );
+  }
+
+  String toJson() => value;
+}
+
+class UserId {
+  final String value;
+
+  const UserId({
+    required this.value,
+  });
+
+  factory UserId.fromJson(Map<String, dynamic> json) {
+    return UserId(
+      value: json["value"] as String,
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "value": value,
+    };
+  }
+}
+
+sealed class MetadataEvent {
+  const MetadataEvent();
+
+  factory MetadataEvent.fromJson(Map<String, dynamic> json) {
+    return switch (json["type"]) {
+      "signed-up" => SignUp.fromJson(json),
+      _ => throw FormatException("unknown MetadataEvent tag", json["type"]),
+    };
+  }
+
+  Map<String, dynamic> toJson();
+}
+
+class SignUp extends MetadataEvent {
+  const SignUp();
+
+  factory SignUp.fromJson(Map<String, dynamic> json) {
+    return const SignUp();
+  }
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      "type": "signed-up",
+    };
+  }
+}
+
+class FavoriteNumber {
+  final double value;
+
+  const FavoriteNumber({
+    required this.value,
+  });
+
+  factory FavoriteNumber.fromJson(Map<String, dynamic> json) {
+    return FavoriteNumber(
+      value: (json["value"] as num).toDouble(),
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "value": value,
+    };
+  }
+}
+
+class Metadata {
+  final MetadataEvent event;
+  final List<FavoriteNumber> favoriteNumbers;
+  final UserId id;
+  final Status status;
+  final String login;
+
+  const Metadata({
+    required this.event,
+    required this.favoriteNumbers,
+    required this.id,
+    required this.status,
+    required this.login,
+  });
+
+  factory Metadata.fromJson(Map<String, dynamic> json) {
+    return Metadata(
+      event: MetadataEvent.fromJson(json["event"] as Map<String, dynamic>),
+      favoriteNumbers: (json["favoriteNumbers"] as List<dynamic>).map((v0) => FavoriteNumber.fromJson(v0 as Map<String, dynamic>)).toList(),
+      id: UserId.fromJson(json["id"] as Map<String, dynamic>),
+      status: Status.fromJson(json["status"] as String),
+      login: json["userName"] as String,
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "event": event.toJson(),
+      "favoriteNumbers": favoriteNumbers.map((v0) => v0.toJson()).toList(),
+      "id": id.toJson(),
+      "status": status.toJson(),
+      "userName": login,
+    };
+  }
+}
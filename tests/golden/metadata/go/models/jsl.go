@@ -0,0 +1,45 @@
+package models
+
+import "time"
+
+type ValidationError struct {
+	InstancePath string
+	SchemaPath string
+}
+
+func jslPointer(path []string) string {
+	pointer := ""
+	for _, token := range path {
+		pointer += "/"
+		for _, c := range token {
+			switch c {
+			case '~':
+				pointer += "~0"
+			case '/':
+				pointer += "~1"
+			default:
+				pointer += string(c)
+			}
+		}
+	}
+	return pointer
+}
+
+func jslAppend(path []string, tokens ...string) []string {
+	return append(append([]string{}, path...), tokens...)
+}
+
+type ValidationErrors []ValidationError
+
+func (e ValidationErrors) Error() string {
+	return "instance at \"" + e[0].InstancePath + "\" rejected by schema at \"" + e[0].SchemaPath + "\""
+}
+
+func jslIsTimestamp(instance interface{}) bool {
+	s, ok := instance.(string)
+	if !ok {
+		return false
+	}
+	_, err := time.Parse(time.RFC3339, s)
+	return err == nil
+}
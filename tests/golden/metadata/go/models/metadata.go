@@ -0,0 +1,264 @@
+package models
+
+import (
+	"encoding/json"
+	"strconv"
+)
+
+type Status string
+
+const (
+	StatusDone Status = "done"
+	StatusActive Status = "in-progress"
+)
+
+func ValidateStatus(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateStatus(instance, nil, &errors)
+	return errors
+}
+
+func validateStatus(instance interface{}, path []string, errors *[]ValidationError) {
+	switch instance {
+	case "done", "in-progress":
+	default:
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/status/enum"})
+	}
+}
+
+func DecodeStatus(data []byte) (Status, error) {
+	var out Status
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateStatus(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type UserId struct {
+	Value string `json:"value"`
+}
+
+func ValidateUserId(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateUserId(instance, nil, &errors)
+	return errors
+}
+
+func validateUserId(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/userIdentifier/properties"})
+		return
+	}
+	if v0, ok := obj["value"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "value")), SchemaPath: "/definitions/userIdentifier/properties/value/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/userIdentifier/properties/value"})
+	}
+}
+
+func DecodeUserId(data []byte) (UserId, error) {
+	var out UserId
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateUserId(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type SignUp struct {
+}
+
+type MetadataEvent struct {
+	Type string `json:"type"`
+	Val interface{} `json:"-"`
+}
+
+func (s *MetadataEvent) UnmarshalJSON(buf []byte) error {
+	var x struct{ Tag string `json:"type"` }
+	if err := json.Unmarshal(buf, &x); err != nil {
+		return err
+	}
+	switch x.Tag {
+	case "signed-up":
+		var data SignUp
+		if err := json.Unmarshal(buf, &data); err != nil {
+			return err
+		}
+		s.Val = data
+	}
+	s.Type = x.Tag
+	return nil
+}
+
+func (s MetadataEvent) MarshalJSON() ([]byte, error) {
+	switch val := s.Val.(type) {
+	case SignUp:
+		var data struct{
+			Tag string `json:"type"`
+			SignUp
+		}
+		data.SignUp = val
+		data.Tag = s.Type
+		return json.Marshal(data)
+	}
+	panic("invalid discriminator tag")
+}
+
+func ValidateMetadataEvent(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateMetadataEvent(instance, nil, &errors)
+	return errors
+}
+
+func validateMetadataEvent(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/event/discriminator"})
+		return
+	}
+	tag, ok := obj["type"]
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/event/discriminator/tag"})
+		return
+	}
+	switch tag {
+	case "signed-up":
+	default:
+		if _, ok := tag.(string); ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "type")), SchemaPath: "/properties/event/discriminator/mapping"})
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "type")), SchemaPath: "/properties/event/discriminator/tag"})
+		}
+	}
+}
+
+func DecodeMetadataEvent(data []byte) (MetadataEvent, error) {
+	var out MetadataEvent
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateMetadataEvent(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type FavoriteNumber struct {
+	Value float64 `json:"value"`
+}
+
+func ValidateFavoriteNumber(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateFavoriteNumber(instance, nil, &errors)
+	return errors
+}
+
+func validateFavoriteNumber(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/favoriteNumbers/elements/properties"})
+		return
+	}
+	if v0, ok := obj["value"]; ok {
+		if _, ok := v0.(float64); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "value")), SchemaPath: "/properties/favoriteNumbers/elements/properties/value/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/favoriteNumbers/elements/properties/value"})
+	}
+}
+
+func DecodeFavoriteNumber(data []byte) (FavoriteNumber, error) {
+	var out FavoriteNumber
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateFavoriteNumber(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type Metadata struct {
+	Event MetadataEvent `json:"event"`
+	FavoriteNumbers []FavoriteNumber `json:"favoriteNumbers"`
+	Id UserId `json:"id"`
+	Status Status `json:"status"`
+	Login string `json:"userName"`
+}
+
+func ValidateMetadata(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateMetadata(instance, nil, &errors)
+	return errors
+}
+
+func validateMetadata(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties"})
+		return
+	}
+	if v0, ok := obj["event"]; ok {
+		validateMetadataEvent(v0, jslAppend(path, "event"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/event"})
+	}
+	if v0, ok := obj["favoriteNumbers"]; ok {
+		if a0, ok := v0.([]interface{}); ok {
+			for i0, v1 := range a0 {
+				validateFavoriteNumber(v1, jslAppend(path, "favoriteNumbers", strconv.Itoa(i0)), errors)
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "favoriteNumbers")), SchemaPath: "/properties/favoriteNumbers/elements"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/favoriteNumbers"})
+	}
+	if v0, ok := obj["id"]; ok {
+		validateUserId(v0, jslAppend(path, "id"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/id"})
+	}
+	if v0, ok := obj["status"]; ok {
+		validateStatus(v0, jslAppend(path, "status"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/status"})
+	}
+	if v0, ok := obj["userName"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "userName")), SchemaPath: "/properties/userName/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/userName"})
+	}
+}
+
+func DecodeMetadata(data []byte) (Metadata, error) {
+	var out Metadata
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateMetadata(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
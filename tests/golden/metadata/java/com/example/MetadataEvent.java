@@ -0,0 +1,43 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonSubTypes;
+import com.fasterxml.jackson.annotation.JsonTypeInfo;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type")
+@JsonSubTypes({
+    @JsonSubTypes.Type(value = SignUp.class, name = "signed-up"),
+})
+public abstract class MetadataEvent {
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/properties/event/discriminator"));
+            return;
+        }
+        if (!instance.has("type")) {
+            errors.add(new ValidationError(path, "/properties/event/discriminator/tag"));
+            return;
+        }
+        if (!instance.get("type").isTextual()) {
+            errors.add(new ValidationError(ValidationError.append(path, "type"), "/properties/event/discriminator/tag"));
+            return;
+        }
+
+        switch (instance.get("type").asText()) {
+        case "signed-up":
+            break;
+        default:
+            errors.add(new ValidationError(ValidationError.append(path, "type"), "/properties/event/discriminator/mapping"));
+        }
+    }
+}
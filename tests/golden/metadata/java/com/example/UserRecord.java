@@ -0,0 +1,72 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonProperty;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class UserRecord {
+    public MetadataEvent event;
+    public List<FavoriteNumber> favoriteNumbers;
+    public UserId id;
+    public Status status;
+    @JsonProperty("userName")
+    public String login;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/properties"));
+            return;
+        }
+        if (instance.has("event")) {
+            JsonNode v0 = instance.get("event");
+            MetadataEvent.validate(v0, ValidationError.append(path, "event"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/event"));
+        }
+        if (instance.has("favoriteNumbers")) {
+            JsonNode v0 = instance.get("favoriteNumbers");
+            if (v0.isArray()) {
+                for (int i0 = 0; i0 < v0.size(); i0++) {
+                    JsonNode v1 = v0.get(i0);
+                    FavoriteNumber.validate(v1, ValidationError.append(path, "favoriteNumbers", Integer.toString(i0)), errors);
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "favoriteNumbers"), "/properties/favoriteNumbers/elements"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/favoriteNumbers"));
+        }
+        if (instance.has("id")) {
+            JsonNode v0 = instance.get("id");
+            UserId.validate(v0, ValidationError.append(path, "id"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/id"));
+        }
+        if (instance.has("status")) {
+            JsonNode v0 = instance.get("status");
+            Status.validate(v0, ValidationError.append(path, "status"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/status"));
+        }
+        if (instance.has("userName")) {
+            JsonNode v0 = instance.get("userName");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "userName"), "/properties/userName/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/userName"));
+        }
+    }
+}
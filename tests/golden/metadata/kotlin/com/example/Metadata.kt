@@ -0,0 +1,49 @@
+@file:OptIn(ExperimentalSerializationApi::class)
+
+package com.example
+
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.json.JsonClassDiscriminator
+import kotlinx.serialization.json.JsonIgnoreUnknownKeys
+
+@Serializable
+enum class Status {
+    @SerialName("done")
+    DONE,
+    @SerialName("in-progress")
+    Active,
+}
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class UserId(
+    val value: String,
+)
+
+@Serializable
+@JsonClassDiscriminator("type")
+sealed class MetadataEvent
+
+@Serializable
+@SerialName("signed-up")
+@JsonIgnoreUnknownKeys
+class SignUp : MetadataEvent()
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class FavoriteNumber(
+    val value: Double,
+)
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class Metadata(
+    val event: MetadataEvent,
+    val favoriteNumbers: List<FavoriteNumber>,
+    val id: UserId,
+    val status: Status,
+    @SerialName("userName")
+    val login: String,
+)
@@ -0,0 +1,96 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from enum import Enum
+from typing import Any, Dict, List
+
+
+class Status(Enum):
+    DONE = "done"
+    Active = "in-progress"
+
+
+@dataclass
+class UserId:
+    value: str
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> UserId:
+        return cls(
+            value=data["value"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["value"] = self.value
+        return data
+
+
+@dataclass
+class MetadataEvent:
+    @staticmethod
+    def from_json(data: Dict[str, Any]) -> MetadataEvent:
+        variants = {
+            "signed-up": SignUp,
+        }
+
+        return variants[data["type"]].from_json(data)
+
+    def to_json(self) -> Dict[str, Any]:
+        raise NotImplementedError
+
+
+@dataclass
+class SignUp(MetadataEvent):
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> SignUp:
+        return cls(
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {"type": "signed-up"}
+        return data
+
+
+@dataclass
+class FavoriteNumber:
+    value: float
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> FavoriteNumber:
+        return cls(
+            value=data["value"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["value"] = self.value
+        return data
+
+
+@dataclass
+class Metadata:
+    event: MetadataEvent
+    favorite_numbers: List[FavoriteNumber]
+    id: UserId
+    status: Status
+    login: str
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Metadata:
+        return cls(
+            event=MetadataEvent.from_json(data["event"]),
+            favorite_numbers=[FavoriteNumber.from_json(v0) for v0 in data["favoriteNumbers"]],
+            id=UserId.from_json(data["id"]),
+            status=Status(data["status"]),
+            login=data["userName"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["event"] = self.event.to_json()
+        data["favoriteNumbers"] = [v0.to_json() for v0 in self.favorite_numbers]
+        data["id"] = self.id.to_json()
+        data["status"] = self.status.value
+        data["userName"] = self.login
+        return data
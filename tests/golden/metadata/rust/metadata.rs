@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Status {
+    #[serde(rename = "done")]
+    Done,
+    #[serde(rename = "in-progress")]
+    Active,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UserId {
+    pub value: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SignUp {
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum MetadataEvent {
+    #[serde(rename = "signed-up")]
+    SignedUp(SignUp),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FavoriteNumber {
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Metadata {
+    pub event: MetadataEvent,
+    #[serde(rename = "favoriteNumbers")]
+    pub favorite_numbers: Vec<FavoriteNumber>,
+    pub id: UserId,
+    pub status: Status,
+    #[serde(rename = "userName")]
+    pub login: String,
+}
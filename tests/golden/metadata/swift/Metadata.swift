@@ -0,0 +1,61 @@
+import Foundation
+
+enum Status: String, Codable, Equatable {
+    case done
+    case Active = "in-progress"
+}
+
+struct UserId: Codable, Equatable {
+    var value: String
+}
+
+struct SignUp: Codable, Equatable {
+}
+
+enum MetadataEvent: Codable, Equatable {
+    case signedUp(SignUp)
+
+    enum CodingKeys: String, CodingKey {
+        case tag = "type"
+    }
+
+    init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .tag)
+        switch tag {
+        case "signed-up":
+            self = .signedUp(try SignUp(from: decoder))
+        default:
+            throw DecodingError.dataCorruptedError(forKey: .tag, in: container, debugDescription: "Unknown tag: \(tag)")
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case .signedUp(let value):
+            try container.encode("signed-up", forKey: .tag)
+            try value.encode(to: encoder)
+        }
+    }
+}
+
+struct FavoriteNumber: Codable, Equatable {
+    var value: Double
+}
+
+struct Metadata: Codable, Equatable {
+    var event: MetadataEvent
+    var favoriteNumbers: [FavoriteNumber]
+    var id: UserId
+    var status: Status
+    var login: String
+
+    enum CodingKeys: String, CodingKey {
+        case event
+        case favoriteNumbers
+        case id
+        case status
+        case login = "userName"
+    }
+}
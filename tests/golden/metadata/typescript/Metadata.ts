@@ -0,0 +1,151 @@
+export interface ValidationError {
+  instancePath: string;
+  schemaPath: string;
+}
+function jslPointer(path: string[]): string {
+  return path.map((token) => "/" + token.replace(/~/g, "~0").replace(/\//g, "~1")).join("");
+}
+function jslIsObject(instance: unknown): instance is { [key: string]: unknown } {
+  return typeof instance === "object" && instance !== null && !Array.isArray(instance);
+}
+function jslHas(instance: { [key: string]: unknown }, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(instance, key);
+}
+export type Status = "done" | "in-progress";
+export function validateStatus(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateStatusAt(instance, [], errors);
+  return errors;
+}
+export function validateStatusAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (instance !== "done" && instance !== "in-progress") {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/status/enum" });
+  }
+}
+export interface UserId {
+  value: string;
+  [key: string]: unknown;
+}
+export function validateUserId(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateUserIdAt(instance, [], errors);
+  return errors;
+}
+export function validateUserIdAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/userIdentifier/properties" });
+    return;
+  }
+  if (jslHas(instance, "value")) {
+    if (typeof instance["value"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "value"]), schemaPath: "/definitions/userIdentifier/properties/value/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/userIdentifier/properties/value" });
+  }
+}
+export interface SignUp {
+  type: "signed-up";
+  [key: string]: unknown;
+}
+export type MetadataEvent = SignUp;
+export function validateMetadataEvent(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateMetadataEventAt(instance, [], errors);
+  return errors;
+}
+export function validateMetadataEventAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/event/discriminator" });
+    return;
+  }
+  if (!jslHas(instance, "type")) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/event/discriminator/tag" });
+    return;
+  }
+  switch (instance["type"]) {
+    case "signed-up":
+      break;
+    default:
+      if (typeof instance["type"] === "string") {
+        errors.push({ instancePath: jslPointer([...path, "type"]), schemaPath: "/properties/event/discriminator/mapping" });
+      } else {
+        errors.push({ instancePath: jslPointer([...path, "type"]), schemaPath: "/properties/event/discriminator/tag" });
+      }
+  }
+}
+export interface FavoriteNumber {
+  value: number;
+  [key: string]: unknown;
+}
+export function validateFavoriteNumber(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateFavoriteNumberAt(instance, [], errors);
+  return errors;
+}
+export function validateFavoriteNumberAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/favoriteNumbers/elements/properties" });
+    return;
+  }
+  if (jslHas(instance, "value")) {
+    if (typeof instance["value"] !== "number") {
+      errors.push({ instancePath: jslPointer([...path, "value"]), schemaPath: "/properties/favoriteNumbers/elements/properties/value/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/favoriteNumbers/elements/properties/value" });
+  }
+}
+export interface Metadata {
+  event: MetadataEvent;
+  favoriteNumbers: FavoriteNumber[];
+  id: UserId;
+  status: Status;
+  userName: string;
+  [key: string]: unknown;
+}
+export function validateMetadata(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateMetadataAt(instance, [], errors);
+  return errors;
+}
+export function validateMetadataAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties" });
+    return;
+  }
+  if (jslHas(instance, "event")) {
+    validateMetadataEventAt(instance["event"], [...path, "event"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/event" });
+  }
+  if (jslHas(instance, "favoriteNumbers")) {
+    const a0: unknown = instance["favoriteNumbers"];
+    if (Array.isArray(a0)) {
+      a0.forEach((v0: unknown, i0: number) => {
+        validateFavoriteNumberAt(v0, [...path, "favoriteNumbers", String(i0)], errors);
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "favoriteNumbers"]), schemaPath: "/properties/favoriteNumbers/elements" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/favoriteNumbers" });
+  }
+  if (jslHas(instance, "id")) {
+    validateUserIdAt(instance["id"], [...path, "id"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/id" });
+  }
+  if (jslHas(instance, "status")) {
+    validateStatusAt(instance["status"], [...path, "status"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/status" });
+  }
+  if (jslHas(instance, "userName")) {
+    if (typeof instance["userName"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "userName"]), schemaPath: "/properties/userName/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/userName" });
+  }
+}
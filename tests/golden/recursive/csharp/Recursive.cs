@@ -0,0 +1,80 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Example;
+
+public sealed record Company
+{
+    [JsonPropertyName("ceo")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Person? Ceo { get; init; }
+
+    [JsonPropertyName("parent")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Company? Parent { get; init; }
+}
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "op")]
+[JsonDerivedType(typeof(ExprAdd), "add")]
+[JsonDerivedType(typeof(ExprLiteral), "literal")]
+public abstract record Expr;
+
+public sealed record ExprAdd : Expr
+{
+    [JsonPropertyName("left")]
+    public required Expr Left { get; init; }
+
+    [JsonPropertyName("right")]
+    public required Expr Right { get; init; }
+}
+
+public sealed record ExprLiteral : Expr
+{
+    [JsonPropertyName("value")]
+    public required double Value { get; init; }
+}
+
+public sealed record Node
+{
+    [JsonPropertyName("value")]
+    public required string Value { get; init; }
+
+    [JsonPropertyName("next")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Node? Next { get; init; }
+}
+
+public sealed record Person
+{
+    [JsonPropertyName("employer")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Company? Employer { get; init; }
+}
+
+public sealed record Tree
+{
+    [JsonPropertyName("children")]
+    public required List<Tree> Children { get; init; }
+
+    [JsonPropertyName("labels")]
+    public required Dictionary<string, Tree> Labels { get; init; }
+}
+
+public sealed record Recursive
+{
+    [JsonPropertyName("expr")]
+    public required Expr Expr { get; init; }
+
+    [JsonPropertyName("list")]
+    public required Node List { get; init; }
+
+    [JsonPropertyName("person")]
+    public required Person Person { get; init; }
+
+    [JsonPropertyName("tree")]
+    public required Tree Tree { get; init; }
+}
@@ -0,0 +1,187 @@
+class Company {
+  final Person? ceo;
+  final ParentCompany? parent;
+
+  const Company({
+    this.ceo,
+    this.parent,
+  });
+
+  factory Company.fromJson(Map<String, dynamic> json) {
+    return Company(
+      ceo: json["ceo"] == null ? null : Person.fromJson(json["ceo"] as Map<String, dynamic>),
+      parent: json["parent"] == null ? null : Company.fromJson(json["parent"] as Map<String, dynamic>),
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      if (ceo != null) "ceo": ceo!.toJson(),
+      if (parent != null) "parent": parent!.toJson(),
+    };
+  }
+}
+
+sealed class Expr {
+  const Expr();
+
+  factory Expr.fromJson(Map<String, dynamic> json) {
+    return switch (json["op"]) {
+      "add" => ExprAdd.fromJson(json),
+      "literal" => ExprLiteral.fromJson(json),
+      _ => throw FormatException("unknown Expr tag", json["op"]),
+    };
+  }
+
+  Map<String, dynamic> toJson();
+}
+
+class ExprAdd extends Expr {
+  final Expr left;
+  final Expr right;
+
+  const ExprAdd({
+    required this.left,
+    required this.right,
+  });
+
+  factory ExprAdd.fromJson(Map<String, dynamic> json) {
+    return ExprAdd(
+      left: Expr.fromJson(json["left"] as Map<String, dynamic>),
+      right: Expr.fromJson(json["right"] as Map<String, dynamic>),
+    );
+  }
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      "op": "add",
+      "left": left.toJson(),
+      "right": right.toJson(),
+    };
+  }
+}
+
+class ExprLiteral extends Expr {
+  final double value;
+
+  const ExprLiteral({
+    required this.value,
+  });
+
+  factory ExprLiteral.fromJson(Map<String, dynamic> json) {
+    return ExprLiteral(
+      value: (json["value"] as num).toDouble(),
+    );
+  }
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      "op": "literal",
+      "value": value,
+    };
+  }
+}
+
+class Node {
+  final String value;
+  final Node? next;
+
+  const Node({
+    required this.value,
+    this.next,
+  });
+
+  factory Node.fromJson(Map<String, dynamic> json) {
+    return Node(
+      value: json["value"] as String,
+      next: json["next"] == null ? null : Node.fromJson(json["next"] as Map<String, dynamic>),
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "value": value,
+      if (next != null) "next": next!.toJson(),
+    };
+  }
+}
+
+typedef ParentCompany = Company;
+
+class Person {
+  final Company? employer;
+
+  const Person({
+    this.employer,
+  });
+
+  factory Person.fromJson(Map<String, dynamic> json) {
+    return Person(
+      employer: json["employer"] == null ? null : Company.fromJson(json["employer"] as Map<String, dynamic>),
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      if (employer != null) "employer": employer!.toJson(),
+    };
+  }
+}
+
+class Tree {
+  final List<Tree> children;
+  final Map<String, Tree> labels;
+
+  const Tree({
+    required this.children,
+    required this.labels,
+  });
+
+  factory Tree.fromJson(Map<String, dynamic> json) {
+    return Tree(
+      children: (json["children"] as List<dynamic>).map((v0) => Tree.fromJson(v0 as Map<String, dynamic>)).toList(),
+      labels: (json["labels"] as Map<String, dynamic>).map((k0, v0) => MapEntry(k0, Tree.fromJson(v0 as Map<String, dynamic>))),
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "children": children.map((v0) => v0.toJson()).toList(),
+      "labels": labels.map((k0, v0) => MapEntry(k0, v0.toJson())),
+    };
+  }
+}
+
+class Recursive {
+  final Expr expr;
+  final Node list;
+  final Person person;
+  final Tree tree;
+
+  const Recursive({
+    required this.expr,
+    required this.list,
+    required this.person,
+    required this.tree,
+  });
+
+  factory Recursive.fromJson(Map<String, dynamic> json) {
+    return Recursive(
+      expr: Expr.fromJson(json["expr"] as Map<String, dynamic>),
+      list: Node.fromJson(json["list"] as Map<String, dynamic>),
+      person: Person.fromJson(json["person"] as Map<String, dynamic>),
+      tree: Tree.fromJson(json["tree"] as Map<String, dynamic>),
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "expr": expr.toJson(),
+      "list": list.toJson(),
+      "person": person.toJson(),
+      "tree": tree.toJson(),
+    };
+  }
+}
@@ -0,0 +1,371 @@
+package models
+
+import (
+	"encoding/json"
+	"strconv"
+)
+
+type Company struct {
+	Ceo *Person `json:"ceo,omitempty"`
+	Parent *ParentCompany `json:"parent,omitempty"`
+}
+
+func ValidateCompany(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateCompany(instance, nil, &errors)
+	return errors
+}
+
+func validateCompany(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/company/optionalProperties"})
+		return
+	}
+	if v0, ok := obj["ceo"]; ok {
+		validatePerson(v0, jslAppend(path, "ceo"), errors)
+	}
+	if v0, ok := obj["parent"]; ok {
+		validateParentCompany(v0, jslAppend(path, "parent"), errors)
+	}
+}
+
+func DecodeCompany(data []byte) (Company, error) {
+	var out Company
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateCompany(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type ExprAdd struct {
+	Left Expr `json:"left"`
+	Right Expr `json:"right"`
+}
+
+type ExprLiteral struct {
+	Value float64 `json:"value"`
+}
+
+type Expr struct {
+	Op string `json:"op"`
+	Val interface{} `json:"-"`
+}
+
+func (s *Expr) UnmarshalJSON(buf []byte) error {
+	var x struct{ Tag string `json:"op"` }
+	if err := json.Unmarshal(buf, &x); err != nil {
+		return err
+	}
+	switch x.Tag {
+	case "add":
+		var data ExprAdd
+		if err := json.Unmarshal(buf, &data); err != nil {
+			return err
+		}
+		s.Val = data
+	case "literal":
+		var data ExprLiteral
+		if err := json.Unmarshal(buf, &data); err != nil {
+			return err
+		}
+		s.Val = data
+	}
+	s.Op = x.Tag
+	return nil
+}
+
+func (s Expr) MarshalJSON() ([]byte, error) {
+	switch val := s.Val.(type) {
+	case ExprAdd:
+		var data struct{
+			Tag string `json:"op"`
+			ExprAdd
+		}
+		data.ExprAdd = val
+		data.Tag = s.Op
+		return json.Marshal(data)
+	case ExprLiteral:
+		var data struct{
+			Tag string `json:"op"`
+			ExprLiteral
+		}
+		data.ExprLiteral = val
+		data.Tag = s.Op
+		return json.Marshal(data)
+	}
+	panic("invalid discriminator tag")
+}
+
+func ValidateExpr(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateExpr(instance, nil, &errors)
+	return errors
+}
+
+func validateExpr(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/expr/discriminator"})
+		return
+	}
+	tag, ok := obj["op"]
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/expr/discriminator/tag"})
+		return
+	}
+	switch tag {
+	case "add":
+		if v0, ok := obj["left"]; ok {
+			validateExpr(v0, jslAppend(path, "left"), errors)
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/expr/discriminator/mapping/add/properties/left"})
+		}
+		if v0, ok := obj["right"]; ok {
+			validateExpr(v0, jslAppend(path, "right"), errors)
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/expr/discriminator/mapping/add/properties/right"})
+		}
+	case "literal":
+		if v0, ok := obj["value"]; ok {
+			if _, ok := v0.(float64); !ok {
+				*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "value")), SchemaPath: "/definitions/expr/discriminator/mapping/literal/properties/value/type"})
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/expr/discriminator/mapping/literal/properties/value"})
+		}
+	default:
+		if _, ok := tag.(string); ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "op")), SchemaPath: "/definitions/expr/discriminator/mapping"})
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "op")), SchemaPath: "/definitions/expr/discriminator/tag"})
+		}
+	}
+}
+
+func DecodeExpr(data []byte) (Expr, error) {
+	var out Expr
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateExpr(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type Node struct {
+	Value string `json:"value"`
+	Next *Node `json:"next,omitempty"`
+}
+
+func ValidateNode(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateNode(instance, nil, &errors)
+	return errors
+}
+
+func validateNode(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/node/properties"})
+		return
+	}
+	if v0, ok := obj["value"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "value")), SchemaPath: "/definitions/node/properties/value/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/node/properties/value"})
+	}
+	if v0, ok := obj["next"]; ok {
+		validateNode(v0, jslAppend(path, "next"), errors)
+	}
+}
+
+func DecodeNode(data []byte) (Node, error) {
+	var out Node
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateNode(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type ParentCompany Company
+
+func ValidateParentCompany(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateParentCompany(instance, nil, &errors)
+	return errors
+}
+
+func validateParentCompany(instance interface{}, path []string, errors *[]ValidationError) {
+	validateCompany(instance, path, errors)
+}
+
+func DecodeParentCompany(data []byte) (ParentCompany, error) {
+	var out ParentCompany
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateParentCompany(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type Person struct {
+	Employer *Company `json:"employer,omitempty"`
+}
+
+func ValidatePerson(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validatePerson(instance, nil, &errors)
+	return errors
+}
+
+func validatePerson(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/person/optionalProperties"})
+		return
+	}
+	if v0, ok := obj["employer"]; ok {
+		validateCompany(v0, jslAppend(path, "employer"), errors)
+	}
+}
+
+func DecodePerson(data []byte) (Person, error) {
+	var out Person
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidatePerson(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type Tree struct {
+	Children []Tree `json:"children"`
+	Labels map[string]Tree `json:"labels"`
+}
+
+func ValidateTree(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateTree(instance, nil, &errors)
+	return errors
+}
+
+func validateTree(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/tree/properties"})
+		return
+	}
+	if v0, ok := obj["children"]; ok {
+		if a0, ok := v0.([]interface{}); ok {
+			for i0, v1 := range a0 {
+				validateTree(v1, jslAppend(path, "children", strconv.Itoa(i0)), errors)
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "children")), SchemaPath: "/definitions/tree/properties/children/elements"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/tree/properties/children"})
+	}
+	if v0, ok := obj["labels"]; ok {
+		if o0, ok := v0.(map[string]interface{}); ok {
+			for k0, v1 := range o0 {
+				validateTree(v1, jslAppend(path, "labels", k0), errors)
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "labels")), SchemaPath: "/definitions/tree/properties/labels/values"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/tree/properties/labels"})
+	}
+}
+
+func DecodeTree(data []byte) (Tree, error) {
+	var out Tree
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateTree(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type Recursive struct {
+	Expr Expr `json:"expr"`
+	List Node `json:"list"`
+	Person Person `json:"person"`
+	Tree Tree `json:"tree"`
+}
+
+func ValidateRecursive(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateRecursive(instance, nil, &errors)
+	return errors
+}
+
+func validateRecursive(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties"})
+		return
+	}
+	if v0, ok := obj["expr"]; ok {
+		validateExpr(v0, jslAppend(path, "expr"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/expr"})
+	}
+	if v0, ok := obj["list"]; ok {
+		validateNode(v0, jslAppend(path, "list"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/list"})
+	}
+	if v0, ok := obj["person"]; ok {
+		validatePerson(v0, jslAppend(path, "person"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/person"})
+	}
+	if v0, ok := obj["tree"]; ok {
+		validateTree(v0, jslAppend(path, "tree"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/tree"})
+	}
+}
+
+func DecodeRecursive(data []byte) (Recursive, error) {
+	var out Recursive
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateRecursive(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
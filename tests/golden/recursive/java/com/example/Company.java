@@ -0,0 +1,39 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class Company {
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public Person ceo;
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public Company parent;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/definitions/company/optionalProperties"));
+            return;
+        }
+        if (instance.has("ceo")) {
+            JsonNode v0 = instance.get("ceo");
+            Person.validate(v0, ValidationError.append(path, "ceo"), errors);
+        }
+        if (instance.has("parent")) {
+            JsonNode v0 = instance.get("parent");
+            Company.validate(v0, ValidationError.append(path, "parent"), errors);
+        }
+    }
+}
@@ -0,0 +1,66 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonSubTypes;
+import com.fasterxml.jackson.annotation.JsonTypeInfo;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "op")
+@JsonSubTypes({
+    @JsonSubTypes.Type(value = ExprAdd.class, name = "add"),
+    @JsonSubTypes.Type(value = ExprLiteral.class, name = "literal"),
+})
+public abstract class Expr {
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/definitions/expr/discriminator"));
+            return;
+        }
+        if (!instance.has("op")) {
+            errors.add(new ValidationError(path, "/definitions/expr/discriminator/tag"));
+            return;
+        }
+        if (!instance.get("op").isTextual()) {
+            errors.add(new ValidationError(ValidationError.append(path, "op"), "/definitions/expr/discriminator/tag"));
+            return;
+        }
+
+        switch (instance.get("op").asText()) {
+        case "add":
+            if (instance.has("left")) {
+                JsonNode v0 = instance.get("left");
+                Expr.validate(v0, ValidationError.append(path, "left"), errors);
+            } else {
+                errors.add(new ValidationError(path, "/definitions/expr/discriminator/mapping/add/properties/left"));
+            }
+            if (instance.has("right")) {
+                JsonNode v0 = instance.get("right");
+                Expr.validate(v0, ValidationError.append(path, "right"), errors);
+            } else {
+                errors.add(new ValidationError(path, "/definitions/expr/discriminator/mapping/add/properties/right"));
+            }
+            break;
+        case "literal":
+            if (instance.has("value")) {
+                JsonNode v0 = instance.get("value");
+                if (!v0.isNumber()) {
+                    errors.add(new ValidationError(ValidationError.append(path, "value"), "/definitions/expr/discriminator/mapping/literal/properties/value/type"));
+                }
+            } else {
+                errors.add(new ValidationError(path, "/definitions/expr/discriminator/mapping/literal/properties/value"));
+            }
+            break;
+        default:
+            errors.add(new ValidationError(ValidationError.append(path, "op"), "/definitions/expr/discriminator/mapping"));
+        }
+    }
+}
@@ -0,0 +1,42 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class Node {
+    public String value;
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public Node next;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/definitions/node/properties"));
+            return;
+        }
+        if (instance.has("value")) {
+            JsonNode v0 = instance.get("value");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "value"), "/definitions/node/properties/value/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/definitions/node/properties/value"));
+        }
+        if (instance.has("next")) {
+            JsonNode v0 = instance.get("next");
+            Node.validate(v0, ValidationError.append(path, "next"), errors);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class Person {
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public Company employer;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/definitions/person/optionalProperties"));
+            return;
+        }
+        if (instance.has("employer")) {
+            JsonNode v0 = instance.get("employer");
+            Company.validate(v0, ValidationError.append(path, "employer"), errors);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class Recursive {
+    public Expr expr;
+    public Node list;
+    public Person person;
+    public Tree tree;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/properties"));
+            return;
+        }
+        if (instance.has("expr")) {
+            JsonNode v0 = instance.get("expr");
+            Expr.validate(v0, ValidationError.append(path, "expr"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/expr"));
+        }
+        if (instance.has("list")) {
+            JsonNode v0 = instance.get("list");
+            Node.validate(v0, ValidationError.append(path, "list"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/list"));
+        }
+        if (instance.has("person")) {
+            JsonNode v0 = instance.get("person");
+            Person.validate(v0, ValidationError.append(path, "person"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/person"));
+        }
+        if (instance.has("tree")) {
+            JsonNode v0 = instance.get("tree");
+            Tree.validate(v0, ValidationError.append(path, "tree"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/tree"));
+        }
+    }
+}
@@ -0,0 +1,55 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class Tree {
+    public List<Tree> children;
+    public Map<String, Tree> labels;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/definitions/tree/properties"));
+            return;
+        }
+        if (instance.has("children")) {
+            JsonNode v0 = instance.get("children");
+            if (v0.isArray()) {
+                for (int i0 = 0; i0 < v0.size(); i0++) {
+                    JsonNode v1 = v0.get(i0);
+                    Tree.validate(v1, ValidationError.append(path, "children", Integer.toString(i0)), errors);
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "children"), "/definitions/tree/properties/children/elements"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/definitions/tree/properties/children"));
+        }
+        if (instance.has("labels")) {
+            JsonNode v0 = instance.get("labels");
+            if (v0.isObject()) {
+                for (Iterator<Map.Entry<String, JsonNode>> it0 = v0.fields(); it0.hasNext();) {
+                    Map.Entry<String, JsonNode> e0 = it0.next();
+                    JsonNode v1 = e0.getValue();
+                    Tree.validate(v1, ValidationError.append(path, "labels", e0.getKey()), errors);
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "labels"), "/definitions/tree/properties/labels/values"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/definitions/tree/properties/labels"));
+        }
+    }
+}
@@ -0,0 +1,66 @@
+@file:OptIn(ExperimentalSerializationApi::class)
+
+package com.example
+
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.json.JsonClassDiscriminator
+import kotlinx.serialization.json.JsonIgnoreUnknownKeys
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class Company(
+    val ceo: Person? = null,
+    val parent: ParentCompany? = null,
+)
+
+@Serializable
+@JsonClassDiscriminator("op")
+sealed class Expr
+
+@Serializable
+@SerialName("add")
+@JsonIgnoreUnknownKeys
+data class ExprAdd(
+    val left: Expr,
+    val right: Expr,
+) : Expr()
+
+@Serializable
+@SerialName("literal")
+@JsonIgnoreUnknownKeys
+data class ExprLiteral(
+    val value: Double,
+) : Expr()
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class Node(
+    val value: String,
+    val next: Node? = null,
+)
+
+typealias ParentCompany = Company
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class Person(
+    val employer: Company? = null,
+)
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class Tree(
+    val children: List<Tree>,
+    val labels: Map<String, Tree>,
+)
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class Recursive(
+    val expr: Expr,
+    val list: Node,
+    val person: Person,
+    val tree: Tree,
+)
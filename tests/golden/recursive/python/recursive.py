@@ -0,0 +1,159 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from typing import Any, Dict, List, Optional
+
+
+@dataclass
+class Company:
+    ceo: Optional[Person] = None
+    parent: Optional[ParentCompany] = None
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Company:
+        return cls(
+            ceo=Person.from_json(data["ceo"]) if "ceo" in data else None,
+            parent=Company.from_json(data["parent"]) if "parent" in data else None,
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        if self.ceo is not None:
+            data["ceo"] = self.ceo.to_json()
+        if self.parent is not None:
+            data["parent"] = self.parent.to_json()
+        return data
+
+
+@dataclass
+class Expr:
+    @staticmethod
+    def from_json(data: Dict[str, Any]) -> Expr:
+        variants = {
+            "add": ExprAdd,
+            "literal": ExprLiteral,
+        }
+
+        return variants[data["op"]].from_json(data)
+
+    def to_json(self) -> Dict[str, Any]:
+        raise NotImplementedError
+
+
+@dataclass
+class ExprAdd(Expr):
+    left: Expr
+    right: Expr
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> ExprAdd:
+        return cls(
+            left=Expr.from_json(data["left"]),
+            right=Expr.from_json(data["right"]),
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {"op": "add"}
+        data["left"] = self.left.to_json()
+        data["right"] = self.right.to_json()
+        return data
+
+
+@dataclass
+class ExprLiteral(Expr):
+    value: float
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> ExprLiteral:
+        return cls(
+            value=data["value"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {"op": "literal"}
+        data["value"] = self.value
+        return data
+
+
+@dataclass
+class Node:
+    value: str
+    next: Optional[Node] = None
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Node:
+        return cls(
+            value=data["value"],
+            next=Node.from_json(data["next"]) if "next" in data else None,
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["value"] = self.value
+        if self.next is not None:
+            data["next"] = self.next.to_json()
+        return data
+
+
+ParentCompany = "Company"
+
+
+@dataclass
+class Person:
+    employer: Optional[Company] = None
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Person:
+        return cls(
+            employer=Company.from_json(data["employer"]) if "employer" in data else None,
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        if self.employer is not None:
+            data["employer"] = self.employer.to_json()
+        return data
+
+
+@dataclass
+class Tree:
+    children: List[Tree]
+    labels: Dict[str, Tree]
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Tree:
+        return cls(
+            children=[Tree.from_json(v0) for v0 in data["children"]],
+            labels={k0: Tree.from_json(v0) for k0, v0 in data["labels"].items()},
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["children"] = [v0.to_json() for v0 in self.children]
+        data["labels"] = {k0: v0.to_json() for k0, v0 in self.labels.items()}
+        return data
+
+
+@dataclass
+class Recursive:
+    expr: Expr
+    list: Node
+    person: Person
+    tree: Tree
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Recursive:
+        return cls(
+            expr=Expr.from_json(data["expr"]),
+            list=Node.from_json(data["list"]),
+            person=Person.from_json(data["person"]),
+            tree=Tree.from_json(data["tree"]),
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["expr"] = self.expr.to_json()
+        data["list"] = self.list.to_json()
+        data["person"] = self.person.to_json()
+        data["tree"] = self.tree.to_json()
+        return data
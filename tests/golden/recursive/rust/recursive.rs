@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Company {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ceo: Option<Box<Person>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parent: Option<Box<ParentCompany>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExprAdd {
+    pub left: Box<Expr>,
+    pub right: Box<Expr>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExprLiteral {
+    pub value: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op")]
+pub enum Expr {
+    #[serde(rename = "add")]
+    Add(ExprAdd),
+    #[serde(rename = "literal")]
+    Literal(ExprLiteral),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Node {
+    pub value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next: Option<Box<Node>>,
+}
+
+pub type ParentCompany = Company;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Person {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub employer: Option<Box<Company>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tree {
+    pub children: Vec<Tree>,
+    pub labels: std::collections::HashMap<String, Tree>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Recursive {
+    pub expr: Expr,
+    pub list: Node,
+    pub person: Person,
+    pub tree: Tree,
+}
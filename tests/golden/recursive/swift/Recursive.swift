@@ -0,0 +1,72 @@
+import Foundation
+
+struct Company: Codable, Equatable {
+    var ceo: Indirect<Person>?
+    var parent: Indirect<ParentCompany>?
+}
+
+struct ExprAdd: Codable, Equatable {
+    var left: Indirect<Expr>
+    var right: Indirect<Expr>
+}
+
+struct ExprLiteral: Codable, Equatable {
+    var value: Double
+}
+
+enum Expr: Codable, Equatable {
+    case add(ExprAdd)
+    case literal(ExprLiteral)
+
+    enum CodingKeys: String, CodingKey {
+        case tag = "op"
+    }
+
+    init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .tag)
+        switch tag {
+        case "add":
+            self = .add(try ExprAdd(from: decoder))
+        case "literal":
+            self = .literal(try ExprLiteral(from: decoder))
+        default:
+            throw DecodingError.dataCorruptedError(forKey: .tag, in: container, debugDescription: "Unknown tag: \(tag)")
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case .add(let value):
+            try container.encode("add", forKey: .tag)
+            try value.encode(to: encoder)
+        case .literal(let value):
+            try container.encode("literal", forKey: .tag)
+            try value.encode(to: encoder)
+        }
+    }
+}
+
+struct Node: Codable, Equatable {
+    var value: String
+    var next: Indirect<Node>?
+}
+
+typealias ParentCompany = Company
+
+struct Person: Codable, Equatable {
+    var employer: Indirect<Company>?
+}
+
+struct Tree: Codable, Equatable {
+    var children: [Tree]
+    var labels: [String: Tree]
+}
+
+struct Recursive: Codable, Equatable {
+    var expr: Expr
+    var list: Node
+    var person: Person
+    var tree: Tree
+}
@@ -0,0 +1,222 @@
+export interface ValidationError {
+  instancePath: string;
+  schemaPath: string;
+}
+function jslPointer(path: string[]): string {
+  return path.map((token) => "/" + token.replace(/~/g, "~0").replace(/\//g, "~1")).join("");
+}
+function jslIsObject(instance: unknown): instance is { [key: string]: unknown } {
+  return typeof instance === "object" && instance !== null && !Array.isArray(instance);
+}
+function jslHas(instance: { [key: string]: unknown }, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(instance, key);
+}
+export interface Company {
+  ceo?: Person;
+  parent?: ParentCompany;
+  [key: string]: unknown;
+}
+export function validateCompany(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateCompanyAt(instance, [], errors);
+  return errors;
+}
+export function validateCompanyAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/company/optionalProperties" });
+    return;
+  }
+  if (jslHas(instance, "ceo")) {
+    validatePersonAt(instance["ceo"], [...path, "ceo"], errors);
+  }
+  if (jslHas(instance, "parent")) {
+    validateParentCompanyAt(instance["parent"], [...path, "parent"], errors);
+  }
+}
+export interface ExprAdd {
+  op: "add";
+  left: Expr;
+  right: Expr;
+  [key: string]: unknown;
+}
+export interface ExprLiteral {
+  op: "literal";
+  value: number;
+  [key: string]: unknown;
+}
+export type Expr = ExprAdd | ExprLiteral;
+export function validateExpr(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateExprAt(instance, [], errors);
+  return errors;
+}
+export function validateExprAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/expr/discriminator" });
+    return;
+  }
+  if (!jslHas(instance, "op")) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/expr/discriminator/tag" });
+    return;
+  }
+  switch (instance["op"]) {
+    case "add":
+      if (jslHas(instance, "left")) {
+        validateExprAt(instance["left"], [...path, "left"], errors);
+      } else {
+        errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/expr/discriminator/mapping/add/properties/left" });
+      }
+      if (jslHas(instance, "right")) {
+        validateExprAt(instance["right"], [...path, "right"], errors);
+      } else {
+        errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/expr/discriminator/mapping/add/properties/right" });
+      }
+      break;
+    case "literal":
+      if (jslHas(instance, "value")) {
+        if (typeof instance["value"] !== "number") {
+          errors.push({ instancePath: jslPointer([...path, "value"]), schemaPath: "/definitions/expr/discriminator/mapping/literal/properties/value/type" });
+        }
+      } else {
+        errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/expr/discriminator/mapping/literal/properties/value" });
+      }
+      break;
+    default:
+      if (typeof instance["op"] === "string") {
+        errors.push({ instancePath: jslPointer([...path, "op"]), schemaPath: "/definitions/expr/discriminator/mapping" });
+      } else {
+        errors.push({ instancePath: jslPointer([...path, "op"]), schemaPath: "/definitions/expr/discriminator/tag" });
+      }
+  }
+}
+export interface Node {
+  value: string;
+  next?: Node;
+  [key: string]: unknown;
+}
+export function validateNode(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateNodeAt(instance, [], errors);
+  return errors;
+}
+export function validateNodeAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/node/properties" });
+    return;
+  }
+  if (jslHas(instance, "value")) {
+    if (typeof instance["value"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "value"]), schemaPath: "/definitions/node/properties/value/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/node/properties/value" });
+  }
+  if (jslHas(instance, "next")) {
+    validateNodeAt(instance["next"], [...path, "next"], errors);
+  }
+}
+export type ParentCompany = Company;
+export function validateParentCompany(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateParentCompanyAt(instance, [], errors);
+  return errors;
+}
+export function validateParentCompanyAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  validateCompanyAt(instance, path, errors);
+}
+export interface Person {
+  employer?: Company;
+  [key: string]: unknown;
+}
+export function validatePerson(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validatePersonAt(instance, [], errors);
+  return errors;
+}
+export function validatePersonAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/person/optionalProperties" });
+    return;
+  }
+  if (jslHas(instance, "employer")) {
+    validateCompanyAt(instance["employer"], [...path, "employer"], errors);
+  }
+}
+export interface Tree {
+  children: Tree[];
+  labels: { [name: string]: Tree };
+  [key: string]: unknown;
+}
+export function validateTree(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateTreeAt(instance, [], errors);
+  return errors;
+}
+export function validateTreeAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/tree/properties" });
+    return;
+  }
+  if (jslHas(instance, "children")) {
+    const a0: unknown = instance["children"];
+    if (Array.isArray(a0)) {
+      a0.forEach((v0: unknown, i0: number) => {
+        validateTreeAt(v0, [...path, "children", String(i0)], errors);
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "children"]), schemaPath: "/definitions/tree/properties/children/elements" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/tree/properties/children" });
+  }
+  if (jslHas(instance, "labels")) {
+    const o0: unknown = instance["labels"];
+    if (jslIsObject(o0)) {
+      Object.keys(o0).forEach((k0: string) => {
+        validateTreeAt(o0[k0], [...path, "labels", k0], errors);
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "labels"]), schemaPath: "/definitions/tree/properties/labels/values" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/tree/properties/labels" });
+  }
+}
+export interface Recursive {
+  expr: Expr;
+  list: Node;
+  person: Person;
+  tree: Tree;
+  [key: string]: unknown;
+}
+export function validateRecursive(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateRecursiveAt(instance, [], errors);
+  return errors;
+}
+export function validateRecursiveAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties" });
+    return;
+  }
+  if (jslHas(instance, "expr")) {
+    validateExprAt(instance["expr"], [...path, "expr"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/expr" });
+  }
+  if (jslHas(instance, "list")) {
+    validateNodeAt(instance["list"], [...path, "list"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/list" });
+  }
+  if (jslHas(instance, "person")) {
+    validatePersonAt(instance["person"], [...path, "person"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/person" });
+  }
+  if (jslHas(instance, "tree")) {
+    validateTreeAt(instance["tree"], [...path, "tree"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/tree" });
+  }
+}
@@ -0,0 +1,71 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Example;
+
+[JsonConverter(typeof(JsonStringEnumConverter<Color>))]
+public enum Color
+{
+    [JsonStringEnumMemberName("blue")]
+    Blue,
+    [JsonStringEnumMemberName("green")]
+    Green,
+    [JsonStringEnumMemberName("red")]
+    Red,
+}
+
+public sealed record Point
+{
+    [JsonPropertyName("x")]
+    public required double X { get; init; }
+
+    [JsonPropertyName("y")]
+    public required double Y { get; init; }
+}
+
+public sealed record Tree
+{
+    [JsonPropertyName("value")]
+    public required double Value { get; init; }
+
+    [JsonPropertyName("children")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public List<Tree>? Children { get; init; }
+}
+
+public sealed record Refs
+{
+    [JsonPropertyName("color")]
+    public required Color Color { get; init; }
+
+    [JsonPropertyName("id")]
+    public required string Id { get; init; }
+
+    [JsonPropertyName("ids")]
+    public required List<string> Ids { get; init; }
+
+    [JsonPropertyName("points")]
+    public required List<Point> Points { get; init; }
+
+    [JsonPropertyName("pointsById")]
+    public required Dictionary<string, Point> PointsById { get; init; }
+
+    [JsonPropertyName("tree")]
+    public required Tree Tree { get; init; }
+
+    [JsonPropertyName("optionalColor")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Color? OptionalColor { get; init; }
+
+    [JsonPropertyName("optionalId")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? OptionalId { get; init; }
+
+    [JsonPropertyName("optionalPoint")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Point? OptionalPoint { get; init; }
+}
@@ -0,0 +1,122 @@
+enum Color {
+  blue("blue"),
+  green("green"),
+  red("red");
+
+  const Color(this.value);
+
+  final String value;
+
+  factory Color.fromJson(String json) {
+    return values.firstWhere(
+      (value) => value.value == json,
+      orElse: () => throw FormatException("unknown Color value", json),
+    );
+  }
+
+  String toJson() => value;
+}
+
+typedef Id = String;
+
+typedef Ids = List<Id>;
+
+class Point {
+  final double x;
+  final double y;
+
+  const Point({
+    required this.x,
+    required this.y,
+  });
+
+  factory Point.fromJson(Map<String, dynamic> json) {
+    return Point(
+      x: (json["x"] as num).toDouble(),
+      y: (json["y"] as num).toDouble(),
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "x": x,
+      "y": y,
+    };
+  }
+}
+
+class Tree {
+  final double value;
+  final List<Tree>? children;
+
+  const Tree({
+    required this.value,
+    this.children,
+  });
+
+  factory Tree.fromJson(Map<String, dynamic> json) {
+    return Tree(
+      value: (json["value"] as num).toDouble(),
+      children: json["children"] == null ? null : (json["children"] as List<dynamic>).map((v0) => Tree.fromJson(v0 as Map<String, dynamic>)).toList(),
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "value": value,
+      if (children != null) "children": children!.map((v0) => v0.toJson()).toList(),
+    };
+  }
+}
+
+class Refs {
+  final Color color;
+  final Id id;
+  final Ids ids;
+  final List<Point> points;
+  final Map<String, Point> pointsById;
+  final Tree tree;
+  final Color? optionalColor;
+  final Id? optionalId;
+  final Point? optionalPoint;
+
+  const Refs({
+    required this.color,
+    required this.id,
+    required this.ids,
+    required this.points,
+    required this.pointsById,
+    required this.tree,
+    this.optionalColor,
+    this.optionalId,
+    this.optionalPoint,
+  });
+
+  factory Refs.fromJson(Map<String, dynamic> json) {
+    return Refs(
+      color: Color.fromJson(json["color"] as String),
+      id: json["id"] as String,
+      ids: (json["ids"] as List<dynamic>).map((v0) => v0 as String).toList(),
+      points: (json["points"] as List<dynamic>).map((v0) => Point.fromJson(v0 as Map<String, dynamic>)).toList(),
+      pointsById: (json["pointsById"] as Map<String, dynamic>).map((k0, v0) => MapEntry(k0, Point.fromJson(v0 as Map<String, dynamic>))),
+      tree: Tree.fromJson(json["tree"] as Map<String, dynamic>),
+      optionalColor: json["optionalColor"] == null ? null : Color.fromJson(json["optionalColor"] as String),
+      optionalId: json["optionalId"] == null ? null : json["optionalId"] as String,
+      optionalPoint: json["optionalPoint"] == null ? null : Point.fromJson(json["optionalPoint"] as Map<String, dynamic>),
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "color": color.toJson(),
+      "id": id,
+      "ids": ids,
+      "points": points.map((v0) => v0.toJson()).toList(),
+      "pointsById": pointsById.map((k0, v0) => MapEntry(k0, v0.toJson())),
+      "tree": tree.toJson(),
+      if (optionalColor != null) "optionalColor": optionalColor!.toJson(),
+      if (optionalId != null) "optionalId": optionalId,
+      if (optionalPoint != null) "optionalPoint": optionalPoint!.toJson(),
+    };
+  }
+}
@@ -0,0 +1,283 @@
+package models
+
+import (
+	"encoding/json"
+	"strconv"
+)
+
+type Color string
+
+const (
+	ColorBlue Color = "blue"
+	ColorGreen Color = "green"
+	ColorRed Color = "red"
+)
+
+func ValidateColor(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateColor(instance, nil, &errors)
+	return errors
+}
+
+func validateColor(instance interface{}, path []string, errors *[]ValidationError) {
+	switch instance {
+	case "blue", "green", "red":
+	default:
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/color/enum"})
+	}
+}
+
+func DecodeColor(data []byte) (Color, error) {
+	var out Color
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateColor(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type Id string
+
+func ValidateId(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateId(instance, nil, &errors)
+	return errors
+}
+
+func validateId(instance interface{}, path []string, errors *[]ValidationError) {
+	if _, ok := instance.(string); !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/id/type"})
+	}
+}
+
+func DecodeId(data []byte) (Id, error) {
+	var out Id
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateId(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type Ids []Id
+
+func ValidateIds(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateIds(instance, nil, &errors)
+	return errors
+}
+
+func validateIds(instance interface{}, path []string, errors *[]ValidationError) {
+	if a0, ok := instance.([]interface{}); ok {
+		for i0, v1 := range a0 {
+			validateId(v1, jslAppend(path, strconv.Itoa(i0)), errors)
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/ids/elements"})
+	}
+}
+
+func DecodeIds(data []byte) (Ids, error) {
+	var out Ids
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateIds(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type Point struct {
+	X float64 `json:"x"`
+	Y float64 `json:"y"`
+}
+
+func ValidatePoint(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validatePoint(instance, nil, &errors)
+	return errors
+}
+
+func validatePoint(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/point/properties"})
+		return
+	}
+	if v0, ok := obj["x"]; ok {
+		if _, ok := v0.(float64); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "x")), SchemaPath: "/definitions/point/properties/x/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/point/properties/x"})
+	}
+	if v0, ok := obj["y"]; ok {
+		if _, ok := v0.(float64); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "y")), SchemaPath: "/definitions/point/properties/y/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/point/properties/y"})
+	}
+}
+
+func DecodePoint(data []byte) (Point, error) {
+	var out Point
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidatePoint(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type Tree struct {
+	Value float64 `json:"value"`
+	Children []Tree `json:"children,omitempty"`
+}
+
+func ValidateTree(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateTree(instance, nil, &errors)
+	return errors
+}
+
+func validateTree(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/tree/properties"})
+		return
+	}
+	if v0, ok := obj["value"]; ok {
+		if _, ok := v0.(float64); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "value")), SchemaPath: "/definitions/tree/properties/value/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/tree/properties/value"})
+	}
+	if v0, ok := obj["children"]; ok {
+		if a0, ok := v0.([]interface{}); ok {
+			for i0, v1 := range a0 {
+				validateTree(v1, jslAppend(path, "children", strconv.Itoa(i0)), errors)
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "children")), SchemaPath: "/definitions/tree/optionalProperties/children/elements"})
+		}
+	}
+}
+
+func DecodeTree(data []byte) (Tree, error) {
+	var out Tree
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateTree(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type Refs struct {
+	Color Color `json:"color"`
+	Id Id `json:"id"`
+	Ids Ids `json:"ids"`
+	Points []Point `json:"points"`
+	PointsById map[string]Point `json:"pointsById"`
+	Tree Tree `json:"tree"`
+	OptionalColor *Color `json:"optionalColor,omitempty"`
+	OptionalId *Id `json:"optionalId,omitempty"`
+	OptionalPoint *Point `json:"optionalPoint,omitempty"`
+}
+
+func ValidateRefs(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateRefs(instance, nil, &errors)
+	return errors
+}
+
+func validateRefs(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties"})
+		return
+	}
+	if v0, ok := obj["color"]; ok {
+		validateColor(v0, jslAppend(path, "color"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/color"})
+	}
+	if v0, ok := obj["id"]; ok {
+		validateId(v0, jslAppend(path, "id"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/id"})
+	}
+	if v0, ok := obj["ids"]; ok {
+		validateIds(v0, jslAppend(path, "ids"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/ids"})
+	}
+	if v0, ok := obj["points"]; ok {
+		if a0, ok := v0.([]interface{}); ok {
+			for i0, v1 := range a0 {
+				validatePoint(v1, jslAppend(path, "points", strconv.Itoa(i0)), errors)
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "points")), SchemaPath: "/properties/points/elements"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/points"})
+	}
+	if v0, ok := obj["pointsById"]; ok {
+		if o0, ok := v0.(map[string]interface{}); ok {
+			for k0, v1 := range o0 {
+				validatePoint(v1, jslAppend(path, "pointsById", k0), errors)
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "pointsById")), SchemaPath: "/properties/pointsById/values"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/pointsById"})
+	}
+	if v0, ok := obj["tree"]; ok {
+		validateTree(v0, jslAppend(path, "tree"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/tree"})
+	}
+	if v0, ok := obj["optionalColor"]; ok {
+		validateColor(v0, jslAppend(path, "optionalColor"), errors)
+	}
+	if v0, ok := obj["optionalId"]; ok {
+		validateId(v0, jslAppend(path, "optionalId"), errors)
+	}
+	if v0, ok := obj["optionalPoint"]; ok {
+		validatePoint(v0, jslAppend(path, "optionalPoint"), errors)
+	}
+}
+
+func DecodeRefs(data []byte) (Refs, error) {
+	var out Refs
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateRefs(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
@@ -0,0 +1,44 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class Point {
+    public double x;
+    public double y;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/definitions/point/properties"));
+            return;
+        }
+        if (instance.has("x")) {
+            JsonNode v0 = instance.get("x");
+            if (!v0.isNumber()) {
+                errors.add(new ValidationError(ValidationError.append(path, "x"), "/definitions/point/properties/x/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/definitions/point/properties/x"));
+        }
+        if (instance.has("y")) {
+            JsonNode v0 = instance.get("y");
+            if (!v0.isNumber()) {
+                errors.add(new ValidationError(ValidationError.append(path, "y"), "/definitions/point/properties/y/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/definitions/point/properties/y"));
+        }
+    }
+}
@@ -0,0 +1,115 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class Refs {
+    public Color color;
+    public String id;
+    public List<String> ids;
+    public List<Point> points;
+    public Map<String, Point> pointsById;
+    public Tree tree;
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public Color optionalColor;
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public String optionalId;
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public Point optionalPoint;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/properties"));
+            return;
+        }
+        if (instance.has("color")) {
+            JsonNode v0 = instance.get("color");
+            Color.validate(v0, ValidationError.append(path, "color"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/color"));
+        }
+        if (instance.has("id")) {
+            JsonNode v0 = instance.get("id");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "id"), "/definitions/id/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/id"));
+        }
+        if (instance.has("ids")) {
+            JsonNode v0 = instance.get("ids");
+            if (v0.isArray()) {
+                for (int i0 = 0; i0 < v0.size(); i0++) {
+                    JsonNode v1 = v0.get(i0);
+                    if (!v1.isTextual()) {
+                        errors.add(new ValidationError(ValidationError.append(path, "ids", Integer.toString(i0)), "/definitions/id/type"));
+                    }
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "ids"), "/definitions/ids/elements"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/ids"));
+        }
+        if (instance.has("points")) {
+            JsonNode v0 = instance.get("points");
+            if (v0.isArray()) {
+                for (int i0 = 0; i0 < v0.size(); i0++) {
+                    JsonNode v1 = v0.get(i0);
+                    Point.validate(v1, ValidationError.append(path, "points", Integer.toString(i0)), errors);
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "points"), "/properties/points/elements"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/points"));
+        }
+        if (instance.has("pointsById")) {
+            JsonNode v0 = instance.get("pointsById");
+            if (v0.isObject()) {
+                for (Iterator<Map.Entry<String, JsonNode>> it0 = v0.fields(); it0.hasNext();) {
+                    Map.Entry<String, JsonNode> e0 = it0.next();
+                    JsonNode v1 = e0.getValue();
+                    Point.validate(v1, ValidationError.append(path, "pointsById", e0.getKey()), errors);
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "pointsById"), "/properties/pointsById/values"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/pointsById"));
+        }
+        if (instance.has("tree")) {
+            JsonNode v0 = instance.get("tree");
+            Tree.validate(v0, ValidationError.append(path, "tree"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/tree"));
+        }
+        if (instance.has("optionalColor")) {
+            JsonNode v0 = instance.get("optionalColor");
+            Color.validate(v0, ValidationError.append(path, "optionalColor"), errors);
+        }
+        if (instance.has("optionalId")) {
+            JsonNode v0 = instance.get("optionalId");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "optionalId"), "/definitions/id/type"));
+            }
+        }
+        if (instance.has("optionalPoint")) {
+            JsonNode v0 = instance.get("optionalPoint");
+            Point.validate(v0, ValidationError.append(path, "optionalPoint"), errors);
+        }
+    }
+}
@@ -0,0 +1,49 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class Tree {
+    public double value;
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public List<Tree> children;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/definitions/tree/properties"));
+            return;
+        }
+        if (instance.has("value")) {
+            JsonNode v0 = instance.get("value");
+            if (!v0.isNumber()) {
+                errors.add(new ValidationError(ValidationError.append(path, "value"), "/definitions/tree/properties/value/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/definitions/tree/properties/value"));
+        }
+        if (instance.has("children")) {
+            JsonNode v0 = instance.get("children");
+            if (v0.isArray()) {
+                for (int i0 = 0; i0 < v0.size(); i0++) {
+                    JsonNode v1 = v0.get(i0);
+                    Tree.validate(v1, ValidationError.append(path, "children", Integer.toString(i0)), errors);
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "children"), "/definitions/tree/optionalProperties/children/elements"));
+            }
+        }
+    }
+}
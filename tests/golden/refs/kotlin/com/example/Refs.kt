@@ -0,0 +1,50 @@
+@file:OptIn(ExperimentalSerializationApi::class)
+
+package com.example
+
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.json.JsonIgnoreUnknownKeys
+
+@Serializable
+enum class Color {
+    @SerialName("blue")
+    BLUE,
+    @SerialName("green")
+    GREEN,
+    @SerialName("red")
+    RED,
+}
+
+typealias Id = String
+
+typealias Ids = List<Id>
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class Point(
+    val x: Double,
+    val y: Double,
+)
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class Tree(
+    val value: Double,
+    val children: List<Tree>? = null,
+)
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class Refs(
+    val color: Color,
+    val id: Id,
+    val ids: Ids,
+    val points: List<Point>,
+    val pointsById: Map<String, Point>,
+    val tree: Tree,
+    val optionalColor: Color? = null,
+    val optionalId: Id? = null,
+    val optionalPoint: Point? = null,
+)
@@ -0,0 +1,99 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from enum import Enum
+from typing import Any, Dict, List, Optional
+
+
+class Color(Enum):
+    BLUE = "blue"
+    GREEN = "green"
+    RED = "red"
+
+
+Id = str
+
+
+Ids = List["Id"]
+
+
+@dataclass
+class Point:
+    x: float
+    y: float
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Point:
+        return cls(
+            x=data["x"],
+            y=data["y"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["x"] = self.x
+        data["y"] = self.y
+        return data
+
+
+@dataclass
+class Tree:
+    value: float
+    children: Optional[List[Tree]] = None
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Tree:
+        return cls(
+            value=data["value"],
+            children=[Tree.from_json(v0) for v0 in data["children"]] if "children" in data else None,
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["value"] = self.value
+        if self.children is not None:
+            data["children"] = [v0.to_json() for v0 in self.children]
+        return data
+
+
+@dataclass
+class Refs:
+    color: Color
+    id: Id
+    ids: Ids
+    points: List[Point]
+    points_by_id: Dict[str, Point]
+    tree: Tree
+    optional_color: Optional[Color] = None
+    optional_id: Optional[Id] = None
+    optional_point: Optional[Point] = None
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Refs:
+        return cls(
+            color=Color(data["color"]),
+            id=data["id"],
+            ids=data["ids"],
+            points=[Point.from_json(v0) for v0 in data["points"]],
+            points_by_id={k0: Point.from_json(v0) for k0, v0 in data["pointsById"].items()},
+            tree=Tree.from_json(data["tree"]),
+            optional_color=Color(data["optionalColor"]) if "optionalColor" in data else None,
+            optional_id=data.get("optionalId"),
+            optional_point=Point.from_json(data["optionalPoint"]) if "optionalPoint" in data else None,
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["color"] = self.color.value
+        data["id"] = self.id
+        data["ids"] = self.ids
+        data["points"] = [v0.to_json() for v0 in self.points]
+        data["pointsById"] = {k0: v0.to_json() for k0, v0 in self.points_by_id.items()}
+        data["tree"] = self.tree.to_json()
+        if self.optional_color is not None:
+            data["optionalColor"] = self.optional_color.value
+        if self.optional_id is not None:
+            data["optionalId"] = self.optional_id
+        if self.optional_point is not None:
+            data["optionalPoint"] = self.optional_point.to_json()
+        return data
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Color {
+    #[serde(rename = "blue")]
+    Blue,
+    #[serde(rename = "green")]
+    Green,
+    #[serde(rename = "red")]
+    Red,
+}
+
+pub type Id = String;
+
+pub type Ids = Vec<Id>;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tree {
+    pub value: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub children: Option<Vec<Tree>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Refs {
+    pub color: Color,
+    pub id: Id,
+    pub ids: Ids,
+    pub points: Vec<Point>,
+    #[serde(rename = "pointsById")]
+    pub points_by_id: std::collections::HashMap<String, Point>,
+    pub tree: Tree,
+    #[serde(rename = "optionalColor")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional_color: Option<Color>,
+    #[serde(rename = "optionalId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional_id: Option<Id>,
+    #[serde(rename = "optionalPoint")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional_point: Option<Point>,
+}
@@ -0,0 +1,33 @@
+import Foundation
+
+enum Color: String, Codable, Equatable {
+    case blue
+    case green
+    case red
+}
+
+typealias Id = String
+
+typealias Ids = [Id]
+
+struct Point: Codable, Equatable {
+    var x: Double
+    var y: Double
+}
+
+struct Tree: Codable, Equatable {
+    var value: Double
+    var children: [Tree]?
+}
+
+struct Refs: Codable, Equatable {
+    var color: Color
+    var id: Id
+    var ids: Ids
+    var points: [Point]
+    var pointsById: [String: Point]
+    var tree: Tree
+    var optionalColor: Color?
+    var optionalId: Id?
+    var optionalPoint: Point?
+}
@@ -0,0 +1,190 @@
+export interface ValidationError {
+  instancePath: string;
+  schemaPath: string;
+}
+function jslPointer(path: string[]): string {
+  return path.map((token) => "/" + token.replace(/~/g, "~0").replace(/\//g, "~1")).join("");
+}
+function jslIsObject(instance: unknown): instance is { [key: string]: unknown } {
+  return typeof instance === "object" && instance !== null && !Array.isArray(instance);
+}
+function jslHas(instance: { [key: string]: unknown }, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(instance, key);
+}
+export type Color = "blue" | "green" | "red";
+export function validateColor(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateColorAt(instance, [], errors);
+  return errors;
+}
+export function validateColorAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (instance !== "blue" && instance !== "green" && instance !== "red") {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/color/enum" });
+  }
+}
+export type Id = string;
+export function validateId(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateIdAt(instance, [], errors);
+  return errors;
+}
+export function validateIdAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (typeof instance !== "string") {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/id/type" });
+  }
+}
+export type Ids = Id[];
+export function validateIds(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateIdsAt(instance, [], errors);
+  return errors;
+}
+export function validateIdsAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  const a0: unknown = instance;
+  if (Array.isArray(a0)) {
+    a0.forEach((v0: unknown, i0: number) => {
+      validateIdAt(v0, [...path, String(i0)], errors);
+    });
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/ids/elements" });
+  }
+}
+export interface Point {
+  x: number;
+  y: number;
+  [key: string]: unknown;
+}
+export function validatePoint(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validatePointAt(instance, [], errors);
+  return errors;
+}
+export function validatePointAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/point/properties" });
+    return;
+  }
+  if (jslHas(instance, "x")) {
+    if (typeof instance["x"] !== "number") {
+      errors.push({ instancePath: jslPointer([...path, "x"]), schemaPath: "/definitions/point/properties/x/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/point/properties/x" });
+  }
+  if (jslHas(instance, "y")) {
+    if (typeof instance["y"] !== "number") {
+      errors.push({ instancePath: jslPointer([...path, "y"]), schemaPath: "/definitions/point/properties/y/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/point/properties/y" });
+  }
+}
+export interface Tree {
+  value: number;
+  children?: Tree[];
+  [key: string]: unknown;
+}
+export function validateTree(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateTreeAt(instance, [], errors);
+  return errors;
+}
+export function validateTreeAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/tree/properties" });
+    return;
+  }
+  if (jslHas(instance, "value")) {
+    if (typeof instance["value"] !== "number") {
+      errors.push({ instancePath: jslPointer([...path, "value"]), schemaPath: "/definitions/tree/properties/value/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/tree/properties/value" });
+  }
+  if (jslHas(instance, "children")) {
+    const a0: unknown = instance["children"];
+    if (Array.isArray(a0)) {
+      a0.forEach((v0: unknown, i0: number) => {
+        validateTreeAt(v0, [...path, "children", String(i0)], errors);
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "children"]), schemaPath: "/definitions/tree/optionalProperties/children/elements" });
+    }
+  }
+}
+export interface Refs {
+  color: Color;
+  id: Id;
+  ids: Ids;
+  points: Point[];
+  pointsById: { [name: string]: Point };
+  tree: Tree;
+  optionalColor?: Color;
+  optionalId?: Id;
+  optionalPoint?: Point;
+  [key: string]: unknown;
+}
+export function validateRefs(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateRefsAt(instance, [], errors);
+  return errors;
+}
+export function validateRefsAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties" });
+    return;
+  }
+  if (jslHas(instance, "color")) {
+    validateColorAt(instance["color"], [...path, "color"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/color" });
+  }
+  if (jslHas(instance, "id")) {
+    validateIdAt(instance["id"], [...path, "id"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/id" });
+  }
+  if (jslHas(instance, "ids")) {
+    validateIdsAt(instance["ids"], [...path, "ids"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/ids" });
+  }
+  if (jslHas(instance, "points")) {
+    const a0: unknown = instance["points"];
+    if (Array.isArray(a0)) {
+      a0.forEach((v0: unknown, i0: number) => {
+        validatePointAt(v0, [...path, "points", String(i0)], errors);
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "points"]), schemaPath: "/properties/points/elements" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/points" });
+  }
+  if (jslHas(instance, "pointsById")) {
+    const o0: unknown = instance["pointsById"];
+    if (jslIsObject(o0)) {
+      Object.keys(o0).forEach((k0: string) => {
+        validatePointAt(o0[k0], [...path, "pointsById", k0], errors);
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "pointsById"]), schemaPath: "/properties/pointsById/values" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/pointsById" });
+  }
+  if (jslHas(instance, "tree")) {
+    validateTreeAt(instance["tree"], [...path, "tree"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/tree" });
+  }
+  if (jslHas(instance, "optionalColor")) {
+    validateColorAt(instance["optionalColor"], [...path, "optionalColor"], errors);
+  }
+  if (jslHas(instance, "optionalId")) {
+    validateIdAt(instance["optionalId"], [...path, "optionalId"], errors);
+  }
+  if (jslHas(instance, "optionalPoint")) {
+    validatePointAt(instance["optionalPoint"], [...path, "optionalPoint"], errors);
+  }
+}
@@ -0,0 +1,14 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Example;
+
+public sealed record RootElementsElement
+{
+    [JsonPropertyName("id")]
+    public required string Id { get; init; }
+}
@@ -0,0 +1,21 @@
+class RootElementsElement {
+  final String id;
+
+  const RootElementsElement({
+    required this.id,
+  });
+
+  factory RootElementsElement.fromJson(Map<String, dynamic> json) {
+    return RootElementsElement(
+      id: json["id"] as String,
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "id": id,
+    };
+  }
+}
+
+typedef RootElements = List<RootElementsElement>;
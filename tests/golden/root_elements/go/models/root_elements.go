@@ -0,0 +1,75 @@
+package models
+
+import (
+	"encoding/json"
+	"strconv"
+)
+
+type RootElementsElement struct {
+	Id string `json:"id"`
+}
+
+func ValidateRootElementsElement(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateRootElementsElement(instance, nil, &errors)
+	return errors
+}
+
+func validateRootElementsElement(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/elements/properties"})
+		return
+	}
+	if v0, ok := obj["id"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "id")), SchemaPath: "/elements/properties/id/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/elements/properties/id"})
+	}
+}
+
+func DecodeRootElementsElement(data []byte) (RootElementsElement, error) {
+	var out RootElementsElement
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateRootElementsElement(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type RootElements []RootElementsElement
+
+func ValidateRootElements(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateRootElements(instance, nil, &errors)
+	return errors
+}
+
+func validateRootElements(instance interface{}, path []string, errors *[]ValidationError) {
+	if a0, ok := instance.([]interface{}); ok {
+		for i0, v1 := range a0 {
+			validateRootElementsElement(v1, jslAppend(path, strconv.Itoa(i0)), errors)
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/elements"})
+	}
+}
+
+func DecodeRootElements(data []byte) (RootElements, error) {
+	var out RootElements
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateRootElements(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
@@ -0,0 +1,28 @@
+package com.example;
+
+import com.fasterxml.jackson.databind.JsonNode;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+public final class RootElements {
+    private RootElements() {}
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (instance.isArray()) {
+            for (int i0 = 0; i0 < instance.size(); i0++) {
+                JsonNode v1 = instance.get(i0);
+                RootElementsElement.validate(v1, ValidationError.append(path, Integer.toString(i0)), errors);
+            }
+        } else {
+            errors.add(new ValidationError(path, "/elements"));
+        }
+    }
+}
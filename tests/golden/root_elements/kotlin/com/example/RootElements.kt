@@ -0,0 +1,15 @@
+@file:OptIn(ExperimentalSerializationApi::class)
+
+package com.example
+
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.json.JsonIgnoreUnknownKeys
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class RootElementsElement(
+    val id: String,
+)
+
+typealias RootElements = List<RootElementsElement>
@@ -0,0 +1,23 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from typing import Any, Dict, List
+
+
+@dataclass
+class RootElementsElement:
+    id: str
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> RootElementsElement:
+        return cls(
+            id=data["id"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["id"] = self.id
+        return data
+
+
+RootElements = List["RootElementsElement"]
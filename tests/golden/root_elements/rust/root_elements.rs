@@ -0,0 +1,8 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RootElementsElement {
+    pub id: String,
+}
+
+pub type RootElements = Vec<RootElementsElement>;
@@ -0,0 +1,7 @@
+import Foundation
+
+struct RootElementsElement: Codable, Equatable {
+    var id: String
+}
+
+typealias RootElements = [RootElementsElement]
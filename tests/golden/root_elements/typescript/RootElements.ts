@@ -0,0 +1,51 @@
+export interface ValidationError {
+  instancePath: string;
+  schemaPath: string;
+}
+function jslPointer(path: string[]): string {
+  return path.map((token) => "/" + token.replace(/~/g, "~0").replace(/\//g, "~1")).join("");
+}
+function jslIsObject(instance: unknown): instance is { [key: string]: unknown } {
+  return typeof instance === "object" && instance !== null && !Array.isArray(instance);
+}
+function jslHas(instance: { [key: string]: unknown }, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(instance, key);
+}
+export interface RootElementsElement {
+  id: string;
+  [key: string]: unknown;
+}
+export function validateRootElementsElement(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateRootElementsElementAt(instance, [], errors);
+  return errors;
+}
+export function validateRootElementsElementAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/elements/properties" });
+    return;
+  }
+  if (jslHas(instance, "id")) {
+    if (typeof instance["id"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "id"]), schemaPath: "/elements/properties/id/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/elements/properties/id" });
+  }
+}
+export type RootElements = RootElementsElement[];
+export function validateRootElements(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateRootElementsAt(instance, [], errors);
+  return errors;
+}
+export function validateRootElementsAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  const a0: unknown = instance;
+  if (Array.isArray(a0)) {
+    a0.forEach((v0: unknown, i0: number) => {
+      validateRootElementsElementAt(v0, [...path, String(i0)], errors);
+    });
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/elements" });
+  }
+}
@@ -0,0 +1,22 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Example;
+
+/// <summary>
+/// A traffic light color
+/// </summary>
+[JsonConverter(typeof(JsonStringEnumConverter<RootEnum>))]
+public enum RootEnum
+{
+    [JsonStringEnumMemberName("green")]
+    Green,
+    [JsonStringEnumMemberName("red")]
+    Red,
+    [JsonStringEnumMemberName("yellow")]
+    Yellow,
+}
@@ -0,0 +1,19 @@
+/// A traffic light color
+enum RootEnum {
+  green("green"),
+  red("red"),
+  yellow("yellow");
+
+  const RootEnum(this.value);
+
+  final String value;
+
+  factory RootEnum.fromJson(String json) {
+    return values.firstWhere(
+      (value) => value.value == json,
+      orElse: () => throw FormatException("unknown RootEnum value", json),
+    );
+  }
+
+  String toJson() => value;
+}
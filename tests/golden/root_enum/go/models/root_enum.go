@@ -0,0 +1,41 @@
+package models
+
+import (
+	"encoding/json"
+)
+
+// A traffic light color
+type RootEnum string
+
+const (
+	RootEnumGreen RootEnum = "green"
+	RootEnumRed RootEnum = "red"
+	RootEnumYellow RootEnum = "yellow"
+)
+
+func ValidateRootEnum(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateRootEnum(instance, nil, &errors)
+	return errors
+}
+
+func validateRootEnum(instance interface{}, path []string, errors *[]ValidationError) {
+	switch instance {
+	case "green", "red", "yellow":
+	default:
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/enum"})
+	}
+}
+
+func DecodeRootEnum(data []byte) (RootEnum, error) {
+	var out RootEnum
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateRootEnum(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
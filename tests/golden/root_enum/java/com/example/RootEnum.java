@@ -0,0 +1,36 @@
+package com.example;
+
+import com.fasterxml.jackson.databind.JsonNode;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+/**
+ * A traffic light color
+ */
+public enum RootEnum {
+   green,
+   red,
+   yellow,
+   ;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (instance.isTextual()) {
+            switch (instance.asText()) {
+            case "green":
+            case "red":
+            case "yellow":
+                return;
+            }
+        }
+
+        errors.add(new ValidationError(path, "/enum"));
+    }
+}
@@ -0,0 +1,17 @@
+package com.example
+
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+
+/**
+ * A traffic light color
+ */
+@Serializable
+enum class RootEnum {
+    @SerialName("green")
+    GREEN,
+    @SerialName("red")
+    RED,
+    @SerialName("yellow")
+    YELLOW,
+}
@@ -0,0 +1,10 @@
+from __future__ import annotations
+
+from enum import Enum
+
+
+class RootEnum(Enum):
+    """A traffic light color"""
+    GREEN = "green"
+    RED = "red"
+    YELLOW = "yellow"
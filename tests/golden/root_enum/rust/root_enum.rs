@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// A traffic light color
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum RootEnum {
+    #[serde(rename = "green")]
+    Green,
+    #[serde(rename = "red")]
+    Red,
+    #[serde(rename = "yellow")]
+    Yellow,
+}
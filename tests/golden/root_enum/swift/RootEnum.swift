@@ -0,0 +1,8 @@
+import Foundation
+
+/// A traffic light color
+enum RootEnum: String, Codable, Equatable {
+    case green
+    case red
+    case yellow
+}
@@ -0,0 +1,27 @@
+export interface ValidationError {
+  instancePath: string;
+  schemaPath: string;
+}
+function jslPointer(path: string[]): string {
+  return path.map((token) => "/" + token.replace(/~/g, "~0").replace(/\//g, "~1")).join("");
+}
+function jslIsObject(instance: unknown): instance is { [key: string]: unknown } {
+  return typeof instance === "object" && instance !== null && !Array.isArray(instance);
+}
+function jslHas(instance: { [key: string]: unknown }, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(instance, key);
+}
+/**
+ * A traffic light color
+ */
+export type RootEnum = "green" | "red" | "yellow";
+export function validateRootEnum(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateRootEnumAt(instance, [], errors);
+  return errors;
+}
+export function validateRootEnumAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (instance !== "green" && instance !== "red" && instance !== "yellow") {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/enum" });
+  }
+}
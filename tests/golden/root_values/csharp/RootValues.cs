@@ -0,0 +1,17 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Example;
+
+[JsonConverter(typeof(JsonStringEnumConverter<RootValuesValue>))]
+public enum RootValuesValue
+{
+    [JsonStringEnumMemberName("off")]
+    Off,
+    [JsonStringEnumMemberName("on")]
+    On,
+}
@@ -0,0 +1,19 @@
+enum RootValuesValue {
+  off("off"),
+  on("on");
+
+  const RootValuesValue(this.value);
+
+  final String value;
+
+  factory RootValuesValue.fromJson(String json) {
+    return values.firstWhere(
+      (value) => value.value == json,
+      orElse: () => throw FormatException("unknown RootValuesValue value", json),
+    );
+  }
+
+  String toJson() => value;
+}
+
+typedef RootValues = Map<String, RootValuesValue>;
@@ -0,0 +1,70 @@
+package models
+
+import (
+	"encoding/json"
+)
+
+type RootValuesValue string
+
+const (
+	RootValuesValueOff RootValuesValue = "off"
+	RootValuesValueOn RootValuesValue = "on"
+)
+
+func ValidateRootValuesValue(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateRootValuesValue(instance, nil, &errors)
+	return errors
+}
+
+func validateRootValuesValue(instance interface{}, path []string, errors *[]ValidationError) {
+	switch instance {
+	case "off", "on":
+	default:
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/values/enum"})
+	}
+}
+
+func DecodeRootValuesValue(data []byte) (RootValuesValue, error) {
+	var out RootValuesValue
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateRootValuesValue(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type RootValues map[string]RootValuesValue
+
+func ValidateRootValues(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateRootValues(instance, nil, &errors)
+	return errors
+}
+
+func validateRootValues(instance interface{}, path []string, errors *[]ValidationError) {
+	if o0, ok := instance.(map[string]interface{}); ok {
+		for k0, v1 := range o0 {
+			validateRootValuesValue(v1, jslAppend(path, k0), errors)
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/values"})
+	}
+}
+
+func DecodeRootValues(data []byte) (RootValues, error) {
+	var out RootValues
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateRootValues(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
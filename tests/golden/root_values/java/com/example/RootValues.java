@@ -0,0 +1,29 @@
+package com.example;
+
+import com.fasterxml.jackson.databind.JsonNode;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+public final class RootValues {
+    private RootValues() {}
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (instance.isObject()) {
+            for (Iterator<Map.Entry<String, JsonNode>> it0 = instance.fields(); it0.hasNext();) {
+                Map.Entry<String, JsonNode> e0 = it0.next();
+                JsonNode v1 = e0.getValue();
+                RootValuesValue.validate(v1, ValidationError.append(path, e0.getKey()), errors);
+            }
+        } else {
+            errors.add(new ValidationError(path, "/values"));
+        }
+    }
+}
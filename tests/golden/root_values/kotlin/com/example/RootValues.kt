@@ -0,0 +1,14 @@
+package com.example
+
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+
+@Serializable
+enum class RootValuesValue {
+    @SerialName("off")
+    OFF,
+    @SerialName("on")
+    ON,
+}
+
+typealias RootValues = Map<String, RootValuesValue>
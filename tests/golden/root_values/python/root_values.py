@@ -0,0 +1,12 @@
+from __future__ import annotations
+
+from enum import Enum
+from typing import Dict
+
+
+class RootValuesValue(Enum):
+    OFF = "off"
+    ON = "on"
+
+
+RootValues = Dict[str, "RootValuesValue"]
@@ -0,0 +1,8 @@
+import Foundation
+
+enum RootValuesValue: String, Codable, Equatable {
+    case off
+    case on
+}
+
+typealias RootValues = [String: RootValuesValue]
@@ -0,0 +1,40 @@
+export interface ValidationError {
+  instancePath: string;
+  schemaPath: string;
+}
+function jslPointer(path: string[]): string {
+  return path.map((token) => "/" + token.replace(/~/g, "~0").replace(/\//g, "~1")).join("");
+}
+function jslIsObject(instance: unknown): instance is { [key: string]: unknown } {
+  return typeof instance === "object" && instance !== null && !Array.isArray(instance);
+}
+function jslHas(instance: { [key: string]: unknown }, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(instance, key);
+}
+export type RootValuesValue = "off" | "on";
+export function validateRootValuesValue(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateRootValuesValueAt(instance, [], errors);
+  return errors;
+}
+export function validateRootValuesValueAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (instance !== "off" && instance !== "on") {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/values/enum" });
+  }
+}
+export type RootValues = { [name: string]: RootValuesValue };
+export function validateRootValues(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateRootValuesAt(instance, [], errors);
+  return errors;
+}
+export function validateRootValuesAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  const o0: unknown = instance;
+  if (jslIsObject(o0)) {
+    Object.keys(o0).forEach((k0: string) => {
+      validateRootValuesValueAt(o0[k0], [...path, k0], errors);
+    });
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/values" });
+  }
+}
@@ -0,0 +1,46 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Example;
+
+[JsonConverter(typeof(JsonStringEnumConverter<AddressKind>))]
+public enum AddressKind
+{
+    [JsonStringEnumMemberName("home")]
+    Home,
+    [JsonStringEnumMemberName("work")]
+    Work,
+}
+
+public sealed record Address
+{
+    [JsonPropertyName("country")]
+    public required string Country { get; init; }
+
+    [JsonPropertyName("kind")]
+    public required AddressKind Kind { get; init; }
+
+    [JsonPropertyName("street")]
+    public required string Street { get; init; }
+}
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "type")]
+[JsonDerivedType(typeof(ContactEmail), "email")]
+[JsonDerivedType(typeof(ContactPhone), "phone")]
+public abstract record Contact;
+
+public sealed record ContactEmail : Contact
+{
+    [JsonPropertyName("address")]
+    public required string Address { get; init; }
+}
+
+public sealed record ContactPhone : Contact
+{
+    [JsonPropertyName("number")]
+    public required string Number { get; init; }
+}
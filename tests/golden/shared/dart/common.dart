@@ -0,0 +1,109 @@
+enum AddressKind {
+  home("home"),
+  work("work");
+
+  const AddressKind(this.value);
+
+  final String value;
+
+  factory AddressKind.fromJson(String json) {
+    return values.firstWhere(
+      (value) => value.value == json,
+      orElse: () => throw FormatException("unknown AddressKind value", json),
+    );
+  }
+
+  String toJson() => value;
+}
+
+class Address {
+  final Country country;
+  final AddressKind kind;
+  final String street;
+
+  const Address({
+    required this.country,
+    required this.kind,
+    required this.street,
+  });
+
+  factory Address.fromJson(Map<String, dynamic> json) {
+    return Address(
+      country: json["country"] as String,
+      kind: AddressKind.fromJson(json["kind"] as String),
+      street: json["street"] as String,
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "country": country,
+      "kind": kind.toJson(),
+      "street": street,
+    };
+  }
+}
+
+sealed class Contact {
+  const Contact();
+
+  factory Contact.fromJson(Map<String, dynamic> json) {
+    return switch (json["type"]) {
+      "email" => ContactEmail.fromJson(json),
+      "phone" => ContactPhone.fromJson(json),
+      _ => throw FormatException("unknown Contact tag", json["type"]),
+    };
+  }
+
+  Map<String, dynamic> toJson();
+}
+
+class ContactEmail extends Contact {
+  final String address;
+
+  const ContactEmail({
+    required this.address,
+  });
+
+  factory ContactEmail.fromJson(Map<String, dynamic> json) {
+    return ContactEmail(
+      address: json["address"] as String,
+    );
+  }
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      "type": "email",
+      "address": address,
+    };
+  }
+}
+
+class ContactPhone extends Contact {
+  final String number;
+
+  const ContactPhone({
+    required this.number,
+  });
+
+  factory ContactPhone.fromJson(Map<String, dynamic> json) {
+    return ContactPhone(
+      number: json["number"] as String,
+    );
+  }
+
+  @override
+  Map<String, dynamic> toJson() {
+    return {
+      "type": "phone",
+      "number": number,
+    };
+  }
+}
+
+typedef Country = String;
+
+typedef CreatedAt = DateTime;
+
+typedef Common = Object?;
@@ -0,0 +1,37 @@
+import "common.dart" show Address, Contact, Country, CreatedAt;
+
+class Customer {
+  final List<Address> addresses;
+  final Contact contact;
+  final CreatedAt createdAt;
+  final String name;
+  final Country? country;
+
+  const Customer({
+    required this.addresses,
+    required this.contact,
+    required this.createdAt,
+    required this.name,
+    this.country,
+  });
+
+  factory Customer.fromJson(Map<String, dynamic> json) {
+    return Customer(
+      addresses: (json["addresses"] as List<dynamic>).map((v0) => Address.fromJson(v0 as Map<String, dynamic>)).toList(),
+      contact: Contact.fromJson(json["contact"] as Map<String, dynamic>),
+      createdAt: DateTime.parse(json["createdAt"] as String),
+      name: json["name"] as String,
+      country: json["country"] == null ? null : json["country"] as String,
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "addresses": addresses.map((v0) => v0.toJson()).toList(),
+      "contact": contact.toJson(),
+      "createdAt": createdAt.toUtc().toIso8601String(),
+      "name": name,
+      if (country != null) "country": country,
+    };
+  }
+}
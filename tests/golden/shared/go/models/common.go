@@ -0,0 +1,282 @@
+package models
+
+import (
+	"encoding/json"
+	"time"
+)
+
+type AddressKind string
+
+const (
+	AddressKindHome AddressKind = "home"
+	AddressKindWork AddressKind = "work"
+)
+
+func ValidateAddressKind(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateAddressKind(instance, nil, &errors)
+	return errors
+}
+
+func validateAddressKind(instance interface{}, path []string, errors *[]ValidationError) {
+	switch instance {
+	case "home", "work":
+	default:
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/address/properties/kind/enum"})
+	}
+}
+
+func DecodeAddressKind(data []byte) (AddressKind, error) {
+	var out AddressKind
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateAddressKind(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type Address struct {
+	Country Country `json:"country"`
+	Kind AddressKind `json:"kind"`
+	Street string `json:"street"`
+}
+
+func ValidateAddress(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateAddress(instance, nil, &errors)
+	return errors
+}
+
+func validateAddress(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/address/properties"})
+		return
+	}
+	if v0, ok := obj["country"]; ok {
+		validateCountry(v0, jslAppend(path, "country"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/address/properties/country"})
+	}
+	if v0, ok := obj["kind"]; ok {
+		validateAddressKind(v0, jslAppend(path, "kind"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/address/properties/kind"})
+	}
+	if v0, ok := obj["street"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "street")), SchemaPath: "/definitions/address/properties/street/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/address/properties/street"})
+	}
+}
+
+func DecodeAddress(data []byte) (Address, error) {
+	var out Address
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateAddress(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type ContactEmail struct {
+	Address string `json:"address"`
+}
+
+type ContactPhone struct {
+	Number string `json:"number"`
+}
+
+type Contact struct {
+	Type string `json:"type"`
+	Val interface{} `json:"-"`
+}
+
+func (s *Contact) UnmarshalJSON(buf []byte) error {
+	var x struct{ Tag string `json:"type"` }
+	if err := json.Unmarshal(buf, &x); err != nil {
+		return err
+	}
+	switch x.Tag {
+	case "email":
+		var data ContactEmail
+		if err := json.Unmarshal(buf, &data); err != nil {
+			return err
+		}
+		s.Val = data
+	case "phone":
+		var data ContactPhone
+		if err := json.Unmarshal(buf, &data); err != nil {
+			return err
+		}
+		s.Val = data
+	}
+	s.Type = x.Tag
+	return nil
+}
+
+func (s Contact) MarshalJSON() ([]byte, error) {
+	switch val := s.Val.(type) {
+	case ContactEmail:
+		var data struct{
+			Tag string `json:"type"`
+			ContactEmail
+		}
+		data.ContactEmail = val
+		data.Tag = s.Type
+		return json.Marshal(data)
+	case ContactPhone:
+		var data struct{
+			Tag string `json:"type"`
+			ContactPhone
+		}
+		data.ContactPhone = val
+		data.Tag = s.Type
+		return json.Marshal(data)
+	}
+	panic("invalid discriminator tag")
+}
+
+func ValidateContact(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateContact(instance, nil, &errors)
+	return errors
+}
+
+func validateContact(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/contact/discriminator"})
+		return
+	}
+	tag, ok := obj["type"]
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/contact/discriminator/tag"})
+		return
+	}
+	switch tag {
+	case "email":
+		if v0, ok := obj["address"]; ok {
+			if _, ok := v0.(string); !ok {
+				*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "address")), SchemaPath: "/definitions/contact/discriminator/mapping/email/properties/address/type"})
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/contact/discriminator/mapping/email/properties/address"})
+		}
+	case "phone":
+		if v0, ok := obj["number"]; ok {
+			if _, ok := v0.(string); !ok {
+				*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "number")), SchemaPath: "/definitions/contact/discriminator/mapping/phone/properties/number/type"})
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/contact/discriminator/mapping/phone/properties/number"})
+		}
+	default:
+		if _, ok := tag.(string); ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "type")), SchemaPath: "/definitions/contact/discriminator/mapping"})
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "type")), SchemaPath: "/definitions/contact/discriminator/tag"})
+		}
+	}
+}
+
+func DecodeContact(data []byte) (Contact, error) {
+	var out Contact
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateContact(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type Country string
+
+func ValidateCountry(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateCountry(instance, nil, &errors)
+	return errors
+}
+
+func validateCountry(instance interface{}, path []string, errors *[]ValidationError) {
+	if _, ok := instance.(string); !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/country/type"})
+	}
+}
+
+func DecodeCountry(data []byte) (Country, error) {
+	var out Country
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateCountry(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type CreatedAt time.Time
+
+func ValidateCreatedAt(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateCreatedAt(instance, nil, &errors)
+	return errors
+}
+
+func validateCreatedAt(instance interface{}, path []string, errors *[]ValidationError) {
+	if !jslIsTimestamp(instance) {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/definitions/createdAt/type"})
+	}
+}
+
+func DecodeCreatedAt(data []byte) (CreatedAt, error) {
+	var out CreatedAt
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateCreatedAt(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
+
+type Common interface{}
+
+func ValidateCommon(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateCommon(instance, nil, &errors)
+	return errors
+}
+
+func validateCommon(instance interface{}, path []string, errors *[]ValidationError) {
+}
+
+func DecodeCommon(data []byte) (Common, error) {
+	var out Common
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateCommon(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
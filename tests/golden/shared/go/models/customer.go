@@ -0,0 +1,72 @@
+package models
+
+import (
+	"encoding/json"
+	"strconv"
+)
+
+type Customer struct {
+	Addresses []Address `json:"addresses"`
+	Contact Contact `json:"contact"`
+	CreatedAt CreatedAt `json:"createdAt"`
+	Name string `json:"name"`
+	Country *Country `json:"country,omitempty"`
+}
+
+func ValidateCustomer(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateCustomer(instance, nil, &errors)
+	return errors
+}
+
+func validateCustomer(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties"})
+		return
+	}
+	if v0, ok := obj["addresses"]; ok {
+		if a0, ok := v0.([]interface{}); ok {
+			for i0, v1 := range a0 {
+				validateAddress(v1, jslAppend(path, "addresses", strconv.Itoa(i0)), errors)
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "addresses")), SchemaPath: "/properties/addresses/elements"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/addresses"})
+	}
+	if v0, ok := obj["contact"]; ok {
+		validateContact(v0, jslAppend(path, "contact"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/contact"})
+	}
+	if v0, ok := obj["createdAt"]; ok {
+		validateCreatedAt(v0, jslAppend(path, "createdAt"), errors)
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/createdAt"})
+	}
+	if v0, ok := obj["name"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "name")), SchemaPath: "/properties/name/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/name"})
+	}
+	if v0, ok := obj["country"]; ok {
+		validateCountry(v0, jslAppend(path, "country"), errors)
+	}
+}
+
+func DecodeCustomer(data []byte) (Customer, error) {
+	var out Customer
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateCustomer(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
@@ -0,0 +1,51 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class Address {
+    public String country;
+    public AddressKind kind;
+    public String street;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/definitions/address/properties"));
+            return;
+        }
+        if (instance.has("country")) {
+            JsonNode v0 = instance.get("country");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "country"), "/definitions/country/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/definitions/address/properties/country"));
+        }
+        if (instance.has("kind")) {
+            JsonNode v0 = instance.get("kind");
+            AddressKind.validate(v0, ValidationError.append(path, "kind"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/definitions/address/properties/kind"));
+        }
+        if (instance.has("street")) {
+            JsonNode v0 = instance.get("street");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "street"), "/definitions/address/properties/street/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/definitions/address/properties/street"));
+        }
+    }
+}
@@ -0,0 +1,62 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonSubTypes;
+import com.fasterxml.jackson.annotation.JsonTypeInfo;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type")
+@JsonSubTypes({
+    @JsonSubTypes.Type(value = ContactEmail.class, name = "email"),
+    @JsonSubTypes.Type(value = ContactPhone.class, name = "phone"),
+})
+public abstract class Contact {
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/definitions/contact/discriminator"));
+            return;
+        }
+        if (!instance.has("type")) {
+            errors.add(new ValidationError(path, "/definitions/contact/discriminator/tag"));
+            return;
+        }
+        if (!instance.get("type").isTextual()) {
+            errors.add(new ValidationError(ValidationError.append(path, "type"), "/definitions/contact/discriminator/tag"));
+            return;
+        }
+
+        switch (instance.get("type").asText()) {
+        case "email":
+            if (instance.has("address")) {
+                JsonNode v0 = instance.get("address");
+                if (!v0.isTextual()) {
+                    errors.add(new ValidationError(ValidationError.append(path, "address"), "/definitions/contact/discriminator/mapping/email/properties/address/type"));
+                }
+            } else {
+                errors.add(new ValidationError(path, "/definitions/contact/discriminator/mapping/email/properties/address"));
+            }
+            break;
+        case "phone":
+            if (instance.has("number")) {
+                JsonNode v0 = instance.get("number");
+                if (!v0.isTextual()) {
+                    errors.add(new ValidationError(ValidationError.append(path, "number"), "/definitions/contact/discriminator/mapping/phone/properties/number/type"));
+                }
+            } else {
+                errors.add(new ValidationError(path, "/definitions/contact/discriminator/mapping/phone/properties/number"));
+            }
+            break;
+        default:
+            errors.add(new ValidationError(ValidationError.append(path, "type"), "/definitions/contact/discriminator/mapping"));
+        }
+    }
+}
@@ -0,0 +1,74 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class Customer {
+    public List<Address> addresses;
+    public Contact contact;
+    public Instant createdAt;
+    public String name;
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public String country;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/properties"));
+            return;
+        }
+        if (instance.has("addresses")) {
+            JsonNode v0 = instance.get("addresses");
+            if (v0.isArray()) {
+                for (int i0 = 0; i0 < v0.size(); i0++) {
+                    JsonNode v1 = v0.get(i0);
+                    Address.validate(v1, ValidationError.append(path, "addresses", Integer.toString(i0)), errors);
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "addresses"), "/properties/addresses/elements"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/addresses"));
+        }
+        if (instance.has("contact")) {
+            JsonNode v0 = instance.get("contact");
+            Contact.validate(v0, ValidationError.append(path, "contact"), errors);
+        } else {
+            errors.add(new ValidationError(path, "/properties/contact"));
+        }
+        if (instance.has("createdAt")) {
+            JsonNode v0 = instance.get("createdAt");
+            if (!ValidationError.isTimestamp(v0)) {
+                errors.add(new ValidationError(ValidationError.append(path, "createdAt"), "/definitions/createdAt/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/createdAt"));
+        }
+        if (instance.has("name")) {
+            JsonNode v0 = instance.get("name");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "name"), "/properties/name/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/name"));
+        }
+        if (instance.has("country")) {
+            JsonNode v0 = instance.get("country");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "country"), "/definitions/country/type"));
+            }
+        }
+    }
+}
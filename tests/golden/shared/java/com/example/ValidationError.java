@@ -0,0 +1,42 @@
+package com.example;
+
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.OffsetDateTime;
+import java.time.format.DateTimeParseException;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+
+public class ValidationError {
+    public final String instancePath;
+    public final String schemaPath;
+
+    public ValidationError(List<String> instancePath, String schemaPath) {
+        StringBuilder pointer = new StringBuilder();
+        for (String token : instancePath) {
+            pointer.append('/').append(token.replace("~", "~0").replace("/", "~1"));
+        }
+
+        this.instancePath = pointer.toString();
+        this.schemaPath = schemaPath;
+    }
+
+    static List<String> append(List<String> path, String... tokens) {
+        List<String> out = new ArrayList<>(path);
+        out.addAll(Arrays.asList(tokens));
+        return out;
+    }
+
+    static boolean isTimestamp(JsonNode instance) {
+        if (!instance.isTextual()) {
+            return false;
+        }
+
+        try {
+            OffsetDateTime.parse(instance.asText());
+            return true;
+        } catch (DateTimeParseException e) {
+            return false;
+        }
+    }
+}
@@ -0,0 +1,50 @@
+@file:OptIn(ExperimentalSerializationApi::class)
+
+package com.example
+
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.SerialName
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.json.JsonClassDiscriminator
+import kotlinx.serialization.json.JsonElement
+import kotlinx.serialization.json.JsonIgnoreUnknownKeys
+
+@Serializable
+enum class AddressKind {
+    @SerialName("home")
+    HOME,
+    @SerialName("work")
+    WORK,
+}
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class Address(
+    val country: Country,
+    val kind: AddressKind,
+    val street: String,
+)
+
+@Serializable
+@JsonClassDiscriminator("type")
+sealed class Contact
+
+@Serializable
+@SerialName("email")
+@JsonIgnoreUnknownKeys
+data class ContactEmail(
+    val address: String,
+) : Contact()
+
+@Serializable
+@SerialName("phone")
+@JsonIgnoreUnknownKeys
+data class ContactPhone(
+    val number: String,
+) : Contact()
+
+typealias Country = String
+
+typealias CreatedAt = String
+
+typealias Common = JsonElement
@@ -0,0 +1,17 @@
+@file:OptIn(ExperimentalSerializationApi::class)
+
+package com.example
+
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.json.JsonIgnoreUnknownKeys
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class Customer(
+    val addresses: List<Address>,
+    val contact: Contact,
+    val createdAt: CreatedAt,
+    val name: String,
+    val country: Country? = null,
+)
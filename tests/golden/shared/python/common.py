@@ -0,0 +1,93 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from datetime import datetime
+from enum import Enum
+from typing import Any, Dict
+
+
+def _parse_timestamp(value: str) -> datetime:
+    return datetime.fromisoformat(value.replace("Z", "+00:00"))
+
+
+class AddressKind(Enum):
+    HOME = "home"
+    WORK = "work"
+
+
+@dataclass
+class Address:
+    country: Country
+    kind: AddressKind
+    street: str
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Address:
+        return cls(
+            country=data["country"],
+            kind=AddressKind(data["kind"]),
+            street=data["street"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["country"] = self.country
+        data["kind"] = self.kind.value
+        data["street"] = self.street
+        return data
+
+
+@dataclass
+class Contact:
+    @staticmethod
+    def from_json(data: Dict[str, Any]) -> Contact:
+        variants = {
+            "email": ContactEmail,
+            "phone": ContactPhone,
+        }
+
+        return variants[data["type"]].from_json(data)
+
+    def to_json(self) -> Dict[str, Any]:
+        raise NotImplementedError
+
+
+@dataclass
+class ContactEmail(Contact):
+    address: str
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> ContactEmail:
+        return cls(
+            address=data["address"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {"type": "email"}
+        data["address"] = self.address
+        return data
+
+
+@dataclass
+class ContactPhone(Contact):
+    number: str
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> ContactPhone:
+        return cls(
+            number=data["number"],
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {"type": "phone"}
+        data["number"] = self.number
+        return data
+
+
+Country = str
+
+
+CreatedAt = datetime
+
+
+Common = Any
@@ -0,0 +1,40 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from datetime import datetime
+from typing import Any, Dict, List, Optional
+
+from .common import Address, Contact, Country, CreatedAt
+
+
+def _parse_timestamp(value: str) -> datetime:
+    return datetime.fromisoformat(value.replace("Z", "+00:00"))
+
+
+@dataclass
+class Customer:
+    addresses: List[Address]
+    contact: Contact
+    created_at: CreatedAt
+    name: str
+    country: Optional[Country] = None
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Customer:
+        return cls(
+            addresses=[Address.from_json(v0) for v0 in data["addresses"]],
+            contact=Contact.from_json(data["contact"]),
+            created_at=_parse_timestamp(data["createdAt"]),
+            name=data["name"],
+            country=data.get("country"),
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["addresses"] = [v0.to_json() for v0 in self.addresses]
+        data["contact"] = self.contact.to_json()
+        data["createdAt"] = self.created_at.isoformat()
+        data["name"] = self.name
+        if self.country is not None:
+            data["country"] = self.country
+        return data
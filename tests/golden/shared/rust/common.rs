@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum AddressKind {
+    #[serde(rename = "home")]
+    Home,
+    #[serde(rename = "work")]
+    Work,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Address {
+    pub country: Country,
+    pub kind: AddressKind,
+    pub street: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContactEmail {
+    pub address: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContactPhone {
+    pub number: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum Contact {
+    #[serde(rename = "email")]
+    Email(ContactEmail),
+    #[serde(rename = "phone")]
+    Phone(ContactPhone),
+}
+
+pub type Country = String;
+
+pub type CreatedAt = chrono::DateTime<chrono::Utc>;
+
+pub type Common = serde_json::Value;
@@ -0,0 +1,13 @@
+use serde::{Deserialize, Serialize};
+use super::common::{Address, Contact, Country, CreatedAt};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Customer {
+    pub addresses: Vec<Address>,
+    pub contact: Contact,
+    #[serde(rename = "createdAt")]
+    pub created_at: CreatedAt,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub country: Option<Country>,
+}
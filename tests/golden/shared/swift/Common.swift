@@ -0,0 +1,60 @@
+import Foundation
+
+enum AddressKind: String, Codable, Equatable {
+    case home
+    case work
+}
+
+struct Address: Codable, Equatable {
+    var country: Country
+    var kind: AddressKind
+    var street: String
+}
+
+struct ContactEmail: Codable, Equatable {
+    var address: String
+}
+
+struct ContactPhone: Codable, Equatable {
+    var number: String
+}
+
+enum Contact: Codable, Equatable {
+    case email(ContactEmail)
+    case phone(ContactPhone)
+
+    enum CodingKeys: String, CodingKey {
+        case tag = "type"
+    }
+
+    init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .tag)
+        switch tag {
+        case "email":
+            self = .email(try ContactEmail(from: decoder))
+        case "phone":
+            self = .phone(try ContactPhone(from: decoder))
+        default:
+            throw DecodingError.dataCorruptedError(forKey: .tag, in: container, debugDescription: "Unknown tag: \(tag)")
+        }
+    }
+
+    func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case .email(let value):
+            try container.encode("email", forKey: .tag)
+            try value.encode(to: encoder)
+        case .phone(let value):
+            try container.encode("phone", forKey: .tag)
+            try value.encode(to: encoder)
+        }
+    }
+}
+
+typealias Country = String
+
+typealias CreatedAt = String
+
+typealias Common = JSONValue
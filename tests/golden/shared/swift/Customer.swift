@@ -0,0 +1,9 @@
+import Foundation
+
+struct Customer: Codable, Equatable {
+    var addresses: [Address]
+    var contact: Contact
+    var createdAt: CreatedAt
+    var name: String
+    var country: Country?
+}
@@ -0,0 +1,143 @@
+export interface ValidationError {
+  instancePath: string;
+  schemaPath: string;
+}
+function jslPointer(path: string[]): string {
+  return path.map((token) => "/" + token.replace(/~/g, "~0").replace(/\//g, "~1")).join("");
+}
+function jslIsObject(instance: unknown): instance is { [key: string]: unknown } {
+  return typeof instance === "object" && instance !== null && !Array.isArray(instance);
+}
+function jslHas(instance: { [key: string]: unknown }, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(instance, key);
+}
+function jslIsTimestamp(instance: unknown): boolean {
+  return typeof instance === "string" && /^\d{4}-\d{2}-\d{2}[Tt]\d{2}:\d{2}:\d{2}(\.\d+)?([Zz]|[+-]\d{2}:\d{2})$/.test(instance) && !isNaN(Date.parse(instance));
+}
+export type AddressKind = "home" | "work";
+export function validateAddressKind(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateAddressKindAt(instance, [], errors);
+  return errors;
+}
+export function validateAddressKindAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (instance !== "home" && instance !== "work") {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/address/properties/kind/enum" });
+  }
+}
+export interface Address {
+  country: Country;
+  kind: AddressKind;
+  street: string;
+  [key: string]: unknown;
+}
+export function validateAddress(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateAddressAt(instance, [], errors);
+  return errors;
+}
+export function validateAddressAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/address/properties" });
+    return;
+  }
+  if (jslHas(instance, "country")) {
+    validateCountryAt(instance["country"], [...path, "country"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/address/properties/country" });
+  }
+  if (jslHas(instance, "kind")) {
+    validateAddressKindAt(instance["kind"], [...path, "kind"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/address/properties/kind" });
+  }
+  if (jslHas(instance, "street")) {
+    if (typeof instance["street"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "street"]), schemaPath: "/definitions/address/properties/street/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/address/properties/street" });
+  }
+}
+export interface ContactEmail {
+  type: "email";
+  address: string;
+  [key: string]: unknown;
+}
+export interface ContactPhone {
+  type: "phone";
+  number: string;
+  [key: string]: unknown;
+}
+export type Contact = ContactEmail | ContactPhone;
+export function validateContact(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateContactAt(instance, [], errors);
+  return errors;
+}
+export function validateContactAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/contact/discriminator" });
+    return;
+  }
+  if (!jslHas(instance, "type")) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/contact/discriminator/tag" });
+    return;
+  }
+  switch (instance["type"]) {
+    case "email":
+      if (jslHas(instance, "address")) {
+        if (typeof instance["address"] !== "string") {
+          errors.push({ instancePath: jslPointer([...path, "address"]), schemaPath: "/definitions/contact/discriminator/mapping/email/properties/address/type" });
+        }
+      } else {
+        errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/contact/discriminator/mapping/email/properties/address" });
+      }
+      break;
+    case "phone":
+      if (jslHas(instance, "number")) {
+        if (typeof instance["number"] !== "string") {
+          errors.push({ instancePath: jslPointer([...path, "number"]), schemaPath: "/definitions/contact/discriminator/mapping/phone/properties/number/type" });
+        }
+      } else {
+        errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/contact/discriminator/mapping/phone/properties/number" });
+      }
+      break;
+    default:
+      if (typeof instance["type"] === "string") {
+        errors.push({ instancePath: jslPointer([...path, "type"]), schemaPath: "/definitions/contact/discriminator/mapping" });
+      } else {
+        errors.push({ instancePath: jslPointer([...path, "type"]), schemaPath: "/definitions/contact/discriminator/tag" });
+      }
+  }
+}
+export type Country = string;
+export function validateCountry(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateCountryAt(instance, [], errors);
+  return errors;
+}
+export function validateCountryAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (typeof instance !== "string") {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/country/type" });
+  }
+}
+export type CreatedAt = string;
+export function validateCreatedAt(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateCreatedAtAt(instance, [], errors);
+  return errors;
+}
+export function validateCreatedAtAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsTimestamp(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/definitions/createdAt/type" });
+  }
+}
+export type Common = any;
+export function validateCommon(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateCommonAt(instance, [], errors);
+  return errors;
+}
+export function validateCommonAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+}
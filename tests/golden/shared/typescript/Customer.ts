@@ -0,0 +1,65 @@
+import { Address, validateAddressAt, Contact, validateContactAt, Country, validateCountryAt, CreatedAt, validateCreatedAtAt } from "./Common";
+export interface ValidationError {
+  instancePath: string;
+  schemaPath: string;
+}
+function jslPointer(path: string[]): string {
+  return path.map((token) => "/" + token.replace(/~/g, "~0").replace(/\//g, "~1")).join("");
+}
+function jslIsObject(instance: unknown): instance is { [key: string]: unknown } {
+  return typeof instance === "object" && instance !== null && !Array.isArray(instance);
+}
+function jslHas(instance: { [key: string]: unknown }, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(instance, key);
+}
+export interface Customer {
+  addresses: Address[];
+  contact: Contact;
+  createdAt: CreatedAt;
+  name: string;
+  country?: Country;
+  [key: string]: unknown;
+}
+export function validateCustomer(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateCustomerAt(instance, [], errors);
+  return errors;
+}
+export function validateCustomerAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties" });
+    return;
+  }
+  if (jslHas(instance, "addresses")) {
+    const a0: unknown = instance["addresses"];
+    if (Array.isArray(a0)) {
+      a0.forEach((v0: unknown, i0: number) => {
+        validateAddressAt(v0, [...path, "addresses", String(i0)], errors);
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "addresses"]), schemaPath: "/properties/addresses/elements" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/addresses" });
+  }
+  if (jslHas(instance, "contact")) {
+    validateContactAt(instance["contact"], [...path, "contact"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/contact" });
+  }
+  if (jslHas(instance, "createdAt")) {
+    validateCreatedAtAt(instance["createdAt"], [...path, "createdAt"], errors);
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/createdAt" });
+  }
+  if (jslHas(instance, "name")) {
+    if (typeof instance["name"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "name"]), schemaPath: "/properties/name/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/name" });
+  }
+  if (jslHas(instance, "country")) {
+    validateCountryAt(instance["country"], [...path, "country"], errors);
+  }
+}
@@ -0,0 +1,46 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Example;
+
+public sealed record Types
+{
+    [JsonPropertyName("anyValue")]
+    public required JsonElement AnyValue { get; init; }
+
+    [JsonPropertyName("booleanValue")]
+    public required bool BooleanValue { get; init; }
+
+    [JsonPropertyName("numberValue")]
+    public required double NumberValue { get; init; }
+
+    [JsonPropertyName("stringValue")]
+    public required string StringValue { get; init; }
+
+    [JsonPropertyName("timestampValue")]
+    public required DateTimeOffset TimestampValue { get; init; }
+
+    [JsonPropertyName("optionalAny")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public JsonElement? OptionalAny { get; init; }
+
+    [JsonPropertyName("optionalBoolean")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public bool? OptionalBoolean { get; init; }
+
+    [JsonPropertyName("optionalNumber")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public double? OptionalNumber { get; init; }
+
+    [JsonPropertyName("optionalString")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? OptionalString { get; init; }
+
+    [JsonPropertyName("optionalTimestamp")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DateTimeOffset? OptionalTimestamp { get; init; }
+}
@@ -0,0 +1,55 @@
+class Types {
+  final Object? anyValue;
+  final bool booleanValue;
+  final double numberValue;
+  final String stringValue;
+  final DateTime timestampValue;
+  final Object? optionalAny;
+  final bool? optionalBoolean;
+  final double? optionalNumber;
+  final String? optionalString;
+  final DateTime? optionalTimestamp;
+
+  const Types({
+    required this.anyValue,
+    required this.booleanValue,
+    required this.numberValue,
+    required this.stringValue,
+    required this.timestampValue,
+    this.optionalAny,
+    this.optionalBoolean,
+    this.optionalNumber,
+    this.optionalString,
+    this.optionalTimestamp,
+  });
+
+  factory Types.fromJson(Map<String, dynamic> json) {
+    return Types(
+      anyValue: json["anyValue"],
+      booleanValue: json["booleanValue"] as bool,
+      numberValue: (json["numberValue"] as num).toDouble(),
+      stringValue: json["stringValue"] as String,
+      timestampValue: DateTime.parse(json["timestampValue"] as String),
+      optionalAny: json["optionalAny"],
+      optionalBoolean: json["optionalBoolean"] as bool?,
+      optionalNumber: json["optionalNumber"] == null ? null : (json["optionalNumber"] as num).toDouble(),
+      optionalString: json["optionalString"] as String?,
+      optionalTimestamp: json["optionalTimestamp"] == null ? null : DateTime.parse(json["optionalTimestamp"] as String),
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "anyValue": anyValue,
+      "booleanValue": booleanValue,
+      "numberValue": numberValue,
+      "stringValue": stringValue,
+      "timestampValue": timestampValue.toUtc().toIso8601String(),
+      if (optionalAny != null) "optionalAny": optionalAny,
+      if (optionalBoolean != null) "optionalBoolean": optionalBoolean,
+      if (optionalNumber != null) "optionalNumber": optionalNumber,
+      if (optionalString != null) "optionalString": optionalString,
+      if (optionalTimestamp != null) "optionalTimestamp": optionalTimestamp!.toUtc().toIso8601String(),
+    };
+  }
+}
@@ -0,0 +1,97 @@
+package models
+
+import (
+	"encoding/json"
+	"time"
+)
+
+type Types struct {
+	AnyValue interface{} `json:"anyValue"`
+	BooleanValue bool `json:"booleanValue"`
+	NumberValue float64 `json:"numberValue"`
+	StringValue string `json:"stringValue"`
+	TimestampValue time.Time `json:"timestampValue"`
+	OptionalAny interface{} `json:"optionalAny,omitempty"`
+	OptionalBoolean *bool `json:"optionalBoolean,omitempty"`
+	OptionalNumber *float64 `json:"optionalNumber,omitempty"`
+	OptionalString *string `json:"optionalString,omitempty"`
+	OptionalTimestamp *time.Time `json:"optionalTimestamp,omitempty"`
+}
+
+func ValidateTypes(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateTypes(instance, nil, &errors)
+	return errors
+}
+
+func validateTypes(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties"})
+		return
+	}
+	if _, ok := obj["anyValue"]; !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/anyValue"})
+	}
+	if v0, ok := obj["booleanValue"]; ok {
+		if _, ok := v0.(bool); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "booleanValue")), SchemaPath: "/properties/booleanValue/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/booleanValue"})
+	}
+	if v0, ok := obj["numberValue"]; ok {
+		if _, ok := v0.(float64); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "numberValue")), SchemaPath: "/properties/numberValue/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/numberValue"})
+	}
+	if v0, ok := obj["stringValue"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "stringValue")), SchemaPath: "/properties/stringValue/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/stringValue"})
+	}
+	if v0, ok := obj["timestampValue"]; ok {
+		if !jslIsTimestamp(v0) {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "timestampValue")), SchemaPath: "/properties/timestampValue/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/timestampValue"})
+	}
+	if v0, ok := obj["optionalBoolean"]; ok {
+		if _, ok := v0.(bool); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "optionalBoolean")), SchemaPath: "/optionalProperties/optionalBoolean/type"})
+		}
+	}
+	if v0, ok := obj["optionalNumber"]; ok {
+		if _, ok := v0.(float64); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "optionalNumber")), SchemaPath: "/optionalProperties/optionalNumber/type"})
+		}
+	}
+	if v0, ok := obj["optionalString"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "optionalString")), SchemaPath: "/optionalProperties/optionalString/type"})
+		}
+	}
+	if v0, ok := obj["optionalTimestamp"]; ok {
+		if !jslIsTimestamp(v0) {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "optionalTimestamp")), SchemaPath: "/optionalProperties/optionalTimestamp/type"})
+		}
+	}
+}
+
+func DecodeTypes(data []byte) (Types, error) {
+	var out Types
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateTypes(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
@@ -0,0 +1,101 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class Types {
+    public Object anyValue;
+    public boolean booleanValue;
+    public double numberValue;
+    public String stringValue;
+    public Instant timestampValue;
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public Object optionalAny;
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public Boolean optionalBoolean;
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public Double optionalNumber;
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public String optionalString;
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public Instant optionalTimestamp;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/properties"));
+            return;
+        }
+        if (!instance.has("anyValue")) {
+            errors.add(new ValidationError(path, "/properties/anyValue"));
+        }
+        if (instance.has("booleanValue")) {
+            JsonNode v0 = instance.get("booleanValue");
+            if (!v0.isBoolean()) {
+                errors.add(new ValidationError(ValidationError.append(path, "booleanValue"), "/properties/booleanValue/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/booleanValue"));
+        }
+        if (instance.has("numberValue")) {
+            JsonNode v0 = instance.get("numberValue");
+            if (!v0.isNumber()) {
+                errors.add(new ValidationError(ValidationError.append(path, "numberValue"), "/properties/numberValue/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/numberValue"));
+        }
+        if (instance.has("stringValue")) {
+            JsonNode v0 = instance.get("stringValue");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "stringValue"), "/properties/stringValue/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/stringValue"));
+        }
+        if (instance.has("timestampValue")) {
+            JsonNode v0 = instance.get("timestampValue");
+            if (!ValidationError.isTimestamp(v0)) {
+                errors.add(new ValidationError(ValidationError.append(path, "timestampValue"), "/properties/timestampValue/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/timestampValue"));
+        }
+        if (instance.has("optionalBoolean")) {
+            JsonNode v0 = instance.get("optionalBoolean");
+            if (!v0.isBoolean()) {
+                errors.add(new ValidationError(ValidationError.append(path, "optionalBoolean"), "/optionalProperties/optionalBoolean/type"));
+            }
+        }
+        if (instance.has("optionalNumber")) {
+            JsonNode v0 = instance.get("optionalNumber");
+            if (!v0.isNumber()) {
+                errors.add(new ValidationError(ValidationError.append(path, "optionalNumber"), "/optionalProperties/optionalNumber/type"));
+            }
+        }
+        if (instance.has("optionalString")) {
+            JsonNode v0 = instance.get("optionalString");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "optionalString"), "/optionalProperties/optionalString/type"));
+            }
+        }
+        if (instance.has("optionalTimestamp")) {
+            JsonNode v0 = instance.get("optionalTimestamp");
+            if (!ValidationError.isTimestamp(v0)) {
+                errors.add(new ValidationError(ValidationError.append(path, "optionalTimestamp"), "/optionalProperties/optionalTimestamp/type"));
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+@file:OptIn(ExperimentalSerializationApi::class)
+
+package com.example
+
+import kotlinx.serialization.ExperimentalSerializationApi
+import kotlinx.serialization.Serializable
+import kotlinx.serialization.json.JsonElement
+import kotlinx.serialization.json.JsonIgnoreUnknownKeys
+
+@Serializable
+@JsonIgnoreUnknownKeys
+data class Types(
+    val anyValue: JsonElement,
+    val booleanValue: Boolean,
+    val numberValue: Double,
+    val stringValue: String,
+    val timestampValue: String,
+    val optionalAny: JsonElement? = null,
+    val optionalBoolean: Boolean? = null,
+    val optionalNumber: Double? = null,
+    val optionalString: String? = null,
+    val optionalTimestamp: String? = null,
+)
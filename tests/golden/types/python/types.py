@@ -0,0 +1,57 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from datetime import datetime
+from typing import Any, Dict, Optional
+
+
+def _parse_timestamp(value: str) -> datetime:
+    return datetime.fromisoformat(value.replace("Z", "+00:00"))
+
+
+@dataclass
+class Types:
+    any_value: Any
+    boolean_value: bool
+    number_value: float
+    string_value: str
+    timestamp_value: datetime
+    optional_any: Optional[Any] = None
+    optional_boolean: Optional[bool] = None
+    optional_number: Optional[float] = None
+    optional_string: Optional[str] = None
+    optional_timestamp: Optional[datetime] = None
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> Types:
+        return cls(
+            any_value=data["anyValue"],
+            boolean_value=data["booleanValue"],
+            number_value=data["numberValue"],
+            string_value=data["stringValue"],
+            timestamp_value=_parse_timestamp(data["timestampValue"]),
+            optional_any=data.get("optionalAny"),
+            optional_boolean=data.get("optionalBoolean"),
+            optional_number=data.get("optionalNumber"),
+            optional_string=data.get("optionalString"),
+            optional_timestamp=_parse_timestamp(data["optionalTimestamp"]) if "optionalTimestamp" in data else None,
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["anyValue"] = self.any_value
+        data["booleanValue"] = self.boolean_value
+        data["numberValue"] = self.number_value
+        data["stringValue"] = self.string_value
+        data["timestampValue"] = self.timestamp_value.isoformat()
+        if self.optional_any is not None:
+            data["optionalAny"] = self.optional_any
+        if self.optional_boolean is not None:
+            data["optionalBoolean"] = self.optional_boolean
+        if self.optional_number is not None:
+            data["optionalNumber"] = self.optional_number
+        if self.optional_string is not None:
+            data["optionalString"] = self.optional_string
+        if self.optional_timestamp is not None:
+            data["optionalTimestamp"] = self.optional_timestamp.isoformat()
+        return data
@@ -0,0 +1,30 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Types {
+    #[serde(rename = "anyValue")]
+    pub any_value: serde_json::Value,
+    #[serde(rename = "booleanValue")]
+    pub boolean_value: bool,
+    #[serde(rename = "numberValue")]
+    pub number_value: f64,
+    #[serde(rename = "stringValue")]
+    pub string_value: String,
+    #[serde(rename = "timestampValue")]
+    pub timestamp_value: chrono::DateTime<chrono::Utc>,
+    #[serde(rename = "optionalAny")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional_any: Option<serde_json::Value>,
+    #[serde(rename = "optionalBoolean")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional_boolean: Option<bool>,
+    #[serde(rename = "optionalNumber")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional_number: Option<f64>,
+    #[serde(rename = "optionalString")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional_string: Option<String>,
+    #[serde(rename = "optionalTimestamp")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub optional_timestamp: Option<chrono::DateTime<chrono::Utc>>,
+}
@@ -0,0 +1,14 @@
+import Foundation
+
+struct Types: Codable, Equatable {
+    var anyValue: JSONValue
+    var booleanValue: Bool
+    var numberValue: Double
+    var stringValue: String
+    var timestampValue: String
+    var optionalAny: JSONValue?
+    var optionalBoolean: Bool?
+    var optionalNumber: Double?
+    var optionalString: String?
+    var optionalTimestamp: String?
+}
@@ -0,0 +1,91 @@
+export interface ValidationError {
+  instancePath: string;
+  schemaPath: string;
+}
+function jslPointer(path: string[]): string {
+  return path.map((token) => "/" + token.replace(/~/g, "~0").replace(/\//g, "~1")).join("");
+}
+function jslIsObject(instance: unknown): instance is { [key: string]: unknown } {
+  return typeof instance === "object" && instance !== null && !Array.isArray(instance);
+}
+function jslHas(instance: { [key: string]: unknown }, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(instance, key);
+}
+function jslIsTimestamp(instance: unknown): boolean {
+  return typeof instance === "string" && /^\d{4}-\d{2}-\d{2}[Tt]\d{2}:\d{2}:\d{2}(\.\d+)?([Zz]|[+-]\d{2}:\d{2})$/.test(instance) && !isNaN(Date.parse(instance));
+}
+export interface Types {
+  anyValue: any;
+  booleanValue: boolean;
+  numberValue: number;
+  stringValue: string;
+  timestampValue: string;
+  optionalAny?: any;
+  optionalBoolean?: boolean;
+  optionalNumber?: number;
+  optionalString?: string;
+  optionalTimestamp?: string;
+  [key: string]: unknown;
+}
+export function validateTypes(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateTypesAt(instance, [], errors);
+  return errors;
+}
+export function validateTypesAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties" });
+    return;
+  }
+  if (!jslHas(instance, "anyValue")) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/anyValue" });
+  }
+  if (jslHas(instance, "booleanValue")) {
+    if (typeof instance["booleanValue"] !== "boolean") {
+      errors.push({ instancePath: jslPointer([...path, "booleanValue"]), schemaPath: "/properties/booleanValue/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/booleanValue" });
+  }
+  if (jslHas(instance, "numberValue")) {
+    if (typeof instance["numberValue"] !== "number") {
+      errors.push({ instancePath: jslPointer([...path, "numberValue"]), schemaPath: "/properties/numberValue/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/numberValue" });
+  }
+  if (jslHas(instance, "stringValue")) {
+    if (typeof instance["stringValue"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "stringValue"]), schemaPath: "/properties/stringValue/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/stringValue" });
+  }
+  if (jslHas(instance, "timestampValue")) {
+    if (!jslIsTimestamp(instance["timestampValue"])) {
+      errors.push({ instancePath: jslPointer([...path, "timestampValue"]), schemaPath: "/properties/timestampValue/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/timestampValue" });
+  }
+  if (jslHas(instance, "optionalBoolean")) {
+    if (typeof instance["optionalBoolean"] !== "boolean") {
+      errors.push({ instancePath: jslPointer([...path, "optionalBoolean"]), schemaPath: "/optionalProperties/optionalBoolean/type" });
+    }
+  }
+  if (jslHas(instance, "optionalNumber")) {
+    if (typeof instance["optionalNumber"] !== "number") {
+      errors.push({ instancePath: jslPointer([...path, "optionalNumber"]), schemaPath: "/optionalProperties/optionalNumber/type" });
+    }
+  }
+  if (jslHas(instance, "optionalString")) {
+    if (typeof instance["optionalString"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "optionalString"]), schemaPath: "/optionalProperties/optionalString/type" });
+    }
+  }
+  if (jslHas(instance, "optionalTimestamp")) {
+    if (!jslIsTimestamp(instance["optionalTimestamp"])) {
+      errors.push({ instancePath: jslPointer([...path, "optionalTimestamp"]), schemaPath: "/optionalProperties/optionalTimestamp/type" });
+    }
+  }
+}
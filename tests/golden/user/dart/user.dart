@@ -0,0 +1,27 @@
+class User {
+  final bool isAdmin;
+  final String name;
+  final List<double>? favoriteNumbers;
+
+  const User({
+    required this.isAdmin,
+    required this.name,
+    this.favoriteNumbers,
+  });
+
+  factory User.fromJson(Map<String, dynamic> json) {
+    return User(
+      isAdmin: json["isAdmin"] as bool,
+      name: json["name"] as String,
+      favoriteNumbers: json["favoriteNumbers"] == null ? null : (json["favoriteNumbers"] as List<dynamic>).map((v0) => (v0 as num).toDouble()).toList(),
+    );
+  }
+
+  Map<String, dynamic> toJson() {
+    return {
+      "isAdmin": isAdmin,
+      "name": name,
+      if (favoriteNumbers != null) "favoriteNumbers": favoriteNumbers,
+    };
+  }
+}
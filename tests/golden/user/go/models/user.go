@@ -0,0 +1,64 @@
+package models
+
+import (
+	"encoding/json"
+	"strconv"
+)
+
+type User struct {
+	IsAdmin bool `json:"isAdmin"`
+	Name string `json:"name"`
+	FavoriteNumbers []float64 `json:"favoriteNumbers,omitempty"`
+}
+
+func ValidateUser(instance interface{}) []ValidationError {
+	var errors []ValidationError
+	validateUser(instance, nil, &errors)
+	return errors
+}
+
+func validateUser(instance interface{}, path []string, errors *[]ValidationError) {
+	obj, ok := instance.(map[string]interface{})
+	if !ok {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties"})
+		return
+	}
+	if v0, ok := obj["isAdmin"]; ok {
+		if _, ok := v0.(bool); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "isAdmin")), SchemaPath: "/properties/isAdmin/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/isAdmin"})
+	}
+	if v0, ok := obj["name"]; ok {
+		if _, ok := v0.(string); !ok {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "name")), SchemaPath: "/properties/name/type"})
+		}
+	} else {
+		*errors = append(*errors, ValidationError{InstancePath: jslPointer(path), SchemaPath: "/properties/name"})
+	}
+	if v0, ok := obj["favoriteNumbers"]; ok {
+		if a0, ok := v0.([]interface{}); ok {
+			for i0, v1 := range a0 {
+				if _, ok := v1.(float64); !ok {
+					*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "favoriteNumbers", strconv.Itoa(i0))), SchemaPath: "/optionalProperties/favoriteNumbers/elements/type"})
+				}
+			}
+		} else {
+			*errors = append(*errors, ValidationError{InstancePath: jslPointer(jslAppend(path, "favoriteNumbers")), SchemaPath: "/optionalProperties/favoriteNumbers/elements"})
+		}
+	}
+}
+
+func DecodeUser(data []byte) (User, error) {
+	var out User
+	var instance interface{}
+	if err := json.Unmarshal(data, &instance); err != nil {
+		return out, err
+	}
+	if errors := ValidateUser(instance); len(errors) > 0 {
+		return out, ValidationErrors(errors)
+	}
+	err := json.Unmarshal(data, &out)
+	return out, err
+}
@@ -0,0 +1,60 @@
+package com.example;
+
+import com.fasterxml.jackson.annotation.JsonIgnoreProperties;
+import com.fasterxml.jackson.annotation.JsonInclude;
+import com.fasterxml.jackson.databind.JsonNode;
+import java.time.Instant;
+import java.util.ArrayList;
+import java.util.Iterator;
+import java.util.List;
+import java.util.Map;
+
+@JsonIgnoreProperties(ignoreUnknown = true)
+public class User {
+    public boolean isAdmin;
+    public String name;
+    @JsonInclude(JsonInclude.Include.NON_NULL)
+    public List<Double> favoriteNumbers;
+
+    public static List<ValidationError> validate(JsonNode instance) {
+        List<ValidationError> errors = new ArrayList<>();
+        validate(instance, new ArrayList<>(), errors);
+        return errors;
+    }
+
+    static void validate(JsonNode instance, List<String> path, List<ValidationError> errors) {
+        if (!instance.isObject()) {
+            errors.add(new ValidationError(path, "/properties"));
+            return;
+        }
+        if (instance.has("isAdmin")) {
+            JsonNode v0 = instance.get("isAdmin");
+            if (!v0.isBoolean()) {
+                errors.add(new ValidationError(ValidationError.append(path, "isAdmin"), "/properties/isAdmin/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/isAdmin"));
+        }
+        if (instance.has("name")) {
+            JsonNode v0 = instance.get("name");
+            if (!v0.isTextual()) {
+                errors.add(new ValidationError(ValidationError.append(path, "name"), "/properties/name/type"));
+            }
+        } else {
+            errors.add(new ValidationError(path, "/properties/name"));
+        }
+        if (instance.has("favoriteNumbers")) {
+            JsonNode v0 = instance.get("favoriteNumbers");
+            if (v0.isArray()) {
+                for (int i0 = 0; i0 < v0.size(); i0++) {
+                    JsonNode v1 = v0.get(i0);
+                    if (!v1.isNumber()) {
+                        errors.add(new ValidationError(ValidationError.append(path, "favoriteNumbers", Integer.toString(i0)), "/optionalProperties/favoriteNumbers/elements/type"));
+                    }
+                }
+            } else {
+                errors.add(new ValidationError(ValidationError.append(path, "favoriteNumbers"), "/optionalProperties/favoriteNumbers/elements"));
+            }
+        }
+    }
+}
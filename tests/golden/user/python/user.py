@@ -0,0 +1,27 @@
+from __future__ import annotations
+
+from dataclasses import dataclass
+from typing import Any, Dict, List, Optional
+
+
+@dataclass
+class User:
+    is_admin: bool
+    name: str
+    favorite_numbers: Optional[List[float]] = None
+
+    @classmethod
+    def from_json(cls, data: Dict[str, Any]) -> User:
+        return cls(
+            is_admin=data["isAdmin"],
+            name=data["name"],
+            favorite_numbers=data.get("favoriteNumbers"),
+        )
+
+    def to_json(self) -> Dict[str, Any]:
+        data: Dict[str, Any] = {}
+        data["isAdmin"] = self.is_admin
+        data["name"] = self.name
+        if self.favorite_numbers is not None:
+            data["favoriteNumbers"] = self.favorite_numbers
+        return data
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct User {
+    #[serde(rename = "isAdmin")]
+    pub is_admin: bool,
+    pub name: String,
+    #[serde(rename = "favoriteNumbers")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub favorite_numbers: Option<Vec<f64>>,
+}
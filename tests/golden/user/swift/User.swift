@@ -0,0 +1,7 @@
+import Foundation
+
+struct User: Codable, Equatable {
+    var isAdmin: Bool
+    var name: String
+    var favoriteNumbers: [Double]?
+}
@@ -0,0 +1,56 @@
+export interface ValidationError {
+  instancePath: string;
+  schemaPath: string;
+}
+function jslPointer(path: string[]): string {
+  return path.map((token) => "/" + token.replace(/~/g, "~0").replace(/\//g, "~1")).join("");
+}
+function jslIsObject(instance: unknown): instance is { [key: string]: unknown } {
+  return typeof instance === "object" && instance !== null && !Array.isArray(instance);
+}
+function jslHas(instance: { [key: string]: unknown }, key: string): boolean {
+  return Object.prototype.hasOwnProperty.call(instance, key);
+}
+export interface User {
+  isAdmin: boolean;
+  name: string;
+  favoriteNumbers?: number[];
+  [key: string]: unknown;
+}
+export function validateUser(instance: unknown): ValidationError[] {
+  const errors: ValidationError[] = [];
+  validateUserAt(instance, [], errors);
+  return errors;
+}
+export function validateUserAt(instance: unknown, path: string[], errors: ValidationError[]): void {
+  if (!jslIsObject(instance)) {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties" });
+    return;
+  }
+  if (jslHas(instance, "isAdmin")) {
+    if (typeof instance["isAdmin"] !== "boolean") {
+      errors.push({ instancePath: jslPointer([...path, "isAdmin"]), schemaPath: "/properties/isAdmin/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/isAdmin" });
+  }
+  if (jslHas(instance, "name")) {
+    if (typeof instance["name"] !== "string") {
+      errors.push({ instancePath: jslPointer([...path, "name"]), schemaPath: "/properties/name/type" });
+    }
+  } else {
+    errors.push({ instancePath: jslPointer(path), schemaPath: "/properties/name" });
+  }
+  if (jslHas(instance, "favoriteNumbers")) {
+    const a0: unknown = instance["favoriteNumbers"];
+    if (Array.isArray(a0)) {
+      a0.forEach((v0: unknown, i0: number) => {
+        if (typeof v0 !== "number") {
+          errors.push({ instancePath: jslPointer([...path, "favoriteNumbers", String(i0)]), schemaPath: "/optionalProperties/favoriteNumbers/elements/type" });
+        }
+      });
+    } else {
+      errors.push({ instancePath: jslPointer([...path, "favoriteNumbers"]), schemaPath: "/optionalProperties/favoriteNumbers/elements" });
+    }
+  }
+}
@@ -0,0 +1,136 @@
+// Tests for types mapped by the user in place of the usual ones.
+
+use jsl::Schema;
+use jsl_codegen::config::{Config, TypeMapping};
+use serde_json::json;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+fn mapping(name: &str, import: Option<&str>) -> TypeMapping {
+    TypeMapping {
+        name: name.to_owned(),
+        import: import.map(|import| import.to_owned()),
+    }
+}
+
+fn config() -> Config {
+    let mut config = Config::default();
+    config.typescript.out = Some("typescript".to_owned());
+    config.java.out = Some("java".to_owned());
+    config.java.package = Some("com.example".to_owned());
+    config.go.out = Some("go".to_owned());
+    config.rust.out = Some("rust".to_owned());
+    config.python.out = Some("python".to_owned());
+
+    config
+        .typescript
+        .types
+        .insert("timestamp".to_owned(), mapping("Date", None));
+    config.java.types.insert(
+        "timestamp".to_owned(),
+        mapping("OffsetDateTime", Some("java.time.OffsetDateTime")),
+    );
+    config.java.types.insert(
+        "money".to_owned(),
+        mapping("BigDecimal", Some("java.math.BigDecimal")),
+    );
+    config
+        .go
+        .types
+        .insert("integer".to_owned(), mapping("int64", None));
+    config.go.types.insert(
+        "money".to_owned(),
+        mapping("decimal.Decimal", Some("github.com/shopspring/decimal")),
+    );
+    config
+        .rust
+        .types
+        .insert("integer".to_owned(), mapping("i64", None));
+    config
+        .python
+        .types
+        .insert("money".to_owned(), mapping("Decimal", Some("decimal")));
+    config
+}
+
+fn generate() -> BTreeMap<PathBuf, String> {
+    let schema = json!({
+        "definitions": {
+            "price": {
+                "metadata": { "type": "money" },
+                "type": "number"
+            }
+        },
+        "properties": {
+            "createdAt": { "type": "timestamp" },
+            "count": {
+                "metadata": { "type": "integer" },
+                "type": "number"
+            },
+            "price": { "ref": "price" },
+            "ratio": { "type": "number" }
+        }
+    });
+
+    let schema = Schema::from_serde(serde_json::from_value(schema).unwrap()).unwrap();
+    jsl_codegen::generate("order", schema, &config()).unwrap()
+}
+
+fn file<'a>(files: &'a BTreeMap<PathBuf, String>, path: &str) -> &'a str {
+    &files[&PathBuf::from(path)]
+}
+
+#[test]
+fn jsl_types_can_be_mapped() {
+    let files = generate();
+
+    let typescript = file(&files, "typescript/Order.ts");
+    assert!(typescript.contains("createdAt: Date;"));
+    assert!(typescript.contains("!jslIsTimestamp(instance[\"createdAt\"])"));
+
+    let java = file(&files, "java/com/example/Order.java");
+    assert!(java.contains("import java.time.OffsetDateTime;"));
+    assert!(java.contains("public OffsetDateTime createdAt;"));
+}
+
+#[test]
+fn metadata_can_pick_a_mapping() {
+    let files = generate();
+
+    let go = file(&files, "go/order.go");
+    assert!(go.contains("\t\"github.com/shopspring/decimal\"\n"));
+    assert!(go.contains("type Price = decimal.Decimal"));
+    assert!(go.contains("Count int64 `json:\"count\"`"));
+    assert!(go.contains("Ratio float64 `json:\"ratio\"`"));
+
+    let rust = file(&files, "rust/order.rs");
+    assert!(rust.contains("pub count: i64,"));
+    assert!(rust.contains("pub ratio: f64,"));
+
+    let python = file(&files, "python/order.py");
+    assert!(python.contains("from decimal import Decimal\n"));
+    assert!(python.contains("Price = Decimal\n"));
+}
+
+#[test]
+fn mappings_are_imported_through_aliases() {
+    let files = generate();
+
+    // Java has no aliases, so the class using one has to import what the
+    // alias was mapped to.
+    let java = file(&files, "java/com/example/Order.java");
+    assert!(java.contains("import java.math.BigDecimal;"));
+    assert!(java.contains("public BigDecimal price;"));
+}
+
+#[test]
+fn unknown_metadata_types_fall_back_to_the_jsl_type() {
+    let files = generate();
+
+    let java = file(&files, "java/com/example/Order.java");
+    assert!(java.contains("public double count;"));
+
+    let typescript = file(&files, "typescript/Order.ts");
+    assert!(typescript.contains("count: number;"));
+    assert!(typescript.contains("export type Price = number;"));
+}